@@ -0,0 +1,26 @@
+//! Runs the same input through this crate and the `latlon` crate, and fails on inputs that both accept but read as
+//! different coordinates.
+#![no_main]
+
+use iso6709parse::{parse, ISO6709Coord};
+use libfuzzer_sys::fuzz_target;
+
+/// Inputs that both crates accept should agree far more closely than this, in degrees
+const TOLERANCE: f64 = 1e-9;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+    let (Ok(ours), Ok(theirs)) = (parse::<ISO6709Coord>(input), latlon::parse(input)) else {
+        return;
+    };
+    assert!(
+        (ours.lat - theirs.y()).abs() <= TOLERANCE && (ours.lon - theirs.x()).abs() <= TOLERANCE,
+        "{:?} is {:?} here but ({}, {}) in latlon",
+        input,
+        ours,
+        theirs.y(),
+        theirs.x()
+    );
+});
@@ -0,0 +1,57 @@
+//! Runs arbitrary text through every parse function with arbitrary options. The parsers must never panic, whatever
+//! the input; this target only fails if one does.
+#![no_main]
+
+use arbitrary::Arbitrary;
+use iso6709parse::ast::parse_ast;
+use iso6709parse::conformance::check_conformance;
+use iso6709parse::prefix::check_prefix;
+use iso6709parse::{
+    parse, parse_bbox, parse_position, parse_readable, parse_string_representation, parse_with_repairs, sanitize,
+    ISO6709Coord, Leniency, ParserOptions,
+};
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Debug, Arbitrary)]
+struct Input<'a> {
+    text: &'a str,
+    lenient: bool,
+    decimal_comma: bool,
+    allow_exponent: bool,
+    require_crs: Option<bool>,
+    unlimited: bool,
+}
+
+fuzz_target!(|input: Input| {
+    let text = input.text;
+    let _ = parse::<ISO6709Coord>(text);
+    let _ = parse::<geo_types::Coord>(text);
+    let _ = parse_readable::<ISO6709Coord>(text);
+    let _ = parse_string_representation::<ISO6709Coord>(text);
+    let _ = parse_ast(text);
+    let _ = parse_bbox(text);
+    let _ = check_conformance(text);
+    let _ = check_prefix(text);
+    let _ = sanitize(text);
+
+    let options = ParserOptions {
+        leniency: if input.lenient {
+            Leniency::Lenient
+        } else {
+            Leniency::Standard
+        },
+        decimal_comma: input.decimal_comma,
+        allow_exponent: input.allow_exponent,
+        require_crs: input.require_crs,
+        max_input_len: if input.unlimited {
+            None
+        } else {
+            Some(ParserOptions::DEFAULT_MAX_INPUT_LEN)
+        },
+        ..Default::default()
+    };
+    if let Ok(position) = parse_position(text, &options) {
+        assert!(position.coord.lat.abs() <= 90. && position.coord.lon.abs() <= 180.);
+    }
+    let _ = parse_with_repairs(text, &options);
+});
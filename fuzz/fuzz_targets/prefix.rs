@@ -0,0 +1,24 @@
+//! Checks `check_prefix` against `is_valid`, since the prefix automaton is a grammar of its own. Every prefix of an
+//! input that `is_valid` accepts must be a valid prefix, and a prefix is complete exactly when `is_valid` accepts it.
+#![no_main]
+
+use iso6709parse::is_valid;
+use iso6709parse::prefix::check_prefix;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    let check = check_prefix(text);
+    assert_eq!(check.complete, is_valid(text), "{:?}", text);
+    if !is_valid(text) {
+        return;
+    }
+    for end in text
+        .char_indices()
+        .map(|(end, _)| end)
+        .chain([text.len()])
+    {
+        let check = check_prefix(&text[..end]);
+        assert!(check.valid_prefix, "{:?} of {:?}", &text[..end], text);
+        assert_eq!(check.valid_up_to, end, "{:?} of {:?}", &text[..end], text);
+    }
+});
@@ -0,0 +1,42 @@
+//! Formats arbitrary valid coordinates in each format that can be read back, and checks that parsing the output
+//! gives the same coordinate, to within the rounding of the format.
+#![no_main]
+
+use arbitrary::Arbitrary;
+use iso6709parse::testing::assert_roundtrip_with;
+use iso6709parse::{Format, FormatSpec, ISO6709Coord};
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Debug, Arbitrary)]
+struct Input {
+    lat: f64,
+    lon: f64,
+    /// Centimeters, so the altitude is written with few enough digits to be read back
+    altitude: Option<i32>,
+    decimals: u8,
+}
+
+const FORMATS: [Format; 6] = [
+    Format::Degrees,
+    Format::DegreesMinutes,
+    Format::DegreesMinutesSeconds,
+    Format::Readable,
+    Format::Wikipedia,
+    Format::WikipediaTemplate,
+];
+
+fuzz_target!(|input: Input| {
+    let altitude = input.altitude.map(|altitude| f64::from(altitude) / 100.);
+    let Ok(coord) = ISO6709Coord::new(input.lat, input.lon, altitude) else {
+        return;
+    };
+    let decimals = usize::from(input.decimals % 13);
+    for format in FORMATS {
+        let spec = FormatSpec {
+            format,
+            decimals,
+            ..Default::default()
+        };
+        assert_roundtrip_with(&coord, &spec);
+    }
+});
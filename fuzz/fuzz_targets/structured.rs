@@ -0,0 +1,179 @@
+//! Renders coordinates from arbitrary degrees, minutes, seconds, hemispheres and altitudes in either format, and
+//! checks that the parser accepts exactly the valid ones and reads them as the right coordinate. A mutated rendering
+//! has no known validity, so it is only checked to parse to a coordinate in range, if at all.
+#![no_main]
+
+use arbitrary::Arbitrary;
+use iso6709parse::{parse, ISO6709Coord};
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Debug, Arbitrary)]
+enum Layout {
+    Degrees,
+    DegreesMinutes,
+    DegreesMinutesSeconds,
+    Readable,
+}
+
+#[derive(Debug, Arbitrary)]
+struct Angle {
+    degrees: u16,
+    minutes: u8,
+    seconds: u8,
+    /// Digits after the decimal point of the last component
+    fraction: Option<u16>,
+    negative: bool,
+    /// Whether the string representation uses the hemisphere letter instead of the sign
+    letter: bool,
+}
+
+#[derive(Debug, Arbitrary)]
+enum Mutation {
+    Remove(u8),
+    Insert(u8, char),
+    Repeat(u8),
+}
+
+#[derive(Debug, Arbitrary)]
+struct Input {
+    layout: Layout,
+    lat: Angle,
+    lon: Angle,
+    /// Centimeters
+    altitude: Option<i32>,
+    mutation: Option<Mutation>,
+}
+
+impl Angle {
+    /// Keeps each component within the digits that it is written with
+    fn fit(&mut self, width: u32) {
+        self.degrees %= 10u16.pow(width);
+        self.minutes %= 100;
+        self.seconds %= 100;
+    }
+
+    /// The angle in degrees as written in `layout`
+    fn value(&self, layout: &Layout) -> f64 {
+        let fraction = self
+            .fraction
+            .map_or(0., |fraction| format!("0.{}", fraction).parse().unwrap());
+        let value = match layout {
+            Layout::Degrees => f64::from(self.degrees) + fraction,
+            Layout::DegreesMinutes => f64::from(self.degrees) + (f64::from(self.minutes) + fraction) / 60.,
+            _ => {
+                f64::from(self.degrees)
+                    + f64::from(self.minutes) / 60.
+                    + (f64::from(self.seconds) + fraction) / 3600.
+            }
+        };
+        if self.negative {
+            -value
+        } else {
+            value
+        }
+    }
+
+    fn is_valid(&self, layout: &Layout, limit: f64) -> bool {
+        let minutes_valid = match layout {
+            Layout::Degrees => true,
+            Layout::DegreesMinutes => self.minutes < 60,
+            Layout::DegreesMinutesSeconds => self.minutes < 60 && self.seconds < 60,
+            // The readable format adds minutes and seconds of 60 or more as they are
+            Layout::Readable => true,
+        };
+        minutes_valid && self.value(layout).abs() <= limit
+    }
+
+    fn render(&self, layout: &Layout, width: usize, hemispheres: (char, char)) -> String {
+        let hemisphere = if self.negative { hemispheres.1 } else { hemispheres.0 };
+        let fraction = self
+            .fraction
+            .map(|fraction| format!(".{}", fraction))
+            .unwrap_or_default();
+        let numbers = match layout {
+            Layout::Degrees => format!("{:0width$}{}", self.degrees, fraction),
+            Layout::DegreesMinutes => format!("{:0width$}{:02}{}", self.degrees, self.minutes, fraction),
+            Layout::DegreesMinutesSeconds => format!(
+                "{:0width$}{:02}{:02}{}",
+                self.degrees, self.minutes, self.seconds, fraction
+            ),
+            Layout::Readable => {
+                return format!(
+                    "{}°{:02}′{:02}{}″{}",
+                    self.degrees, self.minutes, self.seconds, fraction, hemisphere
+                )
+            }
+        };
+        let prefix = match (self.letter, self.negative) {
+            (true, _) => hemisphere,
+            (false, true) => '-',
+            (false, false) => '+',
+        };
+        format!("{}{}", prefix, numbers)
+    }
+}
+
+fn render(input: &Input) -> String {
+    let lat = input.lat.render(&input.layout, 2, ('N', 'S'));
+    let lon = input.lon.render(&input.layout, 3, ('E', 'W'));
+    let altitude = input.altitude.map(|altitude| f64::from(altitude) / 100.);
+    match (&input.layout, altitude) {
+        (Layout::Readable, None) => format!("{} {}", lat, lon),
+        (Layout::Readable, Some(altitude)) => format!("{} {} {}m", lat, lon, altitude),
+        (_, None) => format!("{}{}/", lat, lon),
+        (_, Some(altitude)) => format!("{}{}{:+}CRSWGS_84/", lat, lon, altitude),
+    }
+}
+
+fn mutate(rendered: &str, mutation: &Mutation) -> String {
+    let mut chars: Vec<char> = rendered.chars().collect();
+    let at = |index: u8| usize::from(index) % (chars.len() + 1);
+    match *mutation {
+        Mutation::Remove(index) => {
+            let index = at(index).min(chars.len() - 1);
+            chars.remove(index);
+        }
+        Mutation::Insert(index, char) => {
+            let index = at(index);
+            chars.insert(index, char);
+        }
+        Mutation::Repeat(index) => {
+            let index = at(index).min(chars.len() - 1);
+            chars.insert(index, chars[index]);
+        }
+    }
+    chars.into_iter().collect()
+}
+
+fuzz_target!(|input: Input| {
+    let mut input = input;
+    input.lat.fit(2);
+    input.lon.fit(3);
+    let rendered = render(&input);
+    if let Some(mutation) = &input.mutation {
+        let mutated = mutate(&rendered, mutation);
+        if let Ok(coord) = parse::<ISO6709Coord>(&mutated) {
+            assert!(
+                coord.lat.abs() <= 90. && coord.lon.abs() <= 180.,
+                "{:?} read as {:?}",
+                mutated,
+                coord
+            );
+        }
+        return;
+    }
+
+    let valid = input.lat.is_valid(&input.layout, 90.) && input.lon.is_valid(&input.layout, 180.);
+    match parse::<ISO6709Coord>(&rendered) {
+        Ok(coord) => {
+            assert!(valid, "invalid {:?} read as {:?}", rendered, coord);
+            let lat = input.lat.value(&input.layout);
+            let lon = input.lon.value(&input.layout);
+            assert!((coord.lat - lat).abs() < 1e-9, "{:?} read as {:?}", rendered, coord);
+            assert!((coord.lon - lon).abs() < 1e-9, "{:?} read as {:?}", rendered, coord);
+            let altitude = input.altitude.map(|altitude| f64::from(altitude) / 100.);
+            assert_eq!(coord.altitude, altitude, "{:?}", rendered);
+        }
+        Err(err) => assert!(!valid, "valid {:?} rejected: {}", rendered, err),
+    }
+});
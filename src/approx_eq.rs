@@ -0,0 +1,87 @@
+use crate::ISO6709Coord;
+use approx::{AbsDiffEq, RelativeEq};
+
+/// Compares the latitude, longitude and altitude with the same tolerance.
+/// Coordinates where only one of the two has an altitude are never equal.
+/// ```
+/// # use iso6709parse::{parse, ISO6709Coord};
+/// use approx::assert_abs_diff_eq;
+///
+/// let coord: ISO6709Coord = parse("50°40′46.461″N 95°48′26.533″W").unwrap();
+/// let expected = ISO6709Coord::from_parts(50.679573, -95.80737, None);
+/// assert_abs_diff_eq!(coord, expected, epsilon = 1e-6);
+/// ```
+impl<T: AbsDiffEq> AbsDiffEq for ISO6709Coord<T>
+where
+    T::Epsilon: Copy,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.lat.abs_diff_eq(&other.lat, epsilon)
+            && self.lon.abs_diff_eq(&other.lon, epsilon)
+            && match (&self.altitude, &other.altitude) {
+                (Some(a), Some(b)) => a.abs_diff_eq(b, epsilon),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
+
+impl<T: RelativeEq> RelativeEq for ISO6709Coord<T>
+where
+    T::Epsilon: Copy,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.lat.relative_eq(&other.lat, epsilon, max_relative)
+            && self.lon.relative_eq(&other.lon, epsilon, max_relative)
+            && match (&self.altitude, &other.altitude) {
+                (Some(a), Some(b)) => a.relative_eq(b, epsilon, max_relative),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::{assert_abs_diff_ne, assert_relative_eq};
+
+    #[test]
+    fn should_compare_approx() {
+        let a = ISO6709Coord {
+            lat: 35.5,
+            lon: -170.1,
+            altitude: Some(8712.),
+        };
+        let b = ISO6709Coord {
+            lat: 35.5 + 1e-9,
+            lon: -170.1 - 1e-9,
+            altitude: Some(8712. + 1e-9),
+        };
+        assert!(a.abs_diff_eq(&b, 1e-8));
+        assert!(!a.abs_diff_eq(&b, 1e-10));
+        assert_relative_eq!(a, b, max_relative = 1e-9);
+
+        let no_altitude = ISO6709Coord {
+            altitude: None,
+            ..a
+        };
+        assert_abs_diff_ne!(a, no_altitude, epsilon = 1.);
+        assert!(no_altitude.abs_diff_eq(&no_altitude, 0.));
+    }
+}
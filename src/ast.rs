@@ -0,0 +1,367 @@
+//! Span-tagged syntax tree for ISO6709 strings.
+//!
+//! Where the `parse*` functions only return the resulting numbers, [`parse_ast`] keeps every component of the
+//! input (sign, degrees, minutes, seconds, altitude, unit and CRS) together with the byte range it was read from.
+//! This makes it possible to lint, highlight or rewrite parts of a coordinate string without re-parsing it by hand.
+use crate::error::ISO6709Error;
+use crate::parsers::altitude::human_readable::altitude_unit;
+use crate::parsers::common::{AngleParts, Component};
+use crate::parsers::iso6709::human_readable::latlong_altitude_parts_with;
+use crate::parsers::iso6709::string_expression::latlong_altitude_crs_parts;
+use crate::{Format, Leniency, Symbols};
+use nom::bytes::complete::tag;
+use nom::character::complete::multispace0;
+use nom::combinator::{all_consuming, opt};
+use nom::sequence::{delimited, preceded};
+use nom::{Finish, IResult, Offset};
+
+/// Byte range of a component within the parsed string. `start` is inclusive, `end` is exclusive, so
+/// `&input[span.start..span.end]` returns the text of the component.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+/// A value read from the input, along with where it was read from.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Spanned<T> {
+    pub value: T,
+    pub span: Span,
+}
+
+/// Direction of a coordinate component. For the readable format this comes from the hemisphere letter,
+/// for the string representation it comes from either the `+`/`-` sign or the hemisphere letter.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Sign {
+    Positive,
+    Negative,
+}
+
+impl Sign {
+    fn multiplier(self) -> f64 {
+        match self {
+            Sign::Positive => 1.,
+            Sign::Negative => -1.,
+        }
+    }
+}
+
+/// Which of the two ISO6709 formats the string was written in.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Notation {
+    /// `15°30′00.000″N 95°15′00.000″W`
+    Readable,
+    /// `N35.50W170.10+8712CRSWGS_85/`
+    StringRepresentation,
+}
+
+/// A latitude or longitude. Any decimal fraction belongs to the last component present, as it does in the input,
+/// so `+4520.30` has `20.30` minutes and no seconds.
+#[derive(Debug, PartialEq, Clone)]
+pub struct AngleNode {
+    pub sign: Spanned<Sign>,
+    pub degrees: Spanned<f64>,
+    pub minutes: Option<Spanned<f64>>,
+    pub seconds: Option<Spanned<f64>>,
+}
+
+impl AngleNode {
+    /// The signed value of the angle in decimal degrees
+    pub fn decimal_degrees(&self) -> f64 {
+        let minutes = self.minutes.as_ref().map_or(0., |m| m.value);
+        let seconds = self.seconds.as_ref().map_or(0., |s| s.value);
+        self.sign.value.multiplier() * (self.degrees.value + minutes / 60. + seconds / 3600.)
+    }
+}
+
+/// The optional third coordinate. `unit` is only found in the readable format, `crs` only in the string representation.
+/// An altitude in the string representation without a CRS has none, and is left out by [`crate::parse`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct AltitudeNode<'a> {
+    pub value: Spanned<f64>,
+    pub unit: Option<Spanned<&'a str>>,
+    pub crs: Option<Spanned<&'a str>>,
+}
+
+/// Root of the tree. `span` covers the coordinate itself, excluding any surrounding whitespace.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CoordinateAst<'a> {
+    pub notation: Notation,
+    pub latitude: AngleNode,
+    pub longitude: AngleNode,
+    pub altitude: Option<AltitudeNode<'a>>,
+    pub span: Span,
+}
+
+impl CoordinateAst<'_> {
+    /// The format the coordinate was written in, going by the last component of the latitude
+    pub(crate) fn format(&self) -> Format {
+        match self.notation {
+            Notation::Readable => Format::Readable,
+            Notation::StringRepresentation if self.latitude.seconds.is_some() => {
+                Format::DegreesMinutesSeconds
+            }
+            Notation::StringRepresentation if self.latitude.minutes.is_some() => {
+                Format::DegreesMinutes
+            }
+            Notation::StringRepresentation => Format::Degrees,
+        }
+    }
+}
+
+/// Parses either of the two formats into a [`CoordinateAst`]. Unlike [`crate::parse`], the whole string
+/// (other than surrounding whitespace) must be part of the coordinate.
+/// ```
+/// # use iso6709parse::ast::parse_ast;
+/// let str = "N35.50W170.10+8712CRSWGS_85/";
+/// let ast = parse_ast(str).unwrap();
+/// let crs = ast.altitude.unwrap().crs.unwrap();
+/// assert_eq!(crs.value, "WGS_85");
+/// assert_eq!(&str[crs.span.start..crs.span.end], "WGS_85");
+/// assert_eq!(ast.longitude.decimal_degrees(), -170.1);
+/// ```
+pub fn parse_ast(str: &str) -> Result<CoordinateAst<'_>, ISO6709Error> {
+    let symbols = Symbols::standard();
+    let parsed =
+        match all_consuming(delimited(multispace0, readable(str, symbols), multispace0))(str)
+            .finish()
+        {
+            Ok((_, parts)) => parts,
+            Err(_) => {
+                all_consuming(delimited(
+                    multispace0,
+                    string_representation(str, Leniency::Standard),
+                    multispace0,
+                ))(str)
+                .finish()?
+                .1
+            }
+        };
+    Ok(tree(str, parsed))
+}
+
+/// Same as [`parse_ast`], but anything after the coordinate is ignored, the same as [`crate::parse`]
+pub(crate) fn parse_ast_prefix(str: &str) -> Option<CoordinateAst<'_>> {
+    parse_ast_prefix_with(str, Symbols::standard(), Leniency::Standard)
+}
+
+/// Same as [`parse_ast_prefix`], with the markers in `symbols` and the `CRS` tag read as `leniency` reads it
+pub(crate) fn parse_ast_prefix_with<'a>(
+    str: &'a str,
+    symbols: &Symbols,
+    leniency: Leniency,
+) -> Option<CoordinateAst<'a>> {
+    let parsed = match preceded(multispace0, readable(str, symbols))(str) {
+        Ok((_, parts)) => parts,
+        Err(_) => {
+            preceded(multispace0, string_representation(str, leniency))(str)
+                .ok()?
+                .1
+        }
+    };
+    Some(tree(str, parsed))
+}
+
+fn tree<'a>(source: &'a str, parts: Parts<'a>) -> CoordinateAst<'a> {
+    let (notation, latitude, longitude, altitude, matched) = parts;
+    CoordinateAst {
+        notation,
+        latitude,
+        longitude,
+        altitude,
+        span: span(source, matched),
+    }
+}
+
+type Parts<'a> = (
+    Notation,
+    AngleNode,
+    AngleNode,
+    Option<AltitudeNode<'a>>,
+    &'a str,
+);
+
+fn span(source: &str, part: &str) -> Span {
+    let start = source.offset(part);
+    Span {
+        start,
+        end: start + part.len(),
+    }
+}
+
+fn spanned<T>(source: &str, part: &str, value: T) -> Spanned<T> {
+    Spanned {
+        value,
+        span: span(source, part),
+    }
+}
+
+/// The node of an angle read by the parsers of [`crate::parsers`]
+fn angle(source: &str, parts: AngleParts) -> AngleNode {
+    let component = |(text, value): Component| spanned(source, text, value);
+    let sign = if parts.sign.1 < 0. {
+        Sign::Negative
+    } else {
+        Sign::Positive
+    };
+    AngleNode {
+        sign: spanned(source, parts.sign.0, sign),
+        degrees: component(parts.degrees),
+        minutes: parts.minutes.map(component),
+        seconds: parts.seconds.map(component),
+    }
+}
+
+fn readable<'s, 'a: 's>(
+    source: &'a str,
+    symbols: &'s Symbols,
+) -> impl Fn(&'a str) -> IResult<&'a str, Parts<'a>> + 's {
+    move |inp| {
+        let (rem, (latitude, longitude, altitude)) = latlong_altitude_parts_with(symbols)(inp)?;
+        let (rem, unit) = match altitude {
+            Some(_) => opt(altitude_unit)(rem)?,
+            None => (rem, None),
+        };
+        let altitude = altitude.map(|(number, value)| AltitudeNode {
+            value: spanned(source, number, value),
+            unit: unit.map(|unit| spanned(source, unit, unit)),
+            crs: None,
+        });
+        let matched = &inp[..inp.offset(rem)];
+        Ok((
+            rem,
+            (
+                Notation::Readable,
+                angle(source, latitude),
+                angle(source, longitude),
+                altitude,
+                matched,
+            ),
+        ))
+    }
+}
+
+fn string_representation<'a>(
+    source: &'a str,
+    leniency: Leniency,
+) -> impl Fn(&'a str) -> IResult<&'a str, Parts<'a>> {
+    move |inp| {
+        let (rem, (latitude, longitude, altitude)) = latlong_altitude_crs_parts(leniency)(inp)?;
+        let altitude = altitude.map(|((number, value), crs)| AltitudeNode {
+            value: spanned(source, number, value),
+            unit: None,
+            crs: crs.map(|crs| spanned(source, crs, crs)),
+        });
+        let matched = &inp[..inp.offset(rem)];
+        let (rem, _) = opt(tag("/"))(rem)?;
+        Ok((
+            rem,
+            (
+                Notation::StringRepresentation,
+                angle(source, latitude),
+                angle(source, longitude),
+                altitude,
+                matched,
+            ),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(source: &str, span: Span) -> &str {
+        &source[span.start..span.end]
+    }
+
+    #[test]
+    fn should_span_readable() {
+        let str = " 15°30′00.000″N 95°15′00.000″W 123.45m";
+        let ast = parse_ast(str).unwrap();
+        assert_eq!(ast.notation, Notation::Readable);
+        assert_eq!(text(str, ast.span), "15°30′00.000″N 95°15′00.000″W 123.45m");
+        assert_eq!(text(str, ast.latitude.degrees.span), "15");
+        assert_eq!(text(str, ast.latitude.minutes.unwrap().span), "30");
+        assert_eq!(text(str, ast.latitude.seconds.unwrap().span), "00.000");
+        assert_eq!(text(str, ast.latitude.sign.span), "N");
+        assert_eq!(text(str, ast.longitude.sign.span), "W");
+        assert_eq!(ast.longitude.decimal_degrees(), -95.25);
+
+        let altitude = ast.altitude.unwrap();
+        assert_eq!(text(str, altitude.value.span), "123.45");
+        assert_eq!(altitude.unit.unwrap().value, "m");
+
+        let str = "15°30′00.000″N 95°15′00.000″W 123.45 m";
+        let unit = parse_ast(str).unwrap().altitude.unwrap().unit.unwrap();
+        assert_eq!(text(str, unit.span), "m");
+    }
+
+    #[test]
+    fn should_span_string_representation() {
+        let str = "+4520.30-02130.00-12.5CRSWGS_84/";
+        let ast = parse_ast(str).unwrap();
+        assert_eq!(ast.notation, Notation::StringRepresentation);
+        assert_eq!(text(str, ast.latitude.sign.span), "+");
+        assert_eq!(text(str, ast.latitude.degrees.span), "45");
+        let minutes = ast.latitude.minutes.clone().unwrap();
+        assert_eq!(text(str, minutes.span), "20.30");
+        assert_eq!(minutes.value, 20.3);
+        assert!(ast.latitude.seconds.is_none());
+        assert_eq!(ast.longitude.sign.value, Sign::Negative);
+        assert_eq!(ast.longitude.decimal_degrees(), -21.5);
+
+        let altitude = ast.altitude.unwrap();
+        assert_eq!(altitude.value.value, -12.5);
+        assert_eq!(text(str, altitude.crs.unwrap().span), "WGS_84");
+
+        let str = "+35.50-170.10+12/";
+        let altitude = parse_ast(str).unwrap().altitude.unwrap();
+        assert_eq!((altitude.value.value, altitude.crs), (12., None));
+
+        let str = "N452035.1528W1452018";
+        let ast = parse_ast(str).unwrap();
+        assert_eq!(text(str, ast.latitude.seconds.unwrap().span), "35.1528");
+        assert_eq!(text(str, ast.longitude.degrees.span), "145");
+        assert_eq!(text(str, ast.longitude.seconds.unwrap().span), "18");
+    }
+
+    #[test]
+    fn should_err_ast() {
+        assert!(parse_ast("N35.50W170.10 trailing").is_err());
+        assert!(parse_ast("+123-02130").is_err());
+        assert!(parse_ast("N4560W17010").is_err());
+        assert!(parse_ast("+90.1-021.0").is_err());
+        assert!(parse_ast("90°40′46.461″N 95°48′26.533″W").is_err());
+        assert!(parse_ast("89°60′01″N 95°15′00″W").is_err());
+    }
+
+    #[test]
+    fn should_agree_with_parse() {
+        for str in [
+            "15°30′00.000″N 95°15′00.000″W 123.45m",
+            "50°40'46.461\"S 95°48′26.533″E",
+            "N35.50W170.10+8712CRSWGS_85/",
+            "+4520.30-02130.00-12.5CRSWGS_84/",
+            "S452035.1528E1452018",
+        ] {
+            let coord = crate::parse_coord(str).unwrap();
+            let ast = parse_ast(str).unwrap();
+            assert!(
+                (ast.latitude.decimal_degrees() - coord.lat).abs() < 1e-12,
+                "{}",
+                str
+            );
+            assert!(
+                (ast.longitude.decimal_degrees() - coord.lon).abs() < 1e-12,
+                "{}",
+                str
+            );
+            assert_eq!(
+                ast.altitude.map(|altitude| altitude.value.value),
+                coord.altitude
+            );
+        }
+    }
+}
@@ -0,0 +1,259 @@
+use crate::parsers::common::{digits0, digits1};
+use crate::{trim, ErrorKind, ISO6709Coord, ISO6709Error};
+use nom::branch::alt;
+use nom::bytes::complete::{tag, tag_no_case, take_while_m_n};
+use nom::character::complete::{satisfy, space0};
+use nom::combinator::{all_consuming, map_res, not, opt, recognize, value};
+use nom::sequence::{pair, preceded, terminated, tuple};
+use nom::{Finish, IResult};
+
+const METERS_PER_FOOT: f64 = 0.3048;
+
+/// The unit an altitude was written in
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum AltitudeUnit {
+    Meters,
+    Feet,
+}
+
+/// What an aviation altitude is measured from
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum AltitudeReference {
+    /// `MSL` or `AMSL`
+    MeanSeaLevel,
+    /// `AGL`
+    AboveGroundLevel,
+    /// A flight level, which is a pressure altitude from the standard pressure of 1013.25 hPa rather than a height
+    /// above anything on the ground
+    FlightLevel,
+}
+
+/// An altitude written the way aviation writes it, such as `FL350`, `3500 ft MSL` or `1200 AGL`.
+/// Without a unit, the altitude is in feet.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[non_exhaustive]
+pub struct AltitudeNotation {
+    /// The altitude in `unit`, which for a flight level is the level times 100 feet
+    pub value: f64,
+    pub unit: AltitudeUnit,
+    pub reference: Option<AltitudeReference>,
+}
+
+impl AltitudeNotation {
+    /// The altitude in meters, as ISO6709 gives it
+    /// ```
+    /// # use iso6709parse::parse_altitude_notation;
+    /// assert_eq!(parse_altitude_notation("FL350").unwrap().meters(), 10668.);
+    /// ```
+    pub fn meters(&self) -> f64 {
+        match self.unit {
+            AltitudeUnit::Meters => self.value,
+            AltitudeUnit::Feet => self.value * METERS_PER_FOOT,
+        }
+    }
+}
+
+/// Parses an aviation altitude on its own: a flight level such as `FL350`, or an altitude followed by a unit and a
+/// reference, either of which may be left out, such as `3500 ft MSL`, `1200 AGL` or `900 m AMSL`. An altitude that
+/// has neither is not aviation notation, and fails with [`ErrorKind::Syntax`].
+/// Readable coordinates may be followed by the same notation, as in `50°40′46″N 95°48′27″W FL350`.
+/// ```
+/// # use iso6709parse::{parse_altitude_notation, AltitudeReference, AltitudeUnit};
+/// let altitude = parse_altitude_notation("3500 ft MSL").unwrap();
+/// assert_eq!(altitude.value, 3500.);
+/// assert_eq!(altitude.unit, AltitudeUnit::Feet);
+/// assert_eq!(altitude.reference, Some(AltitudeReference::MeanSeaLevel));
+/// assert_eq!(altitude.meters(), 1066.8);
+/// ```
+pub fn parse_altitude_notation(str: &str) -> Result<AltitudeNotation, ISO6709Error> {
+    read_notation(str).map_err(|err| err.with_input(str))
+}
+
+fn read_notation(str: &str) -> Result<AltitudeNotation, ISO6709Error> {
+    let (_, notation) = all_consuming(trim(notation))(str).finish()?;
+    notation.ok_or_else(|| {
+        ISO6709Error::new(
+            ErrorKind::Syntax,
+            "an aviation altitude needs a unit, a reference, or both",
+        )
+    })
+}
+
+/// The notation after the altitude of a readable coordinate, or a flight level in place of it, along with the
+/// coordinate with the altitude in meters and the rest of the input
+pub(crate) fn altitude_suffix(
+    coord: ISO6709Coord,
+    rest: &str,
+) -> Option<(ISO6709Coord, AltitudeNotation, &str)> {
+    let (rest, notation) = match coord.altitude {
+        Some(altitude) => {
+            let (rest, notation) = suffix(altitude)(rest).ok()?;
+            (rest, notation?)
+        }
+        None => {
+            let (rest, notation) = flight_level(rest.trim_start()).ok()?;
+            (rest, notation?)
+        }
+    };
+    let coord = ISO6709Coord::unsigned_zero(coord.lat, coord.lon, Some(notation.meters()));
+    Some((coord, notation, rest))
+}
+
+fn notation(inp: &str) -> IResult<&str, Option<AltitudeNotation>> {
+    alt((flight_level, altitude))(inp)
+}
+
+fn flight_level(inp: &str) -> IResult<&str, Option<AltitudeNotation>> {
+    let (rem, level) = terminated(
+        preceded(
+            pair(tag_no_case("FL"), space0),
+            map_res(
+                take_while_m_n(1, 3, |ch: char| ch.is_ascii_digit()),
+                str::parse::<u16>,
+            ),
+        ),
+        not(satisfy(char::is_alphanumeric)),
+    )(inp)?;
+    Ok((
+        rem,
+        Some(AltitudeNotation {
+            value: f64::from(level) * 100.,
+            unit: AltitudeUnit::Feet,
+            reference: Some(AltitudeReference::FlightLevel),
+        }),
+    ))
+}
+
+fn altitude(inp: &str) -> IResult<&str, Option<AltitudeNotation>> {
+    let (rem, value) = map_res(
+        recognize(tuple((
+            opt(tag("-")),
+            digits1,
+            opt(pair(tag("."), digits0)),
+        ))),
+        str::parse::<f64>,
+    )(inp)?;
+    suffix(value)(rem)
+}
+
+/// The unit and reference, giving `None` if there are neither, or the unit is meters without a reference, as that is
+/// how readable coordinates are written anyway
+fn suffix<'a>(value: f64) -> impl Fn(&'a str) -> IResult<&'a str, Option<AltitudeNotation>> {
+    move |inp| {
+        let (rem, (unit, reference)) = pair(
+            opt(preceded(space0, word(unit))),
+            opt(preceded(space0, word(reference))),
+        )(inp)?;
+        let notation = match (unit, reference) {
+            (None | Some(AltitudeUnit::Meters), None) => None,
+            (unit, reference) => Some(AltitudeNotation {
+                value,
+                unit: unit.unwrap_or(AltitudeUnit::Feet),
+                reference,
+            }),
+        };
+        Ok((if notation.is_some() { rem } else { inp }, notation))
+    }
+}
+
+/// `parser`, when it is not followed by more letters or digits, so that `m` is not read from `MSL`
+fn word<'a, O>(
+    parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O> {
+    terminated(parser, not(satisfy(char::is_alphanumeric)))
+}
+
+fn unit(inp: &str) -> IResult<&str, AltitudeUnit> {
+    alt((
+        value(
+            AltitudeUnit::Feet,
+            alt((tag_no_case("feet"), tag_no_case("ft"))),
+        ),
+        value(AltitudeUnit::Meters, tag_no_case("m")),
+    ))(inp)
+}
+
+fn reference(inp: &str) -> IResult<&str, AltitudeReference> {
+    alt((
+        value(
+            AltitudeReference::MeanSeaLevel,
+            alt((tag_no_case("AMSL"), tag_no_case("MSL"))),
+        ),
+        value(AltitudeReference::AboveGroundLevel, tag_no_case("AGL")),
+    ))(inp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_notation() {
+        assert_eq!(
+            parse_altitude_notation(" fl 050 "),
+            Ok(AltitudeNotation {
+                value: 5000.,
+                unit: AltitudeUnit::Feet,
+                reference: Some(AltitudeReference::FlightLevel),
+            })
+        );
+        assert_eq!(
+            parse_altitude_notation("1200 AGL"),
+            Ok(AltitudeNotation {
+                value: 1200.,
+                unit: AltitudeUnit::Feet,
+                reference: Some(AltitudeReference::AboveGroundLevel),
+            })
+        );
+        assert_eq!(
+            parse_altitude_notation("-20.5m AMSL"),
+            Ok(AltitudeNotation {
+                value: -20.5,
+                unit: AltitudeUnit::Meters,
+                reference: Some(AltitudeReference::MeanSeaLevel),
+            })
+        );
+        assert_eq!(
+            parse_altitude_notation("3500 feet").unwrap().meters(),
+            1066.8
+        );
+    }
+
+    #[test]
+    fn should_err_notation() {
+        for str in [
+            "3500",
+            "3500 m",
+            "FL",
+            "FL3500",
+            "FL350 MSL",
+            "3500 ft MSLX",
+            "3500 km",
+        ] {
+            assert_eq!(
+                parse_altitude_notation(str).unwrap_err().kind(),
+                ErrorKind::Syntax,
+                "{}",
+                str
+            );
+        }
+    }
+
+    #[test]
+    fn should_read_suffix() {
+        let coord = ISO6709Coord::from_parts(15.5, -95.25, Some(3500.));
+        let (coord, notation, rest) = altitude_suffix(coord, " ft MSL (WGS84)").unwrap();
+        assert_eq!(coord.altitude, Some(1066.8));
+        assert_eq!(notation.reference, Some(AltitudeReference::MeanSeaLevel));
+        assert_eq!(rest, " (WGS84)");
+        assert!(altitude_suffix(coord, "m (WGS84)").is_none());
+        assert!(altitude_suffix(coord, "").is_none());
+
+        let coord = ISO6709Coord::from_parts(15.5, -95.25, None);
+        let (coord, _, rest) = altitude_suffix(coord, " FL350").unwrap();
+        assert_eq!(coord.altitude, Some(10668.));
+        assert_eq!(rest, "");
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{parse, ErrorKind, ISO6709Coord, ISO6709Error};
+
+/// Parses a bounding box written as its south-west corner and north-east corner, each terminated by a solidus,
+/// such as `+12+034/+13+035/`. Either format can be used for the corners.
+///
+/// A box that crosses the antimeridian has a south-west longitude greater than its north-east longitude.
+/// Since a `Rect` cannot wrap, 360° is added to the east edge, so `+10+170/+20-170/` spans from x = 170 to x = 190.
+/// ```
+/// # use iso6709parse::parse_bbox;
+/// let rect = parse_bbox("+12+034/+13+035/").unwrap();
+/// assert_eq!(rect.min(), geo_types::coord! { x: 34., y: 12. });
+/// assert_eq!(rect.max(), geo_types::coord! { x: 35., y: 13. });
+///
+/// let rect = parse_bbox("+10+170/+20-170/").unwrap();
+/// assert_eq!(rect.width(), 20.);
+/// ```
+pub fn parse_bbox(str: &str) -> Result<geo_types::Rect, ISO6709Error> {
+    let mut last_err = None;
+    for (index, _) in str.match_indices('/') {
+        let (south_west, north_east) = str.split_at(index + 1);
+        if north_east.trim().is_empty() {
+            continue;
+        }
+        match parse_bbox_corners(south_west, north_east) {
+            Ok(rect) => return Ok(rect),
+            Err(err) => last_err = Some(err),
+        }
+    }
+    Err(last_err.unwrap_or_else(|| {
+        ISO6709Error::new(
+            ErrorKind::InvalidBoundingBox,
+            "expected two corners separated by `/`",
+        )
+    }))
+}
+
+/// Same as [`parse_bbox`], with the two corners given separately
+/// ```
+/// # use iso6709parse::parse_bbox_corners;
+/// let rect = parse_bbox_corners("15°30′00″N 95°15′00″W", "16°30′00″N 94°15′00″W").unwrap();
+/// assert_eq!(rect.height(), 1.);
+/// ```
+pub fn parse_bbox_corners(
+    south_west: &str,
+    north_east: &str,
+) -> Result<geo_types::Rect, ISO6709Error> {
+    let south_west: ISO6709Coord = parse(south_west)?;
+    let north_east: ISO6709Coord = parse(north_east)?;
+    if south_west.lat > north_east.lat {
+        return Err(ISO6709Error::new(
+            ErrorKind::InvalidBoundingBox,
+            "the south-west corner is north of the north-east corner",
+        ));
+    }
+    let east = if south_west.lon > north_east.lon {
+        north_east.lon + 360.
+    } else {
+        north_east.lon
+    };
+    Ok(geo_types::Rect::new(
+        geo_types::coord! { x: south_west.lon, y: south_west.lat },
+        geo_types::coord! { x: east, y: north_east.lat },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_bbox() {
+        let rect = parse_bbox("N35.50W170.10/N36.50W169.10/").unwrap();
+        assert_eq!(rect.min(), geo_types::coord! { x: -170.1, y: 35.5 });
+        assert_eq!(rect.max(), geo_types::coord! { x: -169.1, y: 36.5 });
+
+        let rect = parse_bbox(" +12+034+10CRSWGS_84/+13+035+20CRSWGS_84/ ").unwrap();
+        assert_eq!(rect.min(), geo_types::coord! { x: 34., y: 12. });
+
+        let rect = parse_bbox("15°30′00″N 95°15′00″W/16°30′00″N 94°15′00″W").unwrap();
+        assert_eq!(rect.max(), geo_types::coord! { x: -94.25, y: 16.5 });
+    }
+
+    #[test]
+    fn should_cross_antimeridian() {
+        let rect = parse_bbox("-10+175/+10-175/").unwrap();
+        assert_eq!(rect.min(), geo_types::coord! { x: 175., y: -10. });
+        assert_eq!(rect.max(), geo_types::coord! { x: 185., y: 10. });
+    }
+
+    #[test]
+    fn should_err_bbox() {
+        assert_eq!(
+            parse_bbox("+12+034/").unwrap_err().kind(),
+            ErrorKind::InvalidBoundingBox
+        );
+        assert_eq!(
+            parse_bbox("+13+034/+12+035/").unwrap_err().kind(),
+            ErrorKind::InvalidBoundingBox
+        );
+        assert_eq!(
+            parse_bbox("+12+034/+1x+035/").unwrap_err().kind(),
+            ErrorKind::Syntax
+        );
+    }
+}
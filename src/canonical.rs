@@ -0,0 +1,229 @@
+use crate::ISO6709Coord;
+use std::fmt::Write;
+use std::hash::{Hash, Hasher};
+
+/// Past this, the digits are below what an `f64` of a few hundred degrees can hold
+const MAX_KEY_DECIMALS: u32 = 12;
+
+/// Wrapper giving [`ISO6709Coord`] `Eq` and `Hash`, so parsed coordinates can be used as `HashMap` keys
+/// or collected into a `HashSet`.
+///
+/// Values are compared by their bit patterns after canonicalizing them: `-0.0` is the same as `0.0`, and every
+/// NaN is the same as every other NaN. Otherwise, values must be exactly equal.
+/// ```
+/// # use iso6709parse::{parse, CanonicalCoord, ISO6709Coord};
+/// use std::collections::HashSet;
+///
+/// let mut seen = HashSet::new();
+/// assert!(seen.insert(CanonicalCoord::from(parse::<ISO6709Coord>("+00.00+000.00/").unwrap())));
+/// assert!(!seen.insert(CanonicalCoord::from(parse::<ISO6709Coord>("-00.00-000.00/").unwrap())));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CanonicalCoord(pub ISO6709Coord);
+
+impl CanonicalCoord {
+    pub fn into_inner(self) -> ISO6709Coord {
+        self.0
+    }
+
+    fn key(&self) -> (u64, u64, Option<u64>) {
+        (
+            canonical_bits(self.0.lat),
+            canonical_bits(self.0.lon),
+            self.0.altitude.map(canonical_bits),
+        )
+    }
+}
+
+fn canonical_bits(value: f64) -> u64 {
+    if value == 0. {
+        0f64.to_bits()
+    } else if value.is_nan() {
+        f64::NAN.to_bits()
+    } else {
+        value.to_bits()
+    }
+}
+
+impl ISO6709Coord {
+    /// A key that is the same for every coordinate that rounds to the same `decimals` decimal places, for hashing and
+    /// deduplicating datasets the same way from run to run. The latitude and longitude are rounded in degrees and the
+    /// altitude in meters, at most 12 decimal places.
+    ///
+    /// Unlike [`CanonicalCoord`], the key is built from integers, so it does not depend on how floats are printed:
+    /// `-0` is the same as `0`, a longitude of 180 is the same as -180 and longitudes outside of ±180 are wrapped,
+    /// and every longitude at a pole is the same. A NaN or infinite value is written as `NaN`, `inf` or `-inf`, and a
+    /// value too large to quantize is written in full without rounding.
+    /// ```
+    /// # use iso6709parse::{parse, ISO6709Coord};
+    /// let coord: ISO6709Coord = parse("+35.501-170.099+12.3CRSWGS_84/").unwrap();
+    /// assert_eq!(coord.canonical_key(2), "35.50,-170.10,12.30");
+    ///
+    /// let east: ISO6709Coord = parse("-00.001+180.00/").unwrap();
+    /// let west: ISO6709Coord = parse("+00.00-180.00/").unwrap();
+    /// assert_eq!(east.canonical_key(2), west.canonical_key(2));
+    /// ```
+    pub fn canonical_key(&self, decimals: u32) -> String {
+        let decimals = decimals.min(MAX_KEY_DECIMALS);
+        let lat = quantize(self.lat, decimals);
+        // Wrapped before it is quantized, as a huge longitude would not fit in the integer
+        let lon = if self.lon.is_finite() && self.lon.abs() > 180. {
+            self.lon.rem_euclid(360.)
+        } else {
+            self.lon
+        };
+        let lon = quantize(lon, decimals).map(|lon| {
+            let unit = 10i128.pow(decimals);
+            if lat.is_ok_and(|lat| lat.unsigned_abs() >= 90 * unit as u128) {
+                0
+            } else {
+                (lon + 180 * unit).rem_euclid(360 * unit) - 180 * unit
+            }
+        });
+
+        let mut key = String::new();
+        write_fixed(&mut key, lat, decimals);
+        key.push(',');
+        write_fixed(&mut key, lon, decimals);
+        if let Some(altitude) = self.altitude {
+            key.push(',');
+            write_fixed(&mut key, quantize(altitude, decimals), decimals);
+        }
+        key
+    }
+}
+
+/// The value in units of the last decimal place, or the value itself if it is not finite or too large to fit
+fn quantize(value: f64, decimals: u32) -> Result<i128, f64> {
+    let scaled = (value * 10f64.powi(decimals as i32)).round();
+    // i128::MAX as f64 rounds up to 2^127, which does not fit
+    if scaled.is_finite() && scaled.abs() < i128::MAX as f64 {
+        Ok(scaled as i128)
+    } else {
+        Err(value)
+    }
+}
+
+fn write_fixed(out: &mut String, value: Result<i128, f64>, decimals: u32) {
+    let _ = match value {
+        Ok(value) => {
+            let unit = 10u128.pow(decimals);
+            let sign = if value < 0 { "-" } else { "" };
+            let (whole, fraction) = (value.unsigned_abs() / unit, value.unsigned_abs() % unit);
+            if decimals == 0 {
+                write!(out, "{}{}", sign, whole)
+            } else {
+                write!(
+                    out,
+                    "{}{}.{:0width$}",
+                    sign,
+                    whole,
+                    fraction,
+                    width = decimals as usize
+                )
+            }
+        }
+        Err(value) if value.is_nan() => write!(out, "NaN"),
+        Err(value) => write!(out, "{}", value),
+    };
+}
+
+impl From<ISO6709Coord> for CanonicalCoord {
+    fn from(value: ISO6709Coord) -> Self {
+        CanonicalCoord(value)
+    }
+}
+
+impl From<CanonicalCoord> for ISO6709Coord {
+    fn from(value: CanonicalCoord) -> Self {
+        value.0
+    }
+}
+
+impl PartialEq for CanonicalCoord {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for CanonicalCoord {}
+
+impl Hash for CanonicalCoord {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn canonical(lat: f64, lon: f64, altitude: Option<f64>) -> CanonicalCoord {
+        CanonicalCoord(ISO6709Coord { lat, lon, altitude })
+    }
+
+    #[test]
+    fn should_build_canonical_key() {
+        let key = |lat, lon, altitude, decimals| {
+            ISO6709Coord { lat, lon, altitude }.canonical_key(decimals)
+        };
+        assert_eq!(key(35.5, -170.1, None, 3), "35.500,-170.100");
+        assert_eq!(key(-0.004, 0.5, Some(-0.2), 0), "0,1,0");
+        assert_eq!(key(-0.04, 0.04, None, 1), "0.0,0.0");
+        assert_eq!(key(-5.5, 5., None, 1), "-5.5,5.0");
+        assert_eq!(key(1., 179.996, None, 2), key(1., -180., None, 2));
+        assert_eq!(key(1., 190., None, 0), "1,-170");
+        assert_eq!(key(90., 10., None, 2), key(89.999, -170., None, 2));
+        assert_eq!(key(-90., 10., None, 2), "-90.00,0.00");
+        assert_ne!(key(89.99, 10., None, 2), key(89.99, -170., None, 2));
+        assert_eq!(
+            key(f64::NAN, f64::INFINITY, Some(f64::NEG_INFINITY), 2),
+            "NaN,inf,-inf"
+        );
+        assert_eq!(key(1., 1e300, None, 12), key(1., 1e300 % 360., None, 12));
+        assert_eq!(
+            key(1., f64::MAX, None, 0),
+            key(1., f64::MAX % 360., None, 0)
+        );
+        assert_eq!(
+            key(1., -f64::MAX, None, 0),
+            key(1., -f64::MAX % 360., None, 0)
+        );
+        assert_eq!(key(1., -540., None, 2), "1.00,-180.00");
+        assert_eq!(key(-1e300, 10., None, 2), format!("{},10.00", -1e300));
+        assert_eq!(
+            key(f64::MAX, 10., Some(-f64::MAX), 12),
+            format!("{},10.000000000000,{}", f64::MAX, -f64::MAX)
+        );
+        assert_eq!(
+            key(1e30, 10., Some(1e30), 12),
+            format!("{},10.000000000000,{}", 1e30, 1e30)
+        );
+        assert_eq!(
+            key(f64::NEG_INFINITY, f64::NAN, Some(f64::NAN), 12),
+            "-inf,NaN,NaN"
+        );
+        assert_eq!(
+            key(1e9, 0., None, 20),
+            "1000000000.000000000000,0.000000000000"
+        );
+    }
+
+    #[test]
+    fn should_canonicalize() {
+        assert_eq!(canonical(-0., 0., Some(-0.)), canonical(0., -0., Some(0.)));
+        assert_eq!(
+            canonical(f64::NAN, 1., None),
+            canonical(-f64::NAN, 1., None)
+        );
+        assert_ne!(canonical(1., 2., None), canonical(1., 2., Some(0.)));
+        assert_ne!(canonical(1., 2., None), canonical(1., 2. + 1e-12, None));
+
+        let mut map = HashMap::new();
+        map.insert(canonical(-0., 10., None), "first");
+        map.insert(canonical(0., 10., None), "second");
+        assert_eq!(map.len(), 1);
+        assert_eq!(map[&canonical(0., 10., None)], "second");
+    }
+}
@@ -0,0 +1,230 @@
+use crate::parsers::common::{digits0, digits1};
+use crate::{ErrorKind, ISO6709Coord, ISO6709Error};
+use nom::branch::alt;
+use nom::bytes::complete::{tag, tag_no_case};
+use nom::character::complete::{one_of, satisfy, space0, space1};
+use nom::combinator::{all_consuming, map_res, not, opt, recognize, value};
+use nom::sequence::{delimited, pair, preceded, terminated, tuple};
+use nom::IResult;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+impl Direction {
+    fn is_latitude(self) -> bool {
+        matches!(self, Direction::North | Direction::South)
+    }
+
+    fn sign(self) -> f64 {
+        match self {
+            Direction::North | Direction::East => 1.,
+            Direction::South | Direction::West => -1.,
+        }
+    }
+}
+
+/// Degrees, with the minutes and seconds there are
+type Components = (f64, Option<f64>, Option<f64>);
+
+/// Reads coordinates with the directions spelled out, as in OCR'd and transcribed documents:
+/// `North 50 degrees 40 minutes 46 seconds, West 95 degrees 48 minutes 27 seconds` or `50.68 North, 95.81 West`.
+/// The direction may come before or after each angle, and the latitude and longitude may come in either order.
+/// Gives `None` if the input is not written this way, and fails if it is but a value is out of range.
+pub(crate) fn spelled_out(str: &str) -> Result<Option<ISO6709Coord>, ISO6709Error> {
+    let Ok((_, (first, second))) = all_consuming(delimited(
+        space0,
+        pair(
+            angle,
+            preceded(
+                alt((delimited(space0, one_of(",;"), space0), one_of(" "))),
+                angle,
+            ),
+        ),
+        space0,
+    ))(str) else {
+        return Ok(None);
+    };
+    let ((lat_direction, lat), (lon_direction, lon)) = match (first, second) {
+        (lat, lon) if lat.0.is_latitude() && !lon.0.is_latitude() => (lat, lon),
+        (lon, lat) if lat.0.is_latitude() && !lon.0.is_latitude() => (lat, lon),
+        _ => {
+            return Err(ISO6709Error::new(
+                ErrorKind::Syntax,
+                "one direction must be north or south, and the other east or west",
+            ))
+        }
+    };
+    let coord = ISO6709Coord::new(
+        lat_direction.sign() * degrees(lat)?,
+        lon_direction.sign() * degrees(lon)?,
+        None,
+    )?;
+    Ok(Some(ISO6709Coord::unsigned_zero(
+        coord.lat, coord.lon, None,
+    )))
+}
+
+fn degrees((degrees, minutes, seconds): Components) -> Result<f64, ISO6709Error> {
+    let (minutes, seconds) = (minutes.unwrap_or(0.), seconds.unwrap_or(0.));
+    if minutes >= 60. || seconds >= 60. {
+        return Err(ISO6709Error::new(
+            ErrorKind::Syntax,
+            "minutes and seconds must be less than 60",
+        ));
+    }
+    Ok(degrees + minutes / 60. + seconds / 3600.)
+}
+
+fn angle(inp: &str) -> IResult<&str, (Direction, Components)> {
+    alt((
+        pair(terminated(direction, space1), components),
+        map_swap(pair(terminated(components, space1), direction)),
+    ))(inp)
+}
+
+fn map_swap<'a>(
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, (Components, Direction)>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (Direction, Components)> {
+    move |inp| {
+        let (rem, (components, direction)) = parser(inp)?;
+        Ok((rem, (direction, components)))
+    }
+}
+
+fn components(inp: &str) -> IResult<&str, Components> {
+    tuple((
+        terminated(number, opt(preceded(space0, degrees_unit))),
+        opt(preceded(
+            space1,
+            terminated(number, preceded(space0, minutes_unit)),
+        )),
+        opt(preceded(
+            space1,
+            terminated(number, preceded(space0, seconds_unit)),
+        )),
+    ))(inp)
+}
+
+fn number(inp: &str) -> IResult<&str, f64> {
+    map_res(
+        recognize(pair(digits1, opt(pair(tag("."), digits0)))),
+        str::parse,
+    )(inp)
+}
+
+/// `parser`, when it is not followed by more letters or digits, so that `deg` is not read from `degrees`
+fn word<'a, O>(
+    parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O> {
+    terminated(parser, not(satisfy(char::is_alphanumeric)))
+}
+
+fn degrees_unit(inp: &str) -> IResult<&str, &str> {
+    alt((
+        word(alt((
+            tag_no_case("degrees"),
+            tag_no_case("degree"),
+            tag_no_case("deg"),
+        ))),
+        tag("°"),
+    ))(inp)
+}
+
+fn minutes_unit(inp: &str) -> IResult<&str, &str> {
+    alt((
+        word(alt((
+            tag_no_case("minutes"),
+            tag_no_case("minute"),
+            tag_no_case("min"),
+        ))),
+        tag("′"),
+        tag("'"),
+    ))(inp)
+}
+
+fn seconds_unit(inp: &str) -> IResult<&str, &str> {
+    alt((
+        word(alt((
+            tag_no_case("seconds"),
+            tag_no_case("second"),
+            tag_no_case("sec"),
+        ))),
+        tag("″"),
+        tag("\""),
+    ))(inp)
+}
+
+fn direction(inp: &str) -> IResult<&str, Direction> {
+    word(alt((
+        value(Direction::North, tag_no_case("north")),
+        value(Direction::South, tag_no_case("south")),
+        value(Direction::East, tag_no_case("east")),
+        value(Direction::West, tag_no_case("west")),
+    )))(inp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_read_spelled_out() {
+        let dms = ISO6709Coord::from_parts(
+            50. + 40. / 60. + 46. / 3600.,
+            -(95. + 48. / 60. + 27. / 3600.),
+            None,
+        );
+        for str in [
+            "North 50 degrees 40 minutes 46 seconds, West 95 degrees 48 minutes 27 seconds",
+            "50 degrees 40 minutes 46 seconds north; 95 degrees 48 minutes 27 seconds west",
+            " WEST 95 deg 48 min 27 sec, NORTH 50 deg 40 min 46 sec ",
+            "North 50° 40′ 46″ West 95° 48′ 27″",
+        ] {
+            assert_eq!(spelled_out(str), Ok(Some(dms)), "{}", str);
+        }
+        assert_eq!(
+            spelled_out("50.68 North, 95.81 West"),
+            Ok(Some(ISO6709Coord::from_parts(50.68, -95.81, None)))
+        );
+        assert_eq!(
+            spelled_out("South 0 degrees, East 12 degrees 30.5 minutes"),
+            Ok(Some(ISO6709Coord::from_parts(0., 12. + 30.5 / 60., None)))
+        );
+    }
+
+    #[test]
+    fn should_skip_other_input() {
+        for str in [
+            "+35.50-170.10/",
+            "15°30′00″N 95°15′00″W",
+            "50.68 Northern, 95.81 West",
+            "50.68 North, 95.81 West, 12m",
+            "",
+        ] {
+            assert_eq!(spelled_out(str), Ok(None), "{}", str);
+        }
+    }
+
+    #[test]
+    fn should_err_spelled_out() {
+        assert_eq!(
+            spelled_out("50.68 North, 95.81 South").unwrap_err().kind(),
+            ErrorKind::Syntax
+        );
+        assert_eq!(
+            spelled_out("North 50 degrees 60 minutes, West 95 degrees")
+                .unwrap_err()
+                .kind(),
+            ErrorKind::Syntax
+        );
+        assert_eq!(
+            spelled_out("95.5 North, 95.81 West").unwrap_err().kind(),
+            ErrorKind::OutOfRange
+        );
+    }
+}
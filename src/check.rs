@@ -0,0 +1,79 @@
+use crate::exponent::has_exponent;
+use crate::parsers::iso6709;
+use crate::trim;
+
+/// Whether [`crate::parse`] would succeed on `str`. Neither a coordinate nor an [`crate::ISO6709Error`] is built, so
+/// this is the cheaper choice when only the valid or invalid decision matters, such as when filtering large datasets.
+/// ```
+/// # use iso6709parse::is_valid;
+/// assert!(is_valid("N35.50W170.10+8712CRSWGS_85/"));
+/// assert!(is_valid("15°30′00.000″N 95°15′00.000″W"));
+/// assert!(!is_valid("N95.50W170.10/"));
+/// ```
+pub fn is_valid(str: &str) -> bool {
+    !has_exponent(str) && (readable(str) || string_representation(str))
+}
+
+/// Whether [`crate::parse_readable`] would succeed on `str`, see [`is_valid`]
+pub fn is_valid_readable(str: &str) -> bool {
+    !has_exponent(str) && readable(str)
+}
+
+/// Whether [`crate::parse_string_representation`] would succeed on `str`, see [`is_valid`]
+pub fn is_valid_string_representation(str: &str) -> bool {
+    !has_exponent(str) && string_representation(str)
+}
+
+fn readable(str: &str) -> bool {
+    trim(iso6709::human_readable::latlong_altitude_option_parser)(str).is_ok()
+}
+
+fn string_representation(str: &str) -> bool {
+    trim(iso6709::string_expression::latlong_altitude_option_parser)(str).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, parse_readable, parse_string_representation, ISO6709Coord};
+
+    #[test]
+    fn should_agree_with_parse() {
+        let inputs = [
+            "15°30′00.000″N 95°15′00.000″W",
+            " 15°30′00.000″N 95°15′00.000″W 123.45m ",
+            "15°60′00″N 95°15′00.000″W",
+            "N35.50W170.10/",
+            "+3520.30-17006.12+8712CRSWGS_84/",
+            "+35.50-170.10+12/",
+            "+35.50-170.10-12.5",
+            "+3.55e1-170.10/",
+            "15°30′00″N 95°15′00″W 1e-2m",
+            "N95.50W170.10/",
+            "+35.50-190.10/",
+            &format!("+{}-170.10/", "1".repeat(100)),
+            "",
+            "nonsense",
+        ];
+        for input in inputs {
+            assert_eq!(
+                is_valid(input),
+                parse::<ISO6709Coord>(input).is_ok(),
+                "{}",
+                input
+            );
+            assert_eq!(
+                is_valid_readable(input),
+                parse_readable::<ISO6709Coord>(input).is_ok(),
+                "{}",
+                input
+            );
+            assert_eq!(
+                is_valid_string_representation(input),
+                parse_string_representation::<ISO6709Coord>(input).is_ok(),
+                "{}",
+                input
+            );
+        }
+    }
+}
@@ -0,0 +1,228 @@
+use crate::ast::{parse_ast_prefix, CoordinateAst};
+use crate::{parse_position, Format, ISO6709Coord, ParserOptions};
+use std::collections::HashMap;
+
+/// Coordinates parsed in bulk, along with a summary of the dataset that is kept up to date as coordinates are added,
+/// so that ingestion jobs do not need a second pass over their input.
+/// ```
+/// # use iso6709parse::{CoordCollection, Format, ParserOptions};
+/// let inputs = ["+35.50-170.10/", "+3630.00-17010.00/", "15°30′00″N 95°15′00″W", "not a coordinate"];
+/// let collection = CoordCollection::parse(inputs, &ParserOptions::default());
+/// assert_eq!((collection.len(), collection.failed()), (3, 1));
+/// assert_eq!(collection.format_count(Format::Degrees), 1);
+/// assert_eq!(collection.format_count(Format::Readable), 1);
+///
+/// let (south_west, north_east) = collection.bounding_box().unwrap();
+/// assert_eq!((south_west.lat, north_east.lat), (15.5, 36.5));
+/// assert_eq!(north_east.lon, -95.25);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CoordCollection {
+    coords: Vec<ISO6709Coord>,
+    failed: usize,
+    formats: HashMap<Format, usize>,
+    /// Sum of the coordinates as unit vectors, whose direction is the centroid
+    sum: [f64; 3],
+    altitude_sum: f64,
+    altitudes: usize,
+}
+
+impl CoordCollection {
+    pub fn new() -> CoordCollection {
+        CoordCollection::default()
+    }
+
+    /// Parses every input with [`parse_position`], counting the ones that fail rather than stopping at them
+    pub fn parse<'a>(
+        inputs: impl IntoIterator<Item = &'a str>,
+        options: &ParserOptions,
+    ) -> CoordCollection {
+        let mut collection = CoordCollection::new();
+        for input in inputs {
+            collection.push_str(input, options);
+        }
+        collection
+    }
+
+    /// Parses `input` and adds the coordinate, or counts it as failed
+    pub fn push_str(&mut self, input: &str, options: &ParserOptions) {
+        match parse_position(input, options) {
+            Ok(position) => {
+                if let Some(format) = parse_ast_prefix(input).as_ref().map(CoordinateAst::format) {
+                    *self.formats.entry(format).or_default() += 1;
+                }
+                self.push(position.coord);
+            }
+            Err(_) => self.failed += 1,
+        }
+    }
+
+    /// Adds a coordinate that was not parsed from a string, which is not counted in any format
+    pub fn push(&mut self, coord: ISO6709Coord) {
+        let (lat, lon) = (coord.lat.to_radians(), coord.lon.to_radians());
+        self.sum[0] += lat.cos() * lon.cos();
+        self.sum[1] += lat.cos() * lon.sin();
+        self.sum[2] += lat.sin();
+        if let Some(altitude) = coord.altitude {
+            self.altitude_sum += altitude;
+            self.altitudes += 1;
+        }
+        self.coords.push(coord);
+    }
+
+    pub fn coords(&self) -> &[ISO6709Coord] {
+        &self.coords
+    }
+
+    /// Number of coordinates in the collection
+    pub fn len(&self) -> usize {
+        self.coords.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.coords.is_empty()
+    }
+
+    /// Number of inputs that failed to parse
+    pub fn failed(&self) -> usize {
+        self.failed
+    }
+
+    /// Number of inputs written in `format`. Only formats that [`crate::ParserOptions`] can parse are counted, so
+    /// the Wikipedia formats are never counted, and neither are inputs that were rewritten in
+    /// [`crate::Leniency::Lenient`] mode before they could be read.
+    pub fn format_count(&self, format: Format) -> usize {
+        self.formats.get(&format).copied().unwrap_or(0)
+    }
+
+    /// The south-west and north-east corners of the smallest box holding every coordinate, without altitudes.
+    /// As with [`crate::parse_bbox`], a box crossing the antimeridian has a south-west longitude greater than its
+    /// north-east longitude. `None` if the collection is empty.
+    pub fn bounding_box(&self) -> Option<(ISO6709Coord, ISO6709Coord)> {
+        let south = self.coords.iter().map(|coord| coord.lat).reduce(f64::min)?;
+        let north = self.coords.iter().map(|coord| coord.lat).reduce(f64::max)?;
+
+        // The box leaves out the widest gap between longitudes, which is only across the antimeridian if that
+        // is where the widest gap is
+        let mut lons: Vec<f64> = self.coords.iter().map(|coord| coord.lon).collect();
+        lons.sort_by(f64::total_cmp);
+        let (mut west, mut east) = (lons[0], lons[lons.len() - 1]);
+        let mut widest = lons[0] + 360. - lons[lons.len() - 1];
+        for pair in lons.windows(2) {
+            if pair[1] - pair[0] > widest {
+                widest = pair[1] - pair[0];
+                (west, east) = (pair[1], pair[0]);
+            }
+        }
+        Some((
+            ISO6709Coord::from_parts(south, west, None),
+            ISO6709Coord::from_parts(north, east, None),
+        ))
+    }
+
+    /// The center of the coordinates on the surface of a sphere, which unlike averaging the latitudes and longitudes
+    /// is not thrown off by the antimeridian. The altitude is the mean of the altitudes there are.
+    /// `None` if the collection is empty, or the coordinates balance out, as for two opposite points.
+    /// ```
+    /// # use iso6709parse::{CoordCollection, ParserOptions};
+    /// let collection = CoordCollection::parse(["+10+179/", "-10-179/"], &ParserOptions::default());
+    /// let centroid = collection.centroid().unwrap();
+    /// assert!(centroid.lat.abs() < 1e-9 && (centroid.lon.abs() - 180.).abs() < 1e-9);
+    /// ```
+    pub fn centroid(&self) -> Option<ISO6709Coord> {
+        let [x, y, z] = self.sum;
+        let horizontal = x.hypot(y);
+        if self.is_empty() || horizontal.hypot(z) < 1e-12 * self.len() as f64 {
+            return None;
+        }
+        let altitude = (self.altitudes > 0).then(|| self.altitude_sum / self.altitudes as f64);
+        Some(ISO6709Coord::from_parts(
+            z.atan2(horizontal).to_degrees(),
+            y.atan2(x).to_degrees(),
+            altitude,
+        ))
+    }
+}
+
+impl FromIterator<ISO6709Coord> for CoordCollection {
+    fn from_iter<I: IntoIterator<Item = ISO6709Coord>>(iter: I) -> Self {
+        let mut collection = CoordCollection::new();
+        collection.extend(iter);
+        collection
+    }
+}
+
+impl Extend<ISO6709Coord> for CoordCollection {
+    fn extend<I: IntoIterator<Item = ISO6709Coord>>(&mut self, iter: I) {
+        for coord in iter {
+            self.push(coord);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_summarize() {
+        let collection = CoordCollection::parse(
+            [
+                "+35.50-170.10/",
+                "-353000+1700600+12CRSWGS_84/",
+                "N3530W17006/",
+                "35°30′00″S 170°06′00″E 20m",
+                "+95.50-170.10/",
+                "",
+            ],
+            &ParserOptions::default(),
+        );
+        assert_eq!(collection.len(), 4);
+        assert_eq!(collection.failed(), 2);
+        assert_eq!(collection.format_count(Format::Degrees), 1);
+        assert_eq!(collection.format_count(Format::DegreesMinutes), 1);
+        assert_eq!(collection.format_count(Format::DegreesMinutesSeconds), 1);
+        assert_eq!(collection.format_count(Format::Readable), 1);
+        assert_eq!(collection.format_count(Format::Wikipedia), 0);
+
+        let (south_west, north_east) = collection.bounding_box().unwrap();
+        assert_eq!((south_west.lat, north_east.lat), (-35.5, 35.5));
+        assert_eq!((south_west.lon, north_east.lon), (170.1, -170.1));
+        let centroid = collection.centroid().unwrap();
+        assert!(centroid.lat.abs() < 1e-9, "{:?}", centroid);
+        assert!((centroid.lon.abs() - 180.).abs() < 1e-9, "{:?}", centroid);
+        assert_eq!(centroid.altitude, Some(16.));
+    }
+
+    #[test]
+    fn should_collect_coords() {
+        let collection: CoordCollection = [
+            ISO6709Coord::from_parts(10., 20., None),
+            ISO6709Coord::from_parts(20., 40., None),
+            ISO6709Coord::from_parts(15., 30., None),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(collection.coords()[2].lon, 30.);
+        let (south_west, north_east) = collection.bounding_box().unwrap();
+        assert_eq!(south_west, ISO6709Coord::from_parts(10., 20., None));
+        assert_eq!(north_east, ISO6709Coord::from_parts(20., 40., None));
+        let centroid = collection.centroid().unwrap();
+        assert!((centroid.lat - 15.).abs() < 0.5 && (centroid.lon - 30.).abs() < 0.5);
+        assert_eq!(centroid.altitude, None);
+        assert_eq!(collection.format_count(Format::Degrees), 0);
+
+        let empty = CoordCollection::new();
+        assert!(empty.is_empty());
+        assert_eq!(empty.bounding_box(), None);
+        assert_eq!(empty.centroid(), None);
+
+        let opposite: CoordCollection = [
+            ISO6709Coord::from_parts(0., 0., None),
+            ISO6709Coord::from_parts(0., 180., None),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(opposite.centroid(), None);
+    }
+}
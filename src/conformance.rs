@@ -0,0 +1,346 @@
+//! Auditing of ISO6709 strings.
+//!
+//! The parsers accept some inputs that are not strictly ISO6709, and reject others that are only slightly off.
+//! [`check_conformance`] instead lists every way the input deviates from the standard, which is useful for
+//! measuring the quality of an archive of coordinates before deciding how to clean it up.
+use crate::ast::{Notation, Span};
+
+/// A way in which the input deviates from ISO6709
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum DeviationKind {
+    /// The string representation does not end with `/`
+    MissingSolidus,
+    /// A degree, minute or second is missing the leading zero that makes it a fixed width, as in `+5.5` for `+05.5`
+    MissingLeadingZero,
+    /// `'` or `"` instead of the prime `′` or double prime `″`
+    AsciiQuote,
+    /// Whitespace around or inside the string representation, or anything but a single space between the parts of
+    /// the readable format
+    Whitespace,
+    /// `N`, `S`, `E` or `W` instead of `+` or `-` in the string representation
+    HemisphereLetter,
+    /// An altitude without the `CRS` tag that must follow it
+    MissingCrs,
+    /// The `CRS` tag is not in uppercase
+    LowercaseCrsTag,
+    /// The input could not be followed from this point on
+    Unrecognized,
+}
+
+/// A deviation, along with where it is in the input
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Deviation {
+    pub kind: DeviationKind,
+    pub span: Span,
+}
+
+/// The result of [`check_conformance`]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Report {
+    /// Which format the input looks like it was meant to be in
+    pub notation: Notation,
+    /// In the order they appear in the input
+    pub deviations: Vec<Deviation>,
+}
+
+impl Report {
+    /// `true` if the input is strictly ISO6709
+    pub fn is_conformant(&self) -> bool {
+        self.deviations.is_empty()
+    }
+}
+
+/// Lists every way `str` deviates from strict ISO6709. Unlike the parsers, this does not stop at the first problem,
+/// and checks inputs that the parsers would accept anyway, such as hemisphere letters in the string representation.
+/// ```
+/// # use iso6709parse::conformance::{check_conformance, DeviationKind};
+/// let report = check_conformance("+35.50-170.10/");
+/// assert!(report.is_conformant());
+///
+/// let report = check_conformance(" N5.50-170.10");
+/// let kinds: Vec<DeviationKind> = report.deviations.iter().map(|d| d.kind).collect();
+/// assert_eq!(
+///     kinds,
+///     vec![
+///         DeviationKind::Whitespace,
+///         DeviationKind::HemisphereLetter,
+///         DeviationKind::MissingLeadingZero,
+///         DeviationKind::MissingSolidus,
+///     ]
+/// );
+/// assert_eq!((report.deviations[2].span.start, report.deviations[2].span.end), (2, 3));
+/// ```
+pub fn check_conformance(str: &str) -> Report {
+    let mut cursor = Cursor {
+        str,
+        pos: 0,
+        deviations: vec![],
+    };
+    let notation = if str.contains('°') {
+        cursor.readable();
+        Notation::Readable
+    } else {
+        cursor.string_representation();
+        Notation::StringRepresentation
+    };
+    Report {
+        notation,
+        deviations: cursor.deviations,
+    }
+}
+
+struct Cursor<'a> {
+    str: &'a str,
+    pos: usize,
+    deviations: Vec<Deviation>,
+}
+
+impl Cursor<'_> {
+    fn rest(&self) -> &str {
+        &self.str[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn flag(&mut self, kind: DeviationKind, start: usize, end: usize) {
+        self.deviations.push(Deviation {
+            kind,
+            span: Span { start, end },
+        });
+    }
+
+    /// Consumes characters while `cond` holds, returning where they start
+    fn take_while(&mut self, cond: impl Fn(char) -> bool) -> (usize, usize) {
+        let start = self.pos;
+        let len = self
+            .rest()
+            .find(|ch: char| !cond(ch))
+            .unwrap_or(self.rest().len());
+        self.pos += len;
+        (start, self.pos)
+    }
+
+    fn eat(&mut self, options: &[char]) -> Option<char> {
+        let ch = self.peek().filter(|ch| options.contains(ch))?;
+        self.pos += ch.len_utf8();
+        Some(ch)
+    }
+
+    /// Flags any whitespace at the cursor
+    fn whitespace(&mut self) {
+        let (start, end) = self.take_while(char::is_whitespace);
+        if end > start {
+            self.flag(DeviationKind::Whitespace, start, end);
+        }
+    }
+
+    /// Flags the rest of the input as unrecognized, which is an empty span if the input ended too soon
+    fn unrecognized(&mut self) {
+        self.flag(DeviationKind::Unrecognized, self.pos, self.str.len());
+        self.pos = self.str.len();
+    }
+
+    /// Flags anything left over after the coordinate
+    fn end(&mut self) {
+        if self.pos < self.str.len() {
+            self.unrecognized();
+        }
+    }
+
+    /// Digits with an optional fraction, returning the span of the integer part
+    fn number(&mut self) -> Option<(usize, usize)> {
+        let integer = self.take_while(|ch| ch.is_ascii_digit());
+        if integer.0 == integer.1 {
+            return None;
+        }
+        if self.eat(&['.']).is_some() {
+            self.take_while(|ch| ch.is_ascii_digit());
+        }
+        Some(integer)
+    }
+
+    fn string_angle(&mut self, hemispheres: [char; 2], degree_width: usize) -> Option<()> {
+        self.whitespace();
+        let start = self.pos;
+        if self.eat(&['+', '-']).is_none() {
+            self.eat(&hemispheres)?;
+            self.flag(DeviationKind::HemisphereLetter, start, self.pos);
+        }
+        let (start, end) = self.number()?;
+        // Degrees, then optionally two digits each of minutes and seconds
+        if (end - start) % 2 != degree_width % 2 {
+            self.flag(DeviationKind::MissingLeadingZero, start, start + 1);
+        }
+        Some(())
+    }
+
+    fn string_representation(&mut self) {
+        if self.string_angle(['N', 'S'], 2).is_none() || self.string_angle(['E', 'W'], 3).is_none()
+        {
+            return self.unrecognized();
+        }
+        self.whitespace();
+        if self.eat(&['+', '-']).is_some() {
+            if self.number().is_none() {
+                return self.unrecognized();
+            }
+            let start = self.pos;
+            match self.rest().get(..3) {
+                Some("CRS") => self.pos += 3,
+                Some(tag) if tag.eq_ignore_ascii_case("CRS") => {
+                    self.pos += 3;
+                    self.flag(DeviationKind::LowercaseCrsTag, start, self.pos);
+                }
+                _ => self.flag(DeviationKind::MissingCrs, start, start),
+            }
+            self.take_while(|ch| ch != '/' && !ch.is_whitespace());
+        }
+        self.whitespace();
+        if self.eat(&['/']).is_none() {
+            self.flag(DeviationKind::MissingSolidus, self.pos, self.pos);
+        }
+        self.whitespace();
+        self.end();
+    }
+
+    /// Minutes or seconds, which are always two digits, followed by their mark
+    fn readable_component(&mut self, mark: char, ascii: char) -> Option<()> {
+        let (start, end) = self.number()?;
+        if end - start == 1 {
+            self.flag(DeviationKind::MissingLeadingZero, start, end);
+        }
+        let start = self.pos;
+        if self.eat(&[mark]).is_none() {
+            self.eat(&[ascii])?;
+            self.flag(DeviationKind::AsciiQuote, start, self.pos);
+        }
+        Some(())
+    }
+
+    fn readable_angle(&mut self, hemispheres: [char; 2]) -> Option<()> {
+        self.number()?;
+        self.eat(&['°'])?;
+        self.readable_component('′', '\'')?;
+        self.readable_component('″', '"')?;
+        self.eat(&hemispheres)?;
+        Some(())
+    }
+
+    /// A single space between the parts of the readable format
+    fn separator(&mut self) {
+        let (start, end) = self.take_while(char::is_whitespace);
+        if &self.str[start..end] != " " {
+            self.flag(DeviationKind::Whitespace, start, end);
+        }
+    }
+
+    fn readable(&mut self) {
+        self.whitespace();
+        if self.readable_angle(['N', 'S']).is_none() {
+            return self.unrecognized();
+        }
+        if self.pos == self.str.len() {
+            return self.unrecognized();
+        }
+        self.separator();
+        if self.readable_angle(['E', 'W']).is_none() {
+            return self.unrecognized();
+        }
+        if self.rest().trim().is_empty() {
+            self.whitespace();
+            return;
+        }
+        self.separator();
+        self.eat(&['-']);
+        if self.number().is_none() {
+            return self.unrecognized();
+        }
+        self.whitespace();
+        self.take_while(|ch| ch.is_alphabetic());
+        self.whitespace();
+        self.end();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(str: &str) -> Vec<(DeviationKind, usize, usize)> {
+        check_conformance(str)
+            .deviations
+            .iter()
+            .map(|d| (d.kind, d.span.start, d.span.end))
+            .collect()
+    }
+
+    #[test]
+    fn should_accept_conformant() {
+        for str in [
+            "+35.50-170.10/",
+            "+3530-17006/",
+            "+353000.5-1700600.5/",
+            "+35.50-170.10+8712CRSWGS_84/",
+            "15°30′00.000″N 95°15′00.000″W",
+            "15°30′00.000″N 95°15′00.000″W 123.45m",
+        ] {
+            assert!(check_conformance(str).is_conformant(), "{}", str);
+        }
+    }
+
+    #[test]
+    fn should_report_string_representation() {
+        use DeviationKind::*;
+        assert_eq!(kinds("+35.50-170.10"), vec![(MissingSolidus, 13, 13)]);
+        assert_eq!(
+            kinds("+353-17006 /"),
+            vec![(MissingLeadingZero, 1, 2), (Whitespace, 10, 11)]
+        );
+        assert_eq!(
+            kinds("N35.50W70.10+12crsWGS_84/"),
+            vec![
+                (HemisphereLetter, 0, 1),
+                (HemisphereLetter, 6, 7),
+                (MissingLeadingZero, 7, 8),
+                (LowercaseCrsTag, 15, 18)
+            ]
+        );
+        assert_eq!(kinds("+35.50-170.10+12/"), vec![(MissingCrs, 16, 16)]);
+        assert_eq!(
+            kinds("+35.50 hello/"),
+            vec![(Whitespace, 6, 7), (Unrecognized, 7, 13)]
+        );
+        assert_eq!(
+            check_conformance("+35.50-170.10/").notation,
+            Notation::StringRepresentation
+        );
+    }
+
+    #[test]
+    fn should_report_readable() {
+        use DeviationKind::*;
+        assert_eq!(
+            kinds("15°30'0\"N  95°15′00″W"),
+            vec![
+                (AsciiQuote, 6, 7),
+                (MissingLeadingZero, 7, 8),
+                (AsciiQuote, 8, 9),
+                (Whitespace, 10, 12)
+            ]
+        );
+        assert_eq!(
+            kinds(" 15°30′00″N 95°15′00″W\t123m"),
+            vec![(Whitespace, 0, 1), (Whitespace, 32, 33)]
+        );
+        assert_eq!(
+            kinds("15°30′00″N 95°15′00″W 123 m"),
+            vec![(Whitespace, 35, 36)]
+        );
+        assert_eq!(kinds("15°30′00″N 95°15′W"), vec![(Unrecognized, 25, 26)]);
+        assert_eq!(kinds("15°30′00″N"), vec![(Unrecognized, 15, 15)]);
+        assert_eq!(check_conformance("15°30′00″N").notation, Notation::Readable);
+    }
+}
@@ -0,0 +1,192 @@
+use crate::{parse, parse_with_altitude, FormatSpec, ISO6709Coord, ISO6709Coord3D, ISO6709Error};
+
+/// Types that parsed coordinates can be turned into, as required by [`crate::parse`] and the other parse functions.
+///
+/// Every type that implements `From<ISO6709Coord>` already implements this. Implementing it directly is useful
+/// when the orphan rules prevent implementing `From`, or to keep the conversion out of the public `From` impls.
+/// ```
+/// use iso6709parse::{parse, FromIso6709, ISO6709Coord};
+///
+/// struct LatLon(f64, f64);
+///
+/// impl FromIso6709 for LatLon {
+///     fn from_iso6709(coord: ISO6709Coord) -> Self {
+///         LatLon(coord.lat, coord.lon)
+///     }
+/// }
+///
+/// let LatLon(lat, lon) = parse("N35.50W170.10/").unwrap();
+/// assert_eq!((lat, lon), (35.5, -170.1));
+/// ```
+pub trait FromIso6709: Sized {
+    fn from_iso6709(coord: ISO6709Coord) -> Self;
+}
+
+impl<T> FromIso6709 for T
+where
+    ISO6709Coord: Into<T>,
+{
+    fn from_iso6709(coord: ISO6709Coord) -> Self {
+        coord.into()
+    }
+}
+
+/// Types that can be written as ISO6709, implemented for [`ISO6709Coord`] and, with the `geo-types` feature, the
+/// `geo_types` `Point` and `Coord`
+/// ```
+/// use iso6709parse::{FormatSpec, ISO6709Coord, ToIso6709};
+///
+/// let coord = ISO6709Coord::from_parts(35.5, -170.1, None);
+/// assert_eq!(coord.format_iso6709(&FormatSpec::default()).unwrap(), "+35.50000-170.10000/");
+/// ```
+pub trait ToIso6709 {
+    fn to_iso6709(&self) -> ISO6709Coord;
+
+    /// Writes the coordinate with [`ISO6709Coord::format`]
+    fn format_iso6709(&self, spec: &FormatSpec) -> Result<String, ISO6709Error> {
+        self.to_iso6709().format(spec)
+    }
+}
+
+impl<T: Copy + Into<f64>> ToIso6709 for ISO6709Coord<T> {
+    fn to_iso6709(&self) -> ISO6709Coord {
+        ISO6709Coord {
+            lat: self.lat.into(),
+            lon: self.lon.into(),
+            altitude: self.altitude.map(Into::into),
+        }
+    }
+}
+
+/// ```
+/// use iso6709parse::{FormatSpec, ToIso6709};
+///
+/// let point = geo_types::Point::new(-170.1, 35.5);
+/// assert_eq!(point.format_iso6709(&FormatSpec::default()).unwrap(), "+35.50000-170.10000/");
+/// ```
+#[cfg(feature = "geo-types")]
+impl<T: geo_types::CoordNum + Into<f64>> ToIso6709 for geo_types::Coord<T> {
+    fn to_iso6709(&self) -> ISO6709Coord {
+        ISO6709Coord {
+            lat: self.y.into(),
+            lon: self.x.into(),
+            altitude: None,
+        }
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl<T: geo_types::CoordNum + Into<f64>> ToIso6709 for geo_types::Point<T> {
+    fn to_iso6709(&self) -> ISO6709Coord {
+        self.0.to_iso6709()
+    }
+}
+
+/// Parses either format with [`crate::parse`], for code that is generic over `TryFrom`. The orphan rules do not allow
+/// the same for the `geo_types` types, so parse those with [`crate::parse`] or convert the `ISO6709Coord`.
+/// ```
+/// # use iso6709parse::ISO6709Coord;
+/// let coord = ISO6709Coord::<f64>::try_from("N35.50W170.10/").unwrap();
+/// assert_eq!(coord.lat, 35.5);
+/// ```
+impl<T> TryFrom<&str> for ISO6709Coord<T>
+where
+    ISO6709Coord<T>: FromIso6709,
+{
+    type Error = ISO6709Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        parse(value)
+    }
+}
+
+impl<T> TryFrom<String> for ISO6709Coord<T>
+where
+    ISO6709Coord<T>: FromIso6709,
+{
+    type Error = ISO6709Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        parse(&value)
+    }
+}
+
+/// Parses either format with [`crate::parse_with_altitude`], which fails without an altitude
+impl<T> TryFrom<&str> for ISO6709Coord3D<T>
+where
+    ISO6709Coord3D: Into<ISO6709Coord3D<T>>,
+{
+    type Error = ISO6709Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        parse_with_altitude(value)
+    }
+}
+
+impl<T> TryFrom<String> for ISO6709Coord3D<T>
+where
+    ISO6709Coord3D: Into<ISO6709Coord3D<T>>,
+{
+    type Error = ISO6709Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        parse_with_altitude(&value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Format;
+
+    #[test]
+    fn should_convert_to_iso6709() {
+        let coord = ISO6709Coord {
+            lat: 15.5f32,
+            lon: -95.25f32,
+            altitude: Some(12f32),
+        };
+        assert_eq!(coord.to_iso6709().altitude, Some(12.));
+        let spec = FormatSpec {
+            format: Format::Readable,
+            decimals: 0,
+            ..Default::default()
+        };
+        assert_eq!(
+            coord.format_iso6709(&spec).unwrap(),
+            "15°30′00″N 095°15′00″W 12m"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "geo-types")]
+    fn should_convert_geo_types_to_iso6709() {
+        let coord = geo_types::coord! { x: -95.25f32, y: 15.5f32 };
+        assert_eq!(
+            coord.to_iso6709(),
+            ISO6709Coord {
+                lat: 15.5,
+                lon: -95.25,
+                altitude: None
+            }
+        );
+        assert_eq!(geo_types::Point(coord).to_iso6709(), coord.to_iso6709());
+    }
+
+    #[test]
+    fn should_try_from_str() {
+        fn load<T: TryFrom<String, Error = ISO6709Error>>(value: &str) -> Result<T, ISO6709Error> {
+            T::try_from(value.to_string())
+        }
+
+        let coord: ISO6709Coord = load("15°30′00″N 95°15′00″W").unwrap();
+        assert_eq!(coord.lon, -95.25);
+        let coord: ISO6709Coord<f32> = load("+35.50-170.10/").unwrap();
+        assert_eq!(coord.lon, -170.1f32);
+        assert!(ISO6709Coord::<f64>::try_from("+35.50").is_err());
+
+        let coord = ISO6709Coord3D::<f64>::try_from("+35.50-170.10+12CRSWGS_84/").unwrap();
+        assert_eq!(coord.altitude, 12.);
+        assert!(load::<ISO6709Coord3D>("+35.50-170.10/").is_err());
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{parse, ErrorKind, ISO6709Coord, ISO6709Error};
+
+/// A coordinate that always has an altitude, as created by [`parse_with_altitude`]. Implement
+/// `From<ISO6709Coord3D>` for your own types that need the third coordinate.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ISO6709Coord3D<T = f64> {
+    pub lat: T,
+    pub lon: T,
+    pub altitude: T,
+}
+
+impl<T> From<ISO6709Coord3D<T>> for ISO6709Coord<T> {
+    fn from(value: ISO6709Coord3D<T>) -> Self {
+        ISO6709Coord {
+            lat: value.lat,
+            lon: value.lon,
+            altitude: Some(value.altitude),
+        }
+    }
+}
+
+impl<T> TryFrom<ISO6709Coord<T>> for ISO6709Coord3D<T> {
+    type Error = ISO6709Error;
+
+    /// Fails with [`ErrorKind::MissingAltitude`] if the coordinate has no altitude
+    fn try_from(value: ISO6709Coord<T>) -> Result<Self, Self::Error> {
+        match value.altitude {
+            Some(altitude) => Ok(ISO6709Coord3D {
+                lat: value.lat,
+                lon: value.lon,
+                altitude,
+            }),
+            None => Err(ISO6709Error::new(
+                ErrorKind::MissingAltitude,
+                "the coordinate has no altitude",
+            )),
+        }
+    }
+}
+
+impl From<ISO6709Coord3D> for ISO6709Coord3D<f32> {
+    fn from(value: ISO6709Coord3D) -> Self {
+        ISO6709Coord3D {
+            lat: value.lat as f32,
+            lon: value.lon as f32,
+            altitude: value.altitude as f32,
+        }
+    }
+}
+
+/// Parses either of the two formats like [`crate::parse`], into any struct that implements `From<ISO6709Coord3D>`.
+/// Fails with [`ErrorKind::MissingAltitude`] if there is no altitude, so applications that need 3D positions do not
+/// have to unwrap it themselves.
+/// ```
+/// # use iso6709parse::{parse_with_altitude, ErrorKind, ISO6709Coord3D};
+/// let coord: ISO6709Coord3D = parse_with_altitude("N35.50W170.10+8712CRSWGS_84/").unwrap();
+/// assert_eq!(coord.altitude, 8712.);
+///
+/// let err = parse_with_altitude::<ISO6709Coord3D>("N35.50W170.10/").unwrap_err();
+/// assert_eq!(err.kind(), ErrorKind::MissingAltitude);
+/// ```
+pub fn parse_with_altitude<T>(str: &str) -> Result<T, ISO6709Error>
+where
+    ISO6709Coord3D: Into<T>,
+{
+    let coord: ISO6709Coord = parse(str)?;
+    ISO6709Coord3D::try_from(coord).map(Into::into)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_with_altitude() {
+        let expected = ISO6709Coord3D {
+            lat: 15.5,
+            lon: -95.25,
+            altitude: 123.45,
+        };
+        let coord = "15°30′00.000″N 95°15′00.000″W 123.45m";
+        assert_eq!(parse_with_altitude(coord), Ok(expected.clone()));
+        assert_eq!(
+            parse_with_altitude::<ISO6709Coord>(coord).unwrap().altitude,
+            Some(123.45)
+        );
+
+        let coord: ISO6709Coord3D<f32> = parse_with_altitude("+35.50-170.10-12CRSWGS_84/").unwrap();
+        assert_eq!(coord.altitude, -12f32);
+
+        let err =
+            parse_with_altitude::<ISO6709Coord3D>("15°30′00.000″N 95°15′00.000″W").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingAltitude);
+        let err = parse_with_altitude::<ISO6709Coord3D>("+35.50").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Syntax);
+    }
+}
@@ -0,0 +1,107 @@
+use crate::{parse_readable_coord, parse_string_representation_coord};
+use crate::{ErrorKind, ISO6709Coord, ISO6709Error};
+
+/// Parses a record that gives the same point twice, once in the readable format for people and once in the string
+/// representation for machines, and checks that the two are no more than `tolerance_m` meters apart. Altitudes are
+/// only compared when both have one.
+///
+/// Returns the string representation, with the altitude of the readable format if it has none of its own. Fails
+/// with [`ErrorKind::Mismatch`] if the two disagree, or the error of whichever one fails to parse.
+/// ```
+/// # use iso6709parse::{cross_check, ErrorKind};
+/// let coord = cross_check("35°30′00″N 170°06′00″W 12m", "+35.50-170.10/", 1.).unwrap();
+/// assert_eq!((coord.lat, coord.lon, coord.altitude), (35.5, -170.1, Some(12.)));
+///
+/// let err = cross_check("35°30′00″N 170°06′00″W", "+35.50+170.10/", 1.).unwrap_err();
+/// assert_eq!(err.kind(), ErrorKind::Mismatch);
+/// ```
+pub fn cross_check(
+    readable: &str,
+    string_representation: &str,
+    tolerance_m: f64,
+) -> Result<ISO6709Coord, ISO6709Error> {
+    let human = parse_readable_coord(readable)?;
+    let machine = parse_string_representation_coord(string_representation)?;
+
+    let distance = human.distance_m(&machine);
+    if !within(distance, tolerance_m) {
+        return Err(ISO6709Error::new(
+            ErrorKind::Mismatch,
+            format!(
+                "`{}` and `{}` are {:.1} m apart, more than the tolerance of {} m",
+                readable.trim(),
+                string_representation.trim(),
+                distance,
+                tolerance_m
+            ),
+        ));
+    }
+    if let (Some(a), Some(b)) = (human.altitude, machine.altitude) {
+        if !within((a - b).abs(), tolerance_m) {
+            return Err(ISO6709Error::new(
+                ErrorKind::Mismatch,
+                format!(
+                    "the altitudes of {} m and {} m differ by more than the tolerance of {} m",
+                    a, b, tolerance_m
+                ),
+            ));
+        }
+    }
+    Ok(ISO6709Coord {
+        altitude: machine.altitude.or(human.altitude),
+        ..machine
+    })
+}
+
+/// `false` for a NaN tolerance, unlike `difference > tolerance`
+fn within(difference: f64, tolerance: f64) -> bool {
+    difference <= tolerance
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_agree() {
+        let coord = cross_check(
+            "50°40′46.461″N 95°48′26.533″W 123.45m",
+            "+504046.46-0954826.53+123.4CRSWGS_84/",
+            1.,
+        )
+        .unwrap();
+        assert_eq!(coord.altitude, Some(123.4));
+        assert_eq!(coord.lat, 50. + 40. / 60. + 46.46 / 3600.);
+        assert!(cross_check("35°30′00″N 170°06′00″W", "+3530-17006/", 0.001).is_ok());
+    }
+
+    #[test]
+    fn should_flag_mismatch() {
+        let err = cross_check("35°30′00″N 170°06′00″W", "+35.51-170.10/", 100.).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Mismatch);
+        assert!(err.to_string().contains("1112.0 m apart"), "{}", err);
+
+        let err = cross_check(
+            "35°30′00″N 170°06′00″W 10m",
+            "+35.50-170.10+20CRSWGS_84/",
+            5.,
+        )
+        .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Mismatch);
+        assert_eq!(
+            cross_check("35°30′00″N 170°06′00″W", "+35.50-170.10/", f64::NAN)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::Mismatch
+        );
+    }
+
+    #[test]
+    fn should_err_on_either_input() {
+        let err = cross_check("+35.50-170.10/", "+35.50-170.10/", 1.).unwrap_err();
+        assert_eq!(err.input(), Some("+35.50-170.10/"));
+        assert_eq!(err.kind(), ErrorKind::Syntax);
+        let err = cross_check("35°30′00″N 170°06′00″W", "35°30′00″N 170°06′00″W", 1.).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Syntax);
+    }
+}
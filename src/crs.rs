@@ -0,0 +1,372 @@
+/// A Coordinate Reference System identifier, as found after `CRS` in the string representation.
+/// ```
+/// # use iso6709parse::Crs;
+/// assert_eq!(Crs::from("WGS_84"), Crs::Wgs84);
+/// assert_eq!(Crs::from("EPSG:4979"), Crs::Epsg(4979));
+/// assert_eq!(Crs::from("NAD_83"), Crs::Other("NAD_83".to_string()));
+/// assert_eq!(Crs::Epsg(4979).to_string(), "EPSG:4979");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+#[non_exhaustive]
+pub enum Crs {
+    /// `WGS_84`
+    Wgs84,
+    /// `WGS_84_3D`
+    Wgs84_3D,
+    /// `EPSG:` followed by the numeric code, such as `EPSG:4979`
+    Epsg(u32),
+    /// Any identifier not otherwise recognized
+    Other(String),
+}
+
+impl From<&str> for Crs {
+    fn from(value: &str) -> Self {
+        match value {
+            "WGS_84" => Crs::Wgs84,
+            "WGS_84_3D" => Crs::Wgs84_3D,
+            _ => match value.strip_prefix("EPSG:").map(str::parse::<u32>) {
+                Some(Ok(code)) => Crs::Epsg(code),
+                _ => Crs::Other(value.to_string()),
+            },
+        }
+    }
+}
+
+/// What the altitude is measured from. Mixing the two silently causes errors of tens of meters,
+/// since the geoid is up to ~100m away from the ellipsoid.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[non_exhaustive]
+pub enum VerticalDatum {
+    /// Height above the WGS84 ellipsoid, as reported by GNSS receivers
+    Ellipsoidal,
+    /// Orthometric height above the EGM96 geoid, the "above mean sea level" elevation
+    Egm96,
+    /// Orthometric height above the EGM2008 geoid, the "above mean sea level" elevation
+    Egm2008,
+}
+
+impl VerticalDatum {
+    /// `true` for heights above a geoid rather than the ellipsoid
+    pub fn is_orthometric(&self) -> bool {
+        !matches!(self, VerticalDatum::Ellipsoidal)
+    }
+}
+
+const WGS84_DATUM: &str = concat!(
+    r#"DATUM["World Geodetic System 1984","#,
+    r#"ELLIPSOID["WGS 84",6378137,298.257223563,LENGTHUNIT["metre",1]]],"#,
+    r#"PRIMEM["Greenwich",0,ANGLEUNIT["degree",0.0174532925199433]],"#,
+);
+const LAT_LON_AXES: &str = concat!(
+    r#"AXIS["geodetic latitude (Lat)",north,ORDER[1],ANGLEUNIT["degree",0.0174532925199433]],"#,
+    r#"AXIS["geodetic longitude (Lon)",east,ORDER[2],ANGLEUNIT["degree",0.0174532925199433]],"#,
+);
+
+impl Crs {
+    /// The vertical datum implied by the CRS, if it implies one. `WGS_84_3D` is ellipsoidal, while EGM96 and EGM2008
+    /// height systems are orthometric, either by name or by EPSG code, including compound codes such as `EPSG:4326+3855`.
+    /// ```
+    /// # use iso6709parse::{Crs, VerticalDatum};
+    /// assert_eq!(Crs::Wgs84_3D.vertical_datum(), Some(VerticalDatum::Ellipsoidal));
+    /// assert_eq!(Crs::from("EPSG:4326+3855").vertical_datum(), Some(VerticalDatum::Egm2008));
+    /// assert_eq!(Crs::Wgs84.vertical_datum(), None);
+    /// ```
+    pub fn vertical_datum(&self) -> Option<VerticalDatum> {
+        match self {
+            Crs::Wgs84_3D | Crs::Epsg(4979) => Some(VerticalDatum::Ellipsoidal),
+            Crs::Epsg(5773) => Some(VerticalDatum::Egm96),
+            Crs::Epsg(3855) => Some(VerticalDatum::Egm2008),
+            Crs::Other(identifier) if identifier.contains('+') => {
+                // Compound CRS, the vertical part comes last
+                let (_, vertical) = identifier.rsplit_once('+')?;
+                let vertical = if vertical.contains(':') {
+                    Crs::from(vertical)
+                } else {
+                    let (authority, _) = identifier.split_once(':')?;
+                    Crs::from(format!("{}:{}", authority, vertical).as_str())
+                };
+                vertical.vertical_datum()
+            }
+            Crs::Other(identifier) => {
+                let identifier = identifier.to_ascii_uppercase().replace('_', "");
+                if identifier.contains("EGM2008") || identifier.contains("EGM08") {
+                    Some(VerticalDatum::Egm2008)
+                } else if identifier.contains("EGM96") {
+                    Some(VerticalDatum::Egm96)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// OGC WKT2 (ISO 19162) definition of the CRS, so it can be handed to GIS software along with the geometry.
+    /// Only the WGS84 variants are known, any other CRS returns `None`.
+    /// ```
+    /// # use iso6709parse::Crs;
+    /// let wkt = Crs::Wgs84_3D.wkt2().unwrap();
+    /// assert!(wkt.starts_with(r#"GEOGCRS["WGS 84","#));
+    /// assert!(wkt.ends_with(r#"ID["EPSG",4979]]"#));
+    /// assert_eq!(Crs::Epsg(2193).wkt2(), None);
+    /// ```
+    pub fn wkt2(&self) -> Option<String> {
+        match self {
+            Crs::Wgs84 | Crs::Epsg(4326) => Some(format!(
+                r#"GEOGCRS["WGS 84",{}CS[ellipsoidal,2],{}ID["EPSG",4326]]"#,
+                WGS84_DATUM, LAT_LON_AXES
+            )),
+            Crs::Wgs84_3D | Crs::Epsg(4979) => Some(format!(
+                r#"GEOGCRS["WGS 84",{}CS[ellipsoidal,3],{}{}ID["EPSG",4979]]"#,
+                WGS84_DATUM,
+                LAT_LON_AXES,
+                r#"AXIS["ellipsoidal height (h)",up,ORDER[3],LENGTHUNIT["metre",1]],"#
+            )),
+            _ => None,
+        }
+    }
+
+    /// The CRS of a geodetic datum named as in printed coordinates, such as `WGS84`, `ED50` or `NAD 83`. Case,
+    /// spaces, dashes and underscores are ignored. Names that are not known are read the same way as the CRS of the
+    /// string representation.
+    /// ```
+    /// # use iso6709parse::Crs;
+    /// assert_eq!(Crs::from_datum_name("WGS 84"), Crs::Wgs84);
+    /// assert_eq!(Crs::from_datum_name("ED50"), Crs::Epsg(4230));
+    /// assert_eq!(Crs::from_datum_name("EPSG:4258"), Crs::Epsg(4258));
+    /// assert_eq!(Crs::from_datum_name("Local grid"), Crs::Other("Local grid".to_string()));
+    /// ```
+    pub fn from_datum_name(name: &str) -> Crs {
+        let normalized: String = name
+            .chars()
+            .filter(|ch| !matches!(ch, ' ' | '-' | '_'))
+            .map(|ch| ch.to_ascii_uppercase())
+            .collect();
+        match normalized.as_str() {
+            "WGS84" => Crs::Wgs84,
+            "ETRS89" => Crs::Epsg(4258),
+            "ED50" => Crs::Epsg(4230),
+            "NAD83" => Crs::Epsg(4269),
+            "NAD27" => Crs::Epsg(4267),
+            "GDA94" => Crs::Epsg(4283),
+            "GDA2020" => Crs::Epsg(7844),
+            "OSGB36" => Crs::Epsg(4277),
+            "TOKYO" => Crs::Epsg(4301),
+            "JGD2000" => Crs::Epsg(4612),
+            "JGD2011" => Crs::Epsg(6668),
+            "NZGD2000" => Crs::Epsg(4167),
+            "PULKOVO1942" => Crs::Epsg(4284),
+            "CH1903" => Crs::Epsg(4149),
+            "SAD69" => Crs::Epsg(4618),
+            _ => Crs::from(name),
+        }
+    }
+
+    /// `true` for the WGS84 identifiers, including their EPSG codes `EPSG:4326` and `EPSG:4979`.
+    /// Any other CRS needs a datum transformation before it can be compared with WGS84 coordinates.
+    pub fn is_wgs84(&self) -> bool {
+        matches!(self, Crs::Wgs84 | Crs::Wgs84_3D | Crs::Epsg(4326 | 4979))
+    }
+}
+
+impl std::fmt::Display for Crs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Crs::Wgs84 => f.write_str("WGS_84"),
+            Crs::Wgs84_3D => f.write_str("WGS_84_3D"),
+            Crs::Epsg(code) => write!(f, "EPSG:{}", code),
+            Crs::Other(identifier) => f.write_str(identifier),
+        }
+    }
+}
+
+/// A list of Coordinate Reference System identifiers that are accepted after `CRS` in the string representation.
+/// The default registry knows `WGS_84` and `WGS_84_3D`, along with their EPSG codes `EPSG:4326` and `EPSG:4979`,
+/// and can be extended with [`CrsRegistry::with`].
+/// ```
+/// # use iso6709parse::CrsRegistry;
+/// let registry = CrsRegistry::default().with("NAD_83");
+/// assert!(registry.contains("WGS_84"));
+/// assert!(registry.contains("NAD_83"));
+/// assert!(!registry.contains("WGS_85"));
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct CrsRegistry {
+    identifiers: Vec<String>,
+}
+
+impl Default for CrsRegistry {
+    fn default() -> Self {
+        CrsRegistry {
+            identifiers: ["WGS_84", "WGS_84_3D", "EPSG:4326", "EPSG:4979"]
+                .iter()
+                .map(|identifier| identifier.to_string())
+                .collect(),
+        }
+    }
+}
+
+impl CrsRegistry {
+    /// A registry with no known identifiers
+    pub fn empty() -> Self {
+        CrsRegistry {
+            identifiers: vec![],
+        }
+    }
+
+    /// Adds an identifier to the registry
+    pub fn with(mut self, identifier: impl Into<String>) -> Self {
+        self.insert(identifier);
+        self
+    }
+
+    /// Adds an identifier to the registry
+    pub fn insert(&mut self, identifier: impl Into<String>) {
+        let identifier = identifier.into();
+        if !self.contains(&identifier) {
+            self.identifiers.push(identifier);
+        }
+    }
+
+    pub fn contains(&self, identifier: &str) -> bool {
+        self.identifiers.iter().any(|known| known == identifier)
+    }
+}
+
+/// The datum written after a readable coordinate, as in `50°40′46″N 95°48′27″W (WGS84)` or `... 123m ED50`. `rest` is
+/// what follows the coordinate, starting with the unit if there is an altitude.
+pub(crate) fn datum_suffix(rest: &str, altitude: bool) -> Option<Crs> {
+    let mut rest = rest.trim();
+    if altitude {
+        if let Some(after_unit) = rest.strip_prefix('m') {
+            if after_unit.is_empty() || after_unit.starts_with(char::is_whitespace) {
+                rest = after_unit.trim_start();
+            }
+        }
+    }
+    let enclosed = rest
+        .strip_prefix('(')
+        .and_then(|rest| rest.strip_suffix(')'));
+    let name = enclosed.unwrap_or(rest).trim();
+    let valid = name.starts_with(|ch: char| ch.is_ascii_alphabetic())
+        && name
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, ' ' | '-' | '_' | '.' | ':'));
+    if !valid {
+        return None;
+    }
+    // Without parentheses, only known names are taken as a datum rather than some other trailing text
+    match Crs::from_datum_name(name) {
+        Crs::Other(_) if enclosed.is_none() => None,
+        crs => Some(crs),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_extend_registry() {
+        let mut registry = CrsRegistry::empty();
+        assert!(!registry.contains("WGS_84"));
+        registry.insert("WGS_84");
+        registry.insert("WGS_84");
+        assert_eq!(registry.identifiers.len(), 1);
+        assert!(registry.contains("WGS_84"));
+        assert!(!registry.contains("wgs_84"));
+    }
+
+    #[test]
+    fn should_parse_crs() {
+        assert_eq!(Crs::from("WGS_84_3D"), Crs::Wgs84_3D);
+        assert_eq!(Crs::from("EPSG:4326"), Crs::Epsg(4326));
+        assert_eq!(Crs::from("EPSG:"), Crs::Other("EPSG:".to_string()));
+        assert_eq!(Crs::from("EPSG:43a"), Crs::Other("EPSG:43a".to_string()));
+        assert_eq!(Crs::from("epsg:4326"), Crs::Other("epsg:4326".to_string()));
+        assert_eq!(Crs::from("EPSG:4326").to_string(), "EPSG:4326");
+    }
+
+    #[test]
+    fn should_map_datum_names() {
+        assert_eq!(Crs::from_datum_name("wgs-84"), Crs::Wgs84);
+        assert_eq!(Crs::from_datum_name("NAD 27"), Crs::Epsg(4267));
+        assert_eq!(Crs::from_datum_name("Pulkovo 1942"), Crs::Epsg(4284));
+        assert_eq!(Crs::from_datum_name("WGS_84_3D"), Crs::Wgs84_3D);
+        assert_eq!(
+            Crs::from_datum_name("NAD83(CSRS)"),
+            Crs::Other("NAD83(CSRS)".to_string())
+        );
+    }
+
+    #[test]
+    fn should_read_datum_suffix() {
+        assert_eq!(datum_suffix(" (WGS84)", false), Some(Crs::Wgs84));
+        assert_eq!(datum_suffix(" ED50 ", false), Some(Crs::Epsg(4230)));
+        assert_eq!(datum_suffix("m (NAD 83)", true), Some(Crs::Epsg(4269)));
+        assert_eq!(datum_suffix(" m ED50", true), Some(Crs::Epsg(4230)));
+        assert_eq!(datum_suffix("m", true), None);
+        assert_eq!(datum_suffix("", false), None);
+        assert_eq!(datum_suffix(" (50)", false), None);
+        assert_eq!(
+            datum_suffix(" (Local grid)", false),
+            Some(Crs::Other("Local grid".to_string()))
+        );
+        assert_eq!(datum_suffix(" see note", false), None);
+        assert_eq!(datum_suffix(" !", false), None);
+    }
+
+    #[test]
+    fn should_detect_wgs84() {
+        assert!(Crs::Wgs84.is_wgs84());
+        assert!(Crs::Epsg(4979).is_wgs84());
+        assert!(!Crs::Epsg(4267).is_wgs84());
+        assert!(!Crs::Other("NAD_27".to_string()).is_wgs84());
+    }
+
+    #[test]
+    fn should_find_vertical_datum() {
+        assert_eq!(
+            Crs::Epsg(4979).vertical_datum(),
+            Some(VerticalDatum::Ellipsoidal)
+        );
+        assert_eq!(Crs::Epsg(5773).vertical_datum(), Some(VerticalDatum::Egm96));
+        assert_eq!(
+            Crs::from("EPSG:4326+5773").vertical_datum(),
+            Some(VerticalDatum::Egm96)
+        );
+        assert_eq!(
+            Crs::from("EPSG:4326+EPSG:3855").vertical_datum(),
+            Some(VerticalDatum::Egm2008)
+        );
+        assert_eq!(
+            Crs::from("WGS_84_EGM_2008").vertical_datum(),
+            Some(VerticalDatum::Egm2008)
+        );
+        assert_eq!(
+            Crs::from("egm96").vertical_datum(),
+            Some(VerticalDatum::Egm96)
+        );
+        assert_eq!(Crs::from("EPSG:4326+1234").vertical_datum(), None);
+        assert_eq!(Crs::Epsg(4326).vertical_datum(), None);
+        assert!(VerticalDatum::Egm96.is_orthometric());
+        assert!(!VerticalDatum::Ellipsoidal.is_orthometric());
+    }
+
+    #[test]
+    fn should_write_wkt2() {
+        let wkt = Crs::Wgs84.wkt2().unwrap();
+        assert_eq!(Crs::Epsg(4326).wkt2(), Some(wkt.clone()));
+        assert!(wkt.contains("CS[ellipsoidal,2]"));
+        assert!(!wkt.contains("ellipsoidal height"));
+        assert_eq!(wkt.matches('[').count(), wkt.matches(']').count());
+
+        let wkt = Crs::Epsg(4979).wkt2().unwrap();
+        assert_eq!(Crs::Wgs84_3D.wkt2(), Some(wkt.clone()));
+        assert!(wkt.contains("CS[ellipsoidal,3]"));
+        assert!(wkt.contains(r#"AXIS["ellipsoidal height (h)",up,ORDER[3]"#));
+        assert_eq!(wkt.matches('[').count(), wkt.matches(']').count());
+
+        assert_eq!(Crs::Other("NAD_83".to_string()).wkt2(), None);
+    }
+}
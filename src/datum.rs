@@ -0,0 +1,177 @@
+use crate::{Crs, ErrorKind, ISO6709Coord, ISO6709Error, Position};
+use proj4rs::transform::transform;
+use proj4rs::Proj;
+
+const WGS84: &str = "+proj=longlat +datum=WGS84 +no_defs";
+
+impl Crs {
+    /// The PROJ definition of the geographic CRS, for the datums whose shift to WGS84 is a Helmert transformation.
+    /// `None` for datums such as NAD27 that need grid files, and for any CRS that is not known.
+    /// ```
+    /// # use iso6709parse::Crs;
+    /// assert!(Crs::from_datum_name("ED50").proj_definition().unwrap().contains("+ellps=intl"));
+    /// assert_eq!(Crs::from_datum_name("NAD27").proj_definition(), None);
+    /// ```
+    pub fn proj_definition(&self) -> Option<&'static str> {
+        match self {
+            Crs::Wgs84 | Crs::Wgs84_3D | Crs::Epsg(4326 | 4979) => Some(WGS84),
+            Crs::Epsg(4230) => {
+                Some("+proj=longlat +ellps=intl +towgs84=-87,-98,-121,0,0,0,0 +no_defs")
+            }
+            Crs::Epsg(4277) => Some(concat!(
+                "+proj=longlat +ellps=airy ",
+                "+towgs84=446.448,-125.157,542.06,0.15,0.247,0.842,-20.489 +no_defs"
+            )),
+            Crs::Epsg(4149) => {
+                Some("+proj=longlat +ellps=bessel +towgs84=674.374,15.056,405.346,0,0,0,0 +no_defs")
+            }
+            Crs::Epsg(4301) => Some(
+                "+proj=longlat +ellps=bessel +towgs84=-146.414,507.337,680.507,0,0,0,0 +no_defs",
+            ),
+            // Realized to match WGS84 to within a meter
+            Crs::Epsg(4258 | 4269 | 4283 | 4612 | 6668 | 7844 | 4167) => {
+                Some("+proj=longlat +ellps=GRS80 +towgs84=0,0,0,0,0,0,0 +no_defs")
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Position {
+    /// The coordinate moved from its CRS to WGS84, so that coordinates from different reference systems can be
+    /// compared. Coordinates already in WGS84 are returned as they are. The altitude is taken as a height above the
+    /// ellipsoid of the CRS, and is shifted along with the latitude and longitude.
+    /// Fails with [`ErrorKind::UnknownCrs`] without a CRS, or with one that [`Crs::proj_definition`] does not know;
+    /// see [`ISO6709Coord::to_wgs84_from`] for those.
+    /// ```
+    /// # use iso6709parse::{parse_position, ParserOptions};
+    /// let position = parse_position("+48.8566+002.3522+35CRSEPSG:4230/", &ParserOptions::default()).unwrap();
+    /// let wgs84 = position.to_wgs84().unwrap();
+    /// assert!((wgs84.lat - 48.8566).abs() < 0.002 && (wgs84.lon - 2.3522).abs() < 0.002);
+    /// assert_ne!(wgs84, position.coord);
+    /// ```
+    pub fn to_wgs84(&self) -> Result<ISO6709Coord, ISO6709Error> {
+        let crs = self.crs.as_ref().ok_or_else(|| {
+            ISO6709Error::new(
+                ErrorKind::UnknownCrs,
+                "the position has no CRS to transform from",
+            )
+        })?;
+        if crs.is_wgs84() {
+            return Ok(self.coord);
+        }
+        let definition = crs.proj_definition().ok_or_else(|| {
+            ISO6709Error::new(
+                ErrorKind::UnknownCrs,
+                format!("no transformation from {} to WGS84 is known", crs),
+            )
+        })?;
+        self.coord.to_wgs84_from(definition)
+    }
+}
+
+impl ISO6709Coord {
+    /// The coordinate moved to WGS84 from the geographic CRS of the PROJ `definition`, such as
+    /// `+proj=longlat +ellps=intl +towgs84=-87,-98,-121`, for a CRS that [`Crs::proj_definition`] does not know.
+    /// Fails with [`ErrorKind::UnknownCrs`] if the definition is invalid or its transformation fails, and as
+    /// [`ISO6709Coord::new`] does if a value is not finite or out of range.
+    pub fn to_wgs84_from(&self, definition: &str) -> Result<ISO6709Coord, ISO6709Error> {
+        let coord = ISO6709Coord::new(self.lat, self.lon, self.altitude)?;
+        let proj_error = |err: proj4rs::errors::Error| {
+            ISO6709Error::new(
+                ErrorKind::UnknownCrs,
+                format!("cannot transform from `{}`: {}", definition, err),
+            )
+        };
+        let source = Proj::from_proj_string(definition).map_err(proj_error)?;
+        let wgs84 = Proj::from_proj_string(WGS84).map_err(proj_error)?;
+        let mut point = (
+            coord.lon.to_radians(),
+            coord.lat.to_radians(),
+            coord.altitude.unwrap_or(0.),
+        );
+        transform(&source, &wgs84, &mut point).map_err(proj_error)?;
+        let (lon, lat, altitude) = point;
+        ISO6709Coord::new(
+            lat.to_degrees(),
+            lon.to_degrees(),
+            coord.altitude.map(|_| altitude),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_position, ParserOptions};
+
+    fn position(str: &str) -> Position {
+        parse_position(str, &ParserOptions::default()).unwrap()
+    }
+
+    #[test]
+    fn should_transform_to_wgs84() {
+        // The Airy transit circle is on the OSGB36 prime meridian, about 102m west of the WGS84 one and 1.9″ south
+        // of its WGS84 latitude; the Helmert shift is good to about ten meters
+        let greenwich = position("+51.47781+000.0000+0CRSEPSG:4277/")
+            .to_wgs84()
+            .unwrap();
+        assert!((greenwich.lon + 0.00147).abs() < 0.0002, "{:?}", greenwich);
+        assert!((greenwich.lat - 51.47834).abs() < 0.0001, "{:?}", greenwich);
+        assert!(greenwich.altitude.unwrap().abs() < 100.);
+
+        let etrs89 = position("+52.52+013.40+34CRSEPSG:4258/");
+        let wgs84 = etrs89.to_wgs84().unwrap();
+        assert!((wgs84.lat - 52.52).abs() < 1e-8 && (wgs84.lon - 13.4).abs() < 1e-8);
+        assert!((wgs84.altitude.unwrap() - 34.).abs() < 1e-3);
+
+        let wgs84 = position("+35.50-170.10/");
+        assert_eq!(wgs84.to_wgs84(), Ok(wgs84.coord));
+        let ed50 = ISO6709Coord::new(48.8566, 2.3522, None).unwrap();
+        let transformed = ed50
+            .to_wgs84_from(Crs::Epsg(4230).proj_definition().unwrap())
+            .unwrap();
+        assert_eq!(transformed.altitude, None);
+        assert!((transformed.lat - ed50.lat).abs() > 1e-4);
+    }
+
+    #[test]
+    fn should_err_without_transformation() {
+        for str in [
+            "+35.50-170.10+12CRSEPSG:4267/",
+            "+35.50-170.10+12CRSNAD_83/",
+        ] {
+            assert_eq!(
+                position(str).to_wgs84().unwrap_err().kind(),
+                ErrorKind::UnknownCrs,
+                "{}",
+                str
+            );
+        }
+        let options = ParserOptions {
+            default_crs: None,
+            ..Default::default()
+        };
+        let position = parse_position("+35.50-170.10/", &options).unwrap();
+        assert_eq!(
+            position.to_wgs84().unwrap_err().kind(),
+            ErrorKind::UnknownCrs
+        );
+        let coord = ISO6709Coord::new(35.5, -170.1, None).unwrap();
+        assert_eq!(
+            coord.to_wgs84_from("+proj=nonsense").unwrap_err().kind(),
+            ErrorKind::UnknownCrs
+        );
+        assert_eq!(
+            ISO6709Coord {
+                lat: f64::NAN,
+                lon: 0.,
+                altitude: None
+            }
+            .to_wgs84_from(WGS84)
+            .unwrap_err()
+            .kind(),
+            ErrorKind::NonFinite
+        );
+    }
+}
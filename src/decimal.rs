@@ -0,0 +1,70 @@
+use crate::{parse_as, CoordNumber, ISO6709Coord, ISO6709Error};
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+/// Parses either of the two formats, doing the degree, minute and second arithmetic in decimal rather than binary
+/// floating point. Degrees and decimal minutes or seconds are kept exactly as written, so `+35.50-170.10/` gives
+/// exactly `35.50` and `-170.10`, and minutes and seconds are converted to 28 significant digits instead of the
+/// ~16 of `f64`.
+/// This is [`crate::parse_as`] for `Decimal`: the whole string (other than surrounding whitespace) must be part of the
+/// coordinate.
+/// ```
+/// # use iso6709parse::parse_decimal;
+/// use rust_decimal::Decimal;
+/// use std::str::FromStr;
+///
+/// let coord = parse_decimal("50°40′46.461″N 95°48′26.533″W").unwrap();
+/// assert_eq!(coord.lon.to_string(), "-95.80737027777777777777777778");
+///
+/// let coord = parse_decimal("+35.50-170.10+8712.5CRSWGS_84/").unwrap();
+/// assert_eq!(coord.lat, Decimal::from_str("35.50").unwrap());
+/// assert_eq!(coord.altitude, Some(Decimal::from_str("8712.5").unwrap()));
+/// ```
+pub fn parse_decimal(str: &str) -> Result<ISO6709Coord<Decimal>, ISO6709Error> {
+    parse_as(str)
+}
+
+impl CoordNumber for Decimal {
+    fn from_digits(digits: &str) -> Option<Self> {
+        Decimal::from_str(digits).ok()
+    }
+
+    fn from_u16(value: u16) -> Self {
+        Decimal::from(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dec(str: &str) -> Decimal {
+        Decimal::from_str(str).unwrap()
+    }
+
+    #[test]
+    fn should_parse_decimal() {
+        let coord = parse_decimal("+4520.30-02130.00").unwrap();
+        assert_eq!(coord.lat, dec("45.338333333333333333333333333"));
+        assert_eq!(coord.lon, dec("-21.5"));
+        assert_eq!(coord.altitude, None);
+
+        let coord = parse_decimal("S452035.1528E1452018/").unwrap();
+        assert_eq!(coord.lat, dec("-45.343098"));
+        assert_eq!(coord.lon, dec("145.33833333333333333333333333"));
+
+        let coord = parse_decimal("15°30′00.000″N 95°15′00.000″W -123.45m").unwrap();
+        assert_eq!(coord.lat, dec("15.5"));
+        assert_eq!(coord.lon, dec("-95.25"));
+        assert_eq!(coord.altitude, Some(dec("-123.45")));
+
+        let coord = parse_decimal("+35.50-170.10+12/").unwrap();
+        assert_eq!(coord.altitude, None);
+    }
+
+    #[test]
+    fn should_err_decimal() {
+        assert!(parse_decimal("+90.1-021.0").is_err());
+        assert!(parse_decimal("N35.50W170.10 trailing").is_err());
+    }
+}
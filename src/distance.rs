@@ -0,0 +1,130 @@
+use crate::ISO6709Coord;
+
+/// Mean radius of the earth in meters, as used by the haversine formula
+const EARTH_RADIUS: f64 = 6_371_008.8;
+
+impl ISO6709Coord {
+    /// Great circle distance to `other` in meters, using the haversine formula on a spherical earth.
+    /// This is accurate to about 0.5%, which is enough for proximity checks. The altitude is ignored.
+    /// ```
+    /// # use iso6709parse::{parse, ISO6709Coord};
+    /// let a: ISO6709Coord = parse("+00+000/").unwrap();
+    /// let b: ISO6709Coord = parse("+01+000/").unwrap();
+    /// assert_eq!(a.distance_m(&b).round(), 111195.);
+    /// ```
+    pub fn distance_m(&self, other: &ISO6709Coord) -> f64 {
+        let (lat1, lat2) = (self.lat_radians(), other.lat_radians());
+        let half_dlat = (lat2 - lat1) / 2.;
+        let half_dlon = (other.lon - self.lon).to_radians() / 2.;
+        let a = half_dlat.sin().powi(2) + lat1.cos() * lat2.cos() * half_dlon.sin().powi(2);
+        2. * EARTH_RADIUS * a.sqrt().min(1.).asin()
+    }
+
+    /// Distance to `other` in meters along the WGS84 ellipsoid, using Vincenty's inverse formula.
+    /// This is accurate to within a millimeter, but returns `None` for nearly antipodal points where the formula
+    /// does not converge. The altitude is ignored.
+    /// ```
+    /// # use iso6709parse::{parse, ISO6709Coord};
+    /// let a: ISO6709Coord = parse("+00+000/").unwrap();
+    /// let b: ISO6709Coord = parse("+00+001/").unwrap();
+    /// assert_eq!((a.geodesic_distance_m(&b).unwrap() * 100.).round() / 100., 111319.49);
+    /// ```
+    #[cfg(feature = "geodesic")]
+    pub fn geodesic_distance_m(&self, other: &ISO6709Coord) -> Option<f64> {
+        const A: f64 = 6_378_137.;
+        const F: f64 = 1. / 298.257_223_563;
+        const B: f64 = A * (1. - F);
+
+        let l = (other.lon - self.lon).to_radians();
+        let u1 = ((1. - F) * self.lat.to_radians().tan()).atan();
+        let u2 = ((1. - F) * other.lat.to_radians().tan()).atan();
+        let (sin_u1, cos_u1) = u1.sin_cos();
+        let (sin_u2, cos_u2) = u2.sin_cos();
+
+        let mut lambda = l;
+        for _ in 0..200 {
+            let (sin_lambda, cos_lambda) = lambda.sin_cos();
+            let sin_sigma = ((cos_u2 * sin_lambda).powi(2)
+                + (cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda).powi(2))
+            .sqrt();
+            if sin_sigma == 0. {
+                // Same point
+                return Some(0.);
+            }
+            let cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+            let sigma = sin_sigma.atan2(cos_sigma);
+            let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+            let cos_sq_alpha = 1. - sin_alpha.powi(2);
+            let cos_2sigma_m = if cos_sq_alpha == 0. {
+                // Both points on the equator
+                0.
+            } else {
+                cos_sigma - 2. * sin_u1 * sin_u2 / cos_sq_alpha
+            };
+            let c = F / 16. * cos_sq_alpha * (4. + F * (4. - 3. * cos_sq_alpha));
+            let previous = lambda;
+            lambda = l
+                + (1. - c)
+                    * F
+                    * sin_alpha
+                    * (sigma
+                        + c * sin_sigma
+                            * (cos_2sigma_m + c * cos_sigma * (-1. + 2. * cos_2sigma_m.powi(2))));
+
+            if (lambda - previous).abs() < 1e-12 {
+                let u_sq = cos_sq_alpha * (A * A - B * B) / (B * B);
+                let a = 1. + u_sq / 16384. * (4096. + u_sq * (-768. + u_sq * (320. - 175. * u_sq)));
+                let b = u_sq / 1024. * (256. + u_sq * (-128. + u_sq * (74. - 47. * u_sq)));
+                let delta_sigma = b
+                    * sin_sigma
+                    * (cos_2sigma_m
+                        + b / 4.
+                            * (cos_sigma * (-1. + 2. * cos_2sigma_m.powi(2))
+                                - b / 6.
+                                    * cos_2sigma_m
+                                    * (-3. + 4. * sin_sigma.powi(2))
+                                    * (-3. + 4. * cos_2sigma_m.powi(2))));
+                return Some(B * a * (sigma - delta_sigma));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, ISO6709Coord};
+
+    #[test]
+    fn should_calculate_haversine() {
+        let a: ISO6709Coord = parse("N35.50W170.10/").unwrap();
+        assert_eq!(a.distance_m(&a), 0.);
+
+        let north_pole: ISO6709Coord = parse("+90+000/").unwrap();
+        let south_pole: ISO6709Coord = parse("-90+000/").unwrap();
+        let half_circumference = std::f64::consts::PI * super::EARTH_RADIUS;
+        assert!((north_pole.distance_m(&south_pole) - half_circumference).abs() < 1e-6);
+
+        // Across the antimeridian
+        let west: ISO6709Coord = parse("+00-179.5/").unwrap();
+        let east: ISO6709Coord = parse("+00+179.5/").unwrap();
+        assert_eq!(west.distance_m(&east).round(), 111195.);
+        assert_eq!(west.distance_m(&east), east.distance_m(&west));
+    }
+
+    #[cfg(feature = "geodesic")]
+    #[test]
+    fn should_calculate_vincenty() {
+        // Flinders Peak to Buninyong, from Vincenty's paper
+        let flinders: ISO6709Coord = parse("-375703.72030+1442529.52440/").unwrap();
+        let buninyong: ISO6709Coord = parse("-373910.15610+1435535.38390/").unwrap();
+        let distance = flinders.geodesic_distance_m(&buninyong).unwrap();
+        assert!((distance - 54972.271).abs() < 0.001, "{}", distance);
+
+        assert_eq!(flinders.geodesic_distance_m(&flinders), Some(0.));
+
+        let a: ISO6709Coord = parse("+00+000/").unwrap();
+        let antipode: ISO6709Coord = parse("+00.5+179.7/").unwrap();
+        assert_eq!(a.geodesic_distance_m(&antipode), None);
+    }
+}
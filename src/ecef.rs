@@ -0,0 +1,171 @@
+use crate::ISO6709Coord;
+
+/// Semi-major axis of the WGS84 ellipsoid in meters
+const A: f64 = 6_378_137.;
+/// Flattening of the WGS84 ellipsoid
+const F: f64 = 1. / 298.257_223_563;
+/// Semi-minor axis
+const B: f64 = A * (1. - F);
+/// First eccentricity squared
+const E2: f64 = F * (2. - F);
+
+impl ISO6709Coord {
+    /// Earth-centered, earth-fixed X, Y and Z in meters on the WGS84 ellipsoid, as GNSS receivers and satellite
+    /// orbits use. X points to the prime meridian at the equator, Y to 90°E and Z to the north pole.
+    /// The altitude is taken as the height above the ellipsoid, and as 0 if there is none. A coordinate in another CRS
+    /// must be transformed to WGS84 first, see [`crate::Crs::is_wgs84`].
+    /// ```
+    /// # use iso6709parse::{parse, ISO6709Coord};
+    /// let coord: ISO6709Coord = parse("+00+000+100CRSWGS_84/").unwrap();
+    /// assert_eq!(coord.to_ecef(), (6_378_237., 0., 0.));
+    /// ```
+    pub fn to_ecef(&self) -> (f64, f64, f64) {
+        let (sin_lat, cos_lat) = self.lat.to_radians().sin_cos();
+        let (sin_lon, cos_lon) = self.lon.to_radians().sin_cos();
+        let height = self.altitude.unwrap_or(0.);
+        // Radius of curvature in the prime vertical
+        let n = A / (1. - E2 * sin_lat * sin_lat).sqrt();
+        (
+            (n + height) * cos_lat * cos_lon,
+            (n + height) * cos_lat * sin_lon,
+            (n * (1. - E2) + height) * sin_lat,
+        )
+    }
+
+    /// The WGS84 coordinate of earth-centered, earth-fixed X, Y and Z in meters, with the height above the ellipsoid
+    /// as the altitude. This uses Heikkinen's closed form, which is exact to well below a millimeter anywhere outside
+    /// of the earth's core, and gives NaN within a few hundred kilometers of the center.
+    /// ```
+    /// # use iso6709parse::ISO6709Coord;
+    /// let coord = ISO6709Coord::from_ecef(0., 6_378_137., 0.);
+    /// assert_eq!((coord.lat, coord.lon, coord.altitude), (0., 90., Some(0.)));
+    ///
+    /// let everest = ISO6709Coord::new(27.5916, 86.564, Some(8850.)).unwrap();
+    /// let (x, y, z) = everest.to_ecef();
+    /// let coord = ISO6709Coord::from_ecef(x, y, z);
+    /// assert!((coord.lat - 27.5916).abs() < 1e-9 && (coord.altitude.unwrap() - 8850.).abs() < 1e-4);
+    /// ```
+    pub fn from_ecef(x: f64, y: f64, z: f64) -> ISO6709Coord {
+        let p = x.hypot(y);
+        let big_f = 54. * B * B * z * z;
+        let g = p * p + (1. - E2) * z * z - E2 * (A * A - B * B);
+        let c = E2 * E2 * big_f * p * p / (g * g * g);
+        let s = (1. + c + (c * c + 2. * c).sqrt()).cbrt();
+        let k = s + 1. + 1. / s;
+        let big_p = big_f / (3. * k * k * g * g);
+        let q = (1. + 2. * E2 * E2 * big_p).sqrt();
+        let r0 = -big_p * E2 * p / (1. + q)
+            + (A * A / 2. * (1. + 1. / q)
+                - big_p * (1. - E2) * z * z / (q * (1. + q))
+                - big_p * p * p / 2.)
+                .sqrt();
+        let u = (p - E2 * r0).hypot(z);
+        let v = ((p - E2 * r0).powi(2) + (1. - E2) * z * z).sqrt();
+        let z0 = B * B * z / (A * v);
+        let height = u * (1. - B * B / (A * v));
+        // Second eccentricity squared
+        let ep2 = (A * A - B * B) / (B * B);
+        let lat = (z + ep2 * z0).atan2(p).to_degrees();
+        let lon = y.atan2(x).to_degrees();
+        ISO6709Coord::unsigned_zero(lat, lon, Some(height))
+    }
+
+    /// East, north and up in meters from `origin` in its local tangent plane, for computing in a local frame as
+    /// robots and drones do. Up is along the normal to the WGS84 ellipsoid at the origin, so the frame curves away
+    /// from the surface with distance. Missing altitudes are taken as 0, as in [`ISO6709Coord::to_ecef`].
+    /// Behind the `ecef` feature, along with the ECEF conversions it is built on.
+    /// ```
+    /// # use iso6709parse::{parse, ISO6709Coord};
+    /// let origin: ISO6709Coord = parse("+00+000+100CRSWGS_84/").unwrap();
+    /// let point: ISO6709Coord = parse("+00+000+150CRSWGS_84/").unwrap();
+    /// let (east, north, up) = point.to_enu(&origin);
+    /// assert!(east.abs() < 1e-9 && north.abs() < 1e-9 && (up - 50.).abs() < 1e-9);
+    /// ```
+    pub fn to_enu(&self, origin: &ISO6709Coord) -> (f64, f64, f64) {
+        let (x, y, z) = self.to_ecef();
+        let (x0, y0, z0) = origin.to_ecef();
+        let (dx, dy, dz) = (x - x0, y - y0, z - z0);
+        let (sin_lat, cos_lat) = origin.lat.to_radians().sin_cos();
+        let (sin_lon, cos_lon) = origin.lon.to_radians().sin_cos();
+        (
+            -sin_lon * dx + cos_lon * dy,
+            -sin_lat * cos_lon * dx - sin_lat * sin_lon * dy + cos_lat * dz,
+            cos_lat * cos_lon * dx + cos_lat * sin_lon * dy + sin_lat * dz,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(coord: ISO6709Coord, expected: ISO6709Coord) {
+        assert!((coord.lat - expected.lat).abs() < 1e-9, "{:?}", coord);
+        assert!((coord.lon - expected.lon).abs() < 1e-9, "{:?}", coord);
+        let altitude = coord.altitude.unwrap() - expected.altitude.unwrap_or(0.);
+        assert!(altitude.abs() < 1e-4, "{:?}", coord);
+    }
+
+    #[test]
+    fn should_convert_to_ecef() {
+        let ecef = |lat, lon, altitude| ISO6709Coord::from_parts(lat, lon, altitude).to_ecef();
+        assert_eq!(ecef(0., 0., None), (A, 0., 0.));
+        let (x, y, z) = ecef(90., 0., Some(10.));
+        assert!(x.abs() < 1e-6 && y == 0. && (z - B - 10.).abs() < 1e-6);
+        let (x, y, z) = ecef(0., 90., None);
+        assert!(x.abs() < 1e-6 && (y - A).abs() < 1e-6 && z == 0.);
+        let (x, y, z) = ecef(-90., 0., None);
+        assert!(x.abs() < 1e-6 && y == 0. && (z + B).abs() < 1e-6);
+    }
+
+    #[test]
+    fn should_convert_from_ecef() {
+        for coord in [
+            ISO6709Coord::from_parts(0., 0., None),
+            ISO6709Coord::from_parts(35.5, -170.1, Some(8712.)),
+            ISO6709Coord::from_parts(-45.25, 120.75, Some(-400.)),
+            ISO6709Coord::from_parts(89.999, 10., Some(3000.)),
+            ISO6709Coord::from_parts(-10., 179.9, Some(400_000.)),
+            ISO6709Coord::from_parts(27.5916, 86.564, Some(8850.)),
+        ] {
+            let (x, y, z) = coord.to_ecef();
+            assert_close(ISO6709Coord::from_ecef(x, y, z), coord);
+        }
+        let pole = ISO6709Coord::from_ecef(0., 0., B + 5.);
+        assert_eq!(pole.lat, 90.);
+        assert!((pole.altitude.unwrap() - 5.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn should_convert_to_enu() {
+        let origin = ISO6709Coord::from_parts(35.5, -170.1, Some(100.));
+        assert_eq!(origin.to_enu(&origin), (0., 0., 0.));
+
+        // A tenth of a second of arc is a few meters, where the curve of the frame is well below a millimeter
+        let north = ISO6709Coord::from_parts(35.5 + 0.1 / 3600., -170.1, Some(100.));
+        let (e, n, u) = north.to_enu(&origin);
+        assert!(
+            e.abs() < 1e-6 && (n - 3.0820).abs() < 1e-3 && u.abs() < 1e-3,
+            "{} {} {}",
+            e,
+            n,
+            u
+        );
+        let east = ISO6709Coord::from_parts(35.5, -170.1 + 0.1 / 3600., Some(100.));
+        let (e, n, u) = east.to_enu(&origin);
+        assert!(
+            (e - 2.5203).abs() < 1e-3 && n.abs() < 1e-6 && u.abs() < 1e-3,
+            "{} {} {}",
+            e,
+            n,
+            u
+        );
+        let up = ISO6709Coord::from_parts(35.5, -170.1, Some(130.));
+        let (e, n, u) = up.to_enu(&origin);
+        assert!(e.abs() < 1e-6 && n.abs() < 1e-6 && (u - 30.).abs() < 1e-6);
+
+        let (_, _, u) =
+            ISO6709Coord::from_parts(0., 90., None).to_enu(&ISO6709Coord::from_parts(0., 0., None));
+        assert!((u + A).abs() < 1e-6);
+    }
+}
@@ -1,15 +1,186 @@
-#[derive(Debug, PartialEq)]
-pub struct ISO6709Error(String);
+use crate::parsers::common::MAX_DIGITS;
 
-impl std::error::Error for ISO6709Error {}
+/// The kind of failure behind an [`ISO6709Error`]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The input is not in either of the ISO6709 formats, or a value is out of range
+    Syntax,
+    /// The CRS is not in the registry given in the `ParserOptions`
+    UnknownCrs,
+    /// The corners of a bounding box are missing or out of order
+    InvalidBoundingBox,
+    /// A latitude, longitude or altitude is NaN or infinite
+    NonFinite,
+    /// A number in the input has more digits than could ever be meaningful, see [`crate::parse`]
+    TooManyDigits,
+    /// The input is longer than [`crate::ParserOptions::max_input_len`]
+    InputTooLong,
+    /// A latitude or longitude has both a sign and a hemisphere letter, such as `+35.50S`
+    SignConflict,
+    /// A latitude or longitude given to a constructor or formatter is beyond ±90° or ±180°, or a tile level is
+    /// beyond what the tile scheme has
+    OutOfRange,
+    /// A number is written with an exponent, such as `1e2`, see [`crate::ParserOptions::allow_exponent`]
+    ScientificNotation,
+    /// The coordinate has no altitude, but one is required, see [`crate::parse_with_altitude`]
+    MissingAltitude,
+    /// An altitude in the string representation is not followed by a CRS, see [`crate::ParserOptions::require_crs`]
+    MissingCrs,
+    /// The coordinate was rejected by the [`crate::ParserOptions::validator`]
+    Rejected,
+    /// The writer given to [`crate::format_into`] or [`crate::write_into`] failed
+    Write,
+    /// The two representations given to [`crate::cross_check`] are further apart than the tolerance
+    Mismatch,
+    /// The coordinate has an altitude, which [`crate::ParserOptions::altitude`] rejects
+    UnexpectedAltitude,
+}
+
+/// The error returned by the parse functions, along with the input that failed and the underlying `nom` failure
+/// where there is one.
+/// ```
+/// # use iso6709parse::{parse, ErrorKind, ISO6709Coord};
+/// use std::error::Error;
+///
+/// let err = parse::<ISO6709Coord>("N35.50W1x0.10/").unwrap_err();
+/// assert_eq!(err.kind(), ErrorKind::Syntax);
+/// assert_eq!(err.input(), Some("N35.50W1x0.10/"));
+/// assert!(err.source().is_some());
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct ISO6709Error {
+    kind: ErrorKind,
+    message: String,
+    input: Option<String>,
+    source: Option<NomFailure>,
+}
+
+impl ISO6709Error {
+    pub(crate) fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        ISO6709Error {
+            kind,
+            message: message.into(),
+            input: None,
+            source: None,
+        }
+    }
+
+    /// Records the string being parsed, replacing what inner parse functions recorded
+    pub(crate) fn with_input(mut self, input: &str) -> Self {
+        self.input = Some(input.to_string());
+        self
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// The message without the prefix that `Display` adds
+    pub(crate) fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// The whole string that failed to parse, or `None` for errors that do not come from parsing, such as
+    /// those of [`crate::ISO6709Coord::new`]
+    pub fn input(&self) -> Option<&str> {
+        self.input.as_deref()
+    }
+}
+
+impl std::error::Error for ISO6709Error {
+    /// The `nom` failure, for syntax errors
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| source as &(dyn std::error::Error + 'static))
+    }
+}
 impl std::fmt::Display for ISO6709Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Failed to parse ISO6709 coordinate: {}", &self.0)
+        match self.kind {
+            ErrorKind::NonFinite | ErrorKind::OutOfRange => {
+                write!(f, "Invalid ISO6709 coordinate: {}", &self.message)
+            }
+            _ => write!(f, "Failed to parse ISO6709 coordinate: {}", &self.message),
+        }
     }
 }
 
 impl From<nom::error::Error<&'_ str>> for ISO6709Error {
+    /// The message is worded here rather than taken from `nom`, whose wording changes between versions
     fn from(value: nom::error::Error<&'_ str>) -> Self {
-        ISO6709Error(value.to_string())
+        let (kind, message) = match value.code {
+            nom::error::ErrorKind::TooLarge => (
+                ErrorKind::TooManyDigits,
+                format!(
+                    "a number has more than {} digits at `{}`",
+                    MAX_DIGITS, value.input
+                ),
+            ),
+            _ if value.input.is_empty() => {
+                (ErrorKind::Syntax, "unexpected end of input".to_string())
+            }
+            _ => (
+                ErrorKind::Syntax,
+                format!("unexpected input at `{}`", value.input),
+            ),
+        };
+        ISO6709Error {
+            source: Some(NomFailure {
+                code: value.code,
+                remaining: value.input.to_string(),
+            }),
+            ..ISO6709Error::new(kind, message)
+        }
+    }
+}
+
+/// An owned copy of the `nom` error, which is not `Clone`
+#[derive(Debug, PartialEq, Clone)]
+struct NomFailure {
+    code: nom::error::ErrorKind,
+    remaining: String,
+}
+
+impl std::error::Error for NomFailure {}
+impl std::fmt::Display for NomFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "parser stopped at `{}`", self.remaining)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, ISO6709Coord};
+    use std::error::Error;
+
+    #[test]
+    fn should_word_syntax_errors() {
+        let err = parse::<ISO6709Coord>("N35.50W1x0.10/").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Failed to parse ISO6709 coordinate: unexpected input at `1x0.10/`"
+        );
+        assert_eq!(
+            err.source().unwrap().to_string(),
+            "parser stopped at `1x0.10/`"
+        );
+
+        let err = parse::<ISO6709Coord>("N35.50").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Failed to parse ISO6709 coordinate: unexpected end of input"
+        );
+
+        let digits = "1".repeat(50);
+        let err = parse::<ISO6709Coord>(&format!("+35.{}-170.10/", digits)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "Failed to parse ISO6709 coordinate: a number has more than 40 digits at `{}-170.10/`",
+                digits
+            )
+        );
     }
 }
@@ -0,0 +1,181 @@
+use crate::{ErrorKind, ISO6709Coord, ISO6709Error};
+
+/// SRID of WGS 84, the only one that is read or written
+pub(crate) const SRID_WGS84: u32 = 4326;
+
+const POINT: u32 = 1;
+const EWKB_Z: u32 = 0x8000_0000;
+const EWKB_M: u32 = 0x4000_0000;
+const EWKB_SRID: u32 = 0x2000_0000;
+
+/// Writes the coordinate as a little-endian EWKB point with SRID 4326, the binary format of a PostGIS geometry.
+/// The point has a Z coordinate only if the coordinate has an altitude.
+pub(crate) fn to_ewkb(coord: &ISO6709Coord) -> Vec<u8> {
+    let mut kind = POINT | EWKB_SRID;
+    if coord.altitude.is_some() {
+        kind |= EWKB_Z;
+    }
+    let mut out = Vec::with_capacity(33);
+    out.push(1);
+    out.extend_from_slice(&kind.to_le_bytes());
+    out.extend_from_slice(&SRID_WGS84.to_le_bytes());
+    out.extend_from_slice(&coord.lon.to_le_bytes());
+    out.extend_from_slice(&coord.lat.to_le_bytes());
+    if let Some(altitude) = coord.altitude {
+        out.extend_from_slice(&altitude.to_le_bytes());
+    }
+    out
+}
+
+/// Reads an EWKB or ISO WKB point of either byte order. A point without an SRID is taken to be WGS 84, and a
+/// measure is skipped.
+pub(crate) fn from_ewkb(bytes: &[u8]) -> Result<ISO6709Coord, ISO6709Error> {
+    let mut reader = Reader {
+        bytes,
+        little_endian: true,
+    };
+    reader.little_endian = match reader.take(1)? {
+        [0] => false,
+        [1] => true,
+        _ => return Err(syntax("invalid byte order")),
+    };
+    let kind = reader.u32()?;
+    let (geometry, has_z, has_m) = match kind & !(EWKB_Z | EWKB_M | EWKB_SRID) {
+        iso @ 1000..=3999 => (iso % 1000, iso / 1000 != 2, iso / 1000 != 1),
+        geometry => (geometry, kind & EWKB_Z != 0, kind & EWKB_M != 0),
+    };
+    if geometry != POINT {
+        return Err(syntax(format!("geometry type {} is not a point", geometry)));
+    }
+    if kind & EWKB_SRID != 0 {
+        let srid = reader.u32()?;
+        if srid != SRID_WGS84 {
+            return Err(ISO6709Error::new(
+                ErrorKind::UnknownCrs,
+                format!("SRID {} is not WGS 84", srid),
+            ));
+        }
+    }
+    let lon = reader.f64()?;
+    let lat = reader.f64()?;
+    let altitude = if has_z { Some(reader.f64()?) } else { None };
+    if has_m {
+        reader.f64()?;
+    }
+    if !reader.bytes.is_empty() {
+        return Err(syntax("trailing bytes after the point"));
+    }
+    ISO6709Coord::new(lat, lon, altitude)
+}
+
+/// Reads the hexadecimal text form of EWKB, as PostGIS writes geometries in text
+#[cfg(feature = "sqlx-postgres")]
+pub(crate) fn from_hex_ewkb(hex: &str) -> Result<ISO6709Coord, ISO6709Error> {
+    let hex = hex.as_bytes();
+    if !hex.len().is_multiple_of(2) {
+        return Err(syntax("odd number of hexadecimal digits"));
+    }
+    let bytes = hex
+        .chunks(2)
+        .map(|pair| {
+            std::str::from_utf8(pair)
+                .ok()
+                .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+                .ok_or_else(|| syntax("invalid hexadecimal digit"))
+        })
+        .collect::<Result<Vec<u8>, _>>()?;
+    from_ewkb(&bytes)
+}
+
+fn syntax(message: impl Into<String>) -> ISO6709Error {
+    ISO6709Error::new(ErrorKind::Syntax, message)
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    little_endian: bool,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], ISO6709Error> {
+        if self.bytes.len() < len {
+            return Err(syntax("EWKB ends before the point"));
+        }
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn u32(&mut self) -> Result<u32, ISO6709Error> {
+        let bytes = self.take(4)?.try_into().expect("4 bytes");
+        Ok(if self.little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn f64(&mut self) -> Result<f64, ISO6709Error> {
+        let bytes = self.take(8)?.try_into().expect("8 bytes");
+        Ok(if self.little_endian {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_round_trip_ewkb() {
+        let coord = ISO6709Coord::new(35.5, -170.1, Some(8712.)).unwrap();
+        let ewkb = to_ewkb(&coord);
+        assert_eq!(ewkb.len(), 33);
+        assert_eq!(&ewkb[..9], &[1, 1, 0, 0, 0xa0, 0xe6, 0x10, 0, 0]);
+        assert_eq!(from_ewkb(&ewkb), Ok(coord));
+
+        let coord = ISO6709Coord::new(35.5, -170.1, None).unwrap();
+        assert_eq!(to_ewkb(&coord).len(), 25);
+        assert_eq!(from_ewkb(&to_ewkb(&coord)), Ok(coord));
+    }
+
+    #[test]
+    fn should_read_other_wkb() {
+        // POINT Z (2 1 3) in ISO WKB, big endian, without an SRID
+        let mut wkb = vec![0, 0, 0, 0x03, 0xe9];
+        for value in [2f64, 1., 3.] {
+            wkb.extend_from_slice(&value.to_be_bytes());
+        }
+        assert_eq!(
+            from_ewkb(&wkb),
+            Ok(ISO6709Coord::new(1., 2., Some(3.)).unwrap())
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "sqlx-postgres")]
+    fn should_read_hex_ewkb() {
+        assert_eq!(
+            from_hex_ewkb("0101000020E6100000000000000000F03F0000000000000040"),
+            Ok(ISO6709Coord::new(2., 1., None).unwrap())
+        );
+        assert!(from_hex_ewkb("0x").is_err());
+    }
+
+    #[test]
+    fn should_reject_bad_ewkb() {
+        let coord = ISO6709Coord::new(35.5, -170.1, None).unwrap();
+        let mut ewkb = to_ewkb(&coord);
+        assert_eq!(
+            from_ewkb(&ewkb[..20]).unwrap_err().kind(),
+            ErrorKind::Syntax
+        );
+        ewkb[5] = 0x11;
+        assert_eq!(from_ewkb(&ewkb).unwrap_err().kind(), ErrorKind::UnknownCrs);
+        ewkb[1] = 2;
+        assert_eq!(from_ewkb(&ewkb).unwrap_err().kind(), ErrorKind::Syntax);
+    }
+}
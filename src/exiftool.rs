@@ -0,0 +1,153 @@
+use crate::parsers::common::{digits0, digits1};
+use crate::{trim, ErrorKind, FromIso6709, ISO6709Coord, ISO6709Error};
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::{one_of, space0, space1};
+use nom::combinator::{all_consuming, map_res, opt, recognize, value};
+use nom::sequence::{pair, preceded, terminated, tuple};
+use nom::{Finish, IResult};
+
+/// Parses the composite `GPSPosition` written by exiftool and many photo tools, such as
+/// `50 deg 40' 46.46" N, 95 deg 48' 26.53" W`, into any struct that implements [`FromIso6709`].
+/// Minutes and seconds are optional, as when exiftool is run with `-c "%.6f"`, and an altitude such as
+/// `, 123.4 m Above Sea Level` may follow.
+/// ```
+/// # use iso6709parse::{parse_exiftool, ISO6709Coord};
+/// let coord: ISO6709Coord = parse_exiftool(r#"50 deg 40' 46.46" N, 95 deg 48' 26.53" W"#).unwrap();
+/// assert!((coord.lat - 50.679572).abs() < 1e-6);
+/// assert!((coord.lon + 95.807369).abs() < 1e-6);
+///
+/// let coord: ISO6709Coord = parse_exiftool("50.679572 N, 95.807369 W, 12.5 m Below Sea Level").unwrap();
+/// assert_eq!(coord.altitude, Some(-12.5));
+/// ```
+pub fn parse_exiftool<T>(str: &str) -> Result<T, ISO6709Error>
+where
+    T: FromIso6709,
+{
+    read_exiftool(str)
+        .map(T::from_iso6709)
+        .map_err(|err| err.with_input(str))
+}
+
+fn read_exiftool(str: &str) -> Result<ISO6709Coord, ISO6709Error> {
+    let (_, (lat, lon, altitude)) = all_consuming(trim(position))(str).finish()?;
+    let coord = ISO6709Coord::new(lat?, lon?, altitude)?;
+    Ok(ISO6709Coord::unsigned_zero(
+        coord.lat,
+        coord.lon,
+        coord.altitude,
+    ))
+}
+
+type Angle = Result<f64, ISO6709Error>;
+
+fn position(inp: &str) -> IResult<&str, (Angle, Angle, Option<f64>)> {
+    tuple((
+        angle('N', 'S'),
+        preceded(pair(tag(","), space0), angle('E', 'W')),
+        opt(preceded(pair(tag(","), space0), altitude)),
+    ))(inp)
+}
+
+fn number(inp: &str) -> IResult<&str, f64> {
+    map_res(
+        recognize(pair(digits1, opt(pair(tag("."), digits0)))),
+        str::parse,
+    )(inp)
+}
+
+/// Degrees, minutes and seconds followed by the hemisphere. Minutes or seconds of 60 or more still parse, with an
+/// error in place of the angle, so that the error says what is wrong rather than where the parser stopped.
+fn angle<'a>(positive: char, negative: char) -> impl FnMut(&'a str) -> IResult<&'a str, Angle> {
+    move |inp| {
+        let (rem, (degrees, minutes, seconds, hemisphere)) = tuple((
+            terminated(number, opt(preceded(space1, tag("deg")))),
+            opt(preceded(space1, terminated(number, tag("'")))),
+            opt(preceded(space1, terminated(number, tag("\"")))),
+            preceded(space1, one_of([positive, negative].as_slice())),
+        ))(inp)?;
+        let (minutes, seconds) = (minutes.unwrap_or(0.), seconds.unwrap_or(0.));
+        let angle = if minutes >= 60. || seconds >= 60. {
+            Err(ISO6709Error::new(
+                ErrorKind::Syntax,
+                "minutes and seconds must be less than 60",
+            ))
+        } else {
+            let magnitude = degrees + minutes / 60. + seconds / 3600.;
+            Ok(if hemisphere == negative {
+                -magnitude
+            } else {
+                magnitude
+            })
+        };
+        Ok((rem, angle))
+    }
+}
+
+fn altitude(inp: &str) -> IResult<&str, f64> {
+    let (rem, (altitude, sign)) = tuple((
+        terminated(number, pair(space0, tag("m"))),
+        opt(preceded(
+            space1,
+            alt((
+                value(1., tag("Above Sea Level")),
+                value(-1., tag("Below Sea Level")),
+            )),
+        )),
+    ))(inp)?;
+    Ok((rem, sign.unwrap_or(1.) * altitude))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exiftool(str: &str) -> Result<ISO6709Coord, ISO6709Error> {
+        parse_exiftool(str)
+    }
+
+    #[test]
+    fn should_parse_exiftool() {
+        assert_eq!(
+            exiftool(r#"15 deg 30' 0.00" S, 95 deg 15' 0.00" E"#),
+            Ok(ISO6709Coord::from_parts(-15.5, 95.25, None))
+        );
+        assert_eq!(
+            exiftool(r#" 15 deg 30' 0.00" N,95 deg 15' 0.00" W, 123.45 m Above Sea Level "#),
+            Ok(ISO6709Coord::from_parts(15.5, -95.25, Some(123.45)))
+        );
+        assert_eq!(
+            exiftool("15 deg 30' S, 95 deg W, 12m"),
+            Ok(ISO6709Coord::from_parts(-15.5, -95., Some(12.)))
+        );
+        assert!(exiftool(r#"0 deg 0' 0.00" S, 0 deg 0' 0.00" W"#)
+            .unwrap()
+            .lat
+            .is_sign_positive());
+    }
+
+    #[test]
+    fn should_err_exiftool() {
+        for str in [
+            r#"15 deg 30' 0.00" S"#,
+            r#"15 deg 30' 0.00" E, 95 deg 15' 0.00" N"#,
+            r#"15 deg 30' 0.00" S, 95 deg 15' 0.00" W trailing"#,
+            r#"15 deg 60' 0.00" S, 95 deg 15' 0.00" W"#,
+            r#"15 deg 30' 60" S, 95 deg 15' 0.00" W"#,
+            r#"-15 deg 30' 0.00" S, 95 deg 15' 0.00" W"#,
+        ] {
+            assert_eq!(
+                exiftool(str).unwrap_err().kind(),
+                ErrorKind::Syntax,
+                "{}",
+                str
+            );
+        }
+        assert_eq!(
+            exiftool(r#"95 deg 30' 0.00" S, 95 deg 15' 0.00" W"#)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::OutOfRange
+        );
+    }
+}
@@ -0,0 +1,226 @@
+use crate::ast::{parse_ast_prefix, AngleNode, Sign};
+use crate::precision::{angle_precision, decimals};
+use crate::{parse_coord, AnglePrecision, AngleUnit, ErrorKind, Format, ISO6709Error};
+use std::fmt;
+
+/// How a coordinate was read from a string, step by step, for teaching the formats and for settling disputes over
+/// what a coordinate means. `Display` writes it out in full.
+/// ```
+/// # use iso6709parse::{explain, Format};
+/// let explanation = explain("+3530.5-17006+12CRSWGS_84/").unwrap();
+/// assert_eq!(explanation.format, Format::DegreesMinutes);
+/// assert_eq!(explanation.latitude.minutes, Some(30.5));
+/// assert_eq!(
+///     explanation.to_string(),
+///     "Format: DegreesMinutes\n\
+///      Latitude: `+3530.5` = +(35 + 30.5/60) = 35.50833333333333, to 1 decimal of a minute (0.0016667°)\n\
+///      Longitude: `-17006` = -(170 + 6/60) = -170.1, to whole minutes (0.016667°)\n\
+///      Altitude: `+12` = 12 m, to whole meters\n\
+///      CRS: WGS_84"
+/// );
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[non_exhaustive]
+pub struct Explanation {
+    pub format: Format,
+    pub latitude: AngleExplanation,
+    pub longitude: AngleExplanation,
+    pub altitude: Option<AltitudeExplanation>,
+    /// The CRS as written after the altitude of the string representation
+    pub crs: Option<String>,
+}
+
+/// How a latitude or longitude was read
+#[derive(Debug, PartialEq, Clone)]
+#[non_exhaustive]
+pub struct AngleExplanation {
+    /// The text the angle was read from
+    pub written: String,
+    /// From the `+` or `-` sign, or the hemisphere letter
+    pub negative: bool,
+    pub degrees: f64,
+    pub minutes: Option<f64>,
+    pub seconds: Option<f64>,
+    /// `degrees + minutes/60 + seconds/3600` with the sign, as parsing gives it
+    pub value: f64,
+    /// The step of the last digit written, which the value cannot be more precise than
+    pub precision: AnglePrecision,
+}
+
+/// How an altitude was read
+#[derive(Debug, PartialEq, Clone)]
+#[non_exhaustive]
+pub struct AltitudeExplanation {
+    pub written: String,
+    pub value: f64,
+    /// Number of digits after the decimal point
+    pub decimals: usize,
+}
+
+/// Parses a coordinate the same as [`crate::parse`], and explains how each value was computed: the format it was
+/// matched as, its components, the arithmetic that combines them, and how precisely each value was written.
+/// Fails if [`crate::parse`] does, or with [`ErrorKind::Syntax`] for inputs that it only reads by other rules, such as
+/// aviation altitudes.
+pub fn explain(str: &str) -> Result<Explanation, ISO6709Error> {
+    read_explanation(str).map_err(|err| err.with_input(str))
+}
+
+fn read_explanation(str: &str) -> Result<Explanation, ISO6709Error> {
+    let coord = parse_coord(str)?;
+    // An aviation altitude is converted to meters, or read from a flight level
+    let ast = parse_ast_prefix(str)
+        .filter(|ast| ast.altitude.as_ref().map(|altitude| altitude.value.value) == coord.altitude);
+    let Some(ast) = ast else {
+        return Err(ISO6709Error::new(
+            ErrorKind::Syntax,
+            "the coordinate was read by rules that cannot be explained step by step",
+        ));
+    };
+    let altitude = match (&ast.altitude, coord.altitude) {
+        (Some(node), Some(value)) => Some(AltitudeExplanation {
+            written: str[node.value.span.start..node.value.span.end].to_string(),
+            value,
+            decimals: decimals(str, node.value.span),
+        }),
+        _ => None,
+    };
+    Ok(Explanation {
+        format: ast.format(),
+        latitude: angle(str, &ast.latitude, coord.lat),
+        longitude: angle(str, &ast.longitude, coord.lon),
+        altitude,
+        crs: ast
+            .altitude
+            .as_ref()
+            .and_then(|altitude| altitude.crs.as_ref())
+            .map(|crs| crs.value.trim_end().to_string()),
+    })
+}
+
+fn angle(source: &str, node: &AngleNode, value: f64) -> AngleExplanation {
+    let last = node
+        .seconds
+        .as_ref()
+        .or(node.minutes.as_ref())
+        .unwrap_or(&node.degrees);
+    let start = node.sign.span.start.min(node.degrees.span.start);
+    let end = node.sign.span.end.max(last.span.end);
+    AngleExplanation {
+        written: source[start..end].to_string(),
+        negative: node.sign.value == Sign::Negative,
+        degrees: node.degrees.value,
+        minutes: node.minutes.as_ref().map(|minutes| minutes.value),
+        seconds: node.seconds.as_ref().map(|seconds| seconds.value),
+        value,
+        precision: angle_precision(source, node),
+    }
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Format: {:?}", self.format)?;
+        write!(f, "\nLatitude: {}", self.latitude)?;
+        write!(f, "\nLongitude: {}", self.longitude)?;
+        if let Some(altitude) = &self.altitude {
+            write!(f, "\nAltitude: {}", altitude)?;
+        }
+        if let Some(crs) = &self.crs {
+            write!(f, "\nCRS: {}", crs)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for AngleExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.negative { '-' } else { '+' };
+        write!(f, "`{}` = {}", self.written, sign)?;
+        match (self.minutes, self.seconds) {
+            (None, _) => write!(f, "{}", self.degrees)?,
+            (Some(minutes), None) => write!(f, "({} + {}/60)", self.degrees, minutes)?,
+            (Some(minutes), Some(seconds)) => {
+                write!(f, "({} + {}/60 + {}/3600)", self.degrees, minutes, seconds)?
+            }
+        }
+        let unit = match self.precision.unit {
+            AngleUnit::Degrees => "degree",
+            AngleUnit::Minutes => "minute",
+            AngleUnit::Seconds => "second",
+        };
+        write!(f, " = {}, to ", self.value)?;
+        match self.precision.decimals {
+            0 => write!(f, "whole {}s", unit)?,
+            1 => write!(f, "1 decimal of a {}", unit)?,
+            decimals => write!(f, "{} decimals of a {}", decimals, unit)?,
+        }
+        write!(f, " ({}°)", significant(self.precision.resolution()))
+    }
+}
+
+impl fmt::Display for AltitudeExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` = {} m, to ", self.written, self.value)?;
+        match self.decimals {
+            0 => write!(f, "whole meters"),
+            1 => write!(f, "1 decimal of a meter"),
+            decimals => write!(f, "{} decimals of a meter", decimals),
+        }
+    }
+}
+
+/// `value` to 5 significant digits, as steps such as 1/60 do not end
+fn significant(value: f64) -> String {
+    let digits = 4 - value.log10().floor() as i32;
+    format!("{:.*}", digits.max(0) as usize, value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_explain_readable() {
+        let explanation = explain("50°40′46.461″N 95°48′26.533″W 123.45m").unwrap();
+        assert_eq!(explanation.format, Format::Readable);
+        assert_eq!(explanation.longitude.written, "95°48′26.533″W");
+        assert!(explanation.longitude.negative);
+        assert_eq!(explanation.longitude.seconds, Some(26.533));
+        assert_eq!(explanation.altitude.as_ref().unwrap().decimals, 2);
+        assert_eq!(explanation.crs, None);
+        assert_eq!(
+            explanation.latitude.to_string(),
+            "`50°40′46.461″N` = +(50 + 40/60 + 46.461/3600) = 50.6795725, \
+             to 3 decimals of a second (0.00000027778°)"
+        );
+    }
+
+    #[test]
+    fn should_explain_degrees() {
+        let explanation = explain("S05.5E000/ trailing").unwrap();
+        assert_eq!(explanation.format, Format::Degrees);
+        assert_eq!(
+            explanation.to_string(),
+            "Format: Degrees\n\
+             Latitude: `S05.5` = -5.5 = -5.5, to 1 decimal of a degree (0.10000°)\n\
+             Longitude: `E000` = +0 = 0, to whole degrees (1.0000°)"
+        );
+    }
+
+    #[test]
+    fn should_err_explain() {
+        assert_eq!(
+            explain("N35.50W1x0.10/").unwrap_err().kind(),
+            ErrorKind::Syntax
+        );
+        for str in [
+            "50°40′46″N 95°48′27″W FL350",
+            "50°40′46″N 95°48′27″W 1200 ft MSL",
+        ] {
+            let err = explain(str).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::Syntax);
+            assert_eq!(err.input(), Some(str));
+        }
+        let explanation = explain("50°40′46″S 95°48′27″E -12.5m").unwrap();
+        assert_eq!(explanation.altitude.unwrap().value, -12.5);
+    }
+}
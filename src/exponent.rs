@@ -0,0 +1,213 @@
+use crate::ast::{parse_ast_prefix_with, CoordinateAst, Span};
+use crate::parsers::common::{digits0, digits1};
+use crate::{ErrorKind, ISO6709Error, Leniency, Symbols};
+use nom::bytes::complete::tag;
+use nom::character::complete::one_of;
+use nom::combinator::{opt, recognize};
+use nom::sequence::{pair, tuple};
+use nom::IResult;
+use std::borrow::Cow;
+
+/// A number with an exponent, such as `1.5e2`. Only a lowercase `e` is an exponent, since `E` is the east
+/// hemisphere letter in the string representation, as in `N35.50E170.10`.
+fn exponent_number(inp: &str) -> IResult<&str, &str> {
+    recognize(tuple((
+        digits1,
+        opt(pair(tag("."), digits0)),
+        tag("e"),
+        opt(one_of("+-")),
+        digits1,
+    )))(inp)
+}
+
+/// Every number in `str` as `(start, exponent)`, where `exponent` is the text of the number if it has an exponent
+fn numbers(str: &str) -> impl Iterator<Item = (usize, Option<&str>)> {
+    let mut at = 0;
+    std::iter::from_fn(move || {
+        let start = at + str[at..].find(|ch: char| ch.is_ascii_digit())?;
+        let exponent = exponent_number(&str[start..])
+            .ok()
+            .map(|(_, number)| number);
+        let len = match exponent {
+            Some(number) => number.len(),
+            None => str[start..]
+                .find(|ch: char| !ch.is_ascii_digit() && ch != '.')
+                .unwrap_or(str.len() - start),
+        };
+        at = start + len;
+        Some((start, exponent))
+    })
+}
+
+/// The first number with an exponent that is read as a latitude, longitude or altitude, rather than being in the
+/// CRS or in text after the coordinate. When the coordinate cannot be read, which an exponent in it causes, that is
+/// the first number with an exponent anywhere.
+fn exponent_field<'a>(str: &'a str, symbols: &Symbols, leniency: Leniency) -> Option<&'a str> {
+    let first = numbers(str).find_map(|(_, exponent)| exponent)?;
+    let Some(ast) = parse_ast_prefix_with(str, symbols, leniency) else {
+        return Some(first);
+    };
+    // An exponent can also end the coordinate early, as in the altitude of `+35.50-170.10+1.2e3CRSWGS_84/`, so the
+    // numbers are written out in full to find the fields they would be read as
+    let (expanded, spans) = expand(str);
+    let expanded_fields = parse_ast_prefix_with(&expanded, symbols, leniency)
+        .map(|ast| fields(&ast))
+        .unwrap_or_default();
+    let fields = fields(&ast);
+    let overlaps = |fields: &[Span], span: Span| {
+        fields
+            .iter()
+            .any(|field| field.start < span.end && span.start < field.end)
+    };
+    numbers(str)
+        .filter_map(|(start, exponent)| exponent.map(|number| (start, number)))
+        .zip(spans)
+        .find_map(|((start, number), expanded)| {
+            let span = Span {
+                start,
+                end: start + number.len(),
+            };
+            (overlaps(&fields, span) || overlaps(&expanded_fields, expanded)).then_some(number)
+        })
+}
+
+/// The spans of the numbers of the latitude, longitude and altitude
+fn fields(ast: &CoordinateAst) -> Vec<Span> {
+    [&ast.latitude, &ast.longitude]
+        .into_iter()
+        .flat_map(|angle| {
+            [
+                Some(&angle.degrees),
+                angle.minutes.as_ref(),
+                angle.seconds.as_ref(),
+            ]
+        })
+        .chain([ast.altitude.as_ref().map(|altitude| &altitude.value)])
+        .flatten()
+        .map(|field| field.span)
+        .collect()
+}
+
+/// Whether a latitude, longitude or altitude in `str` has an exponent
+pub(crate) fn has_exponent(str: &str) -> bool {
+    exponent_field(str, Symbols::standard(), Leniency::Standard).is_some()
+}
+
+/// Fails with [`ErrorKind::ScientificNotation`] if a latitude, longitude or altitude in `str` has an exponent.
+/// Numbers in text after the coordinate are not looked at.
+pub(crate) fn reject_exponents(str: &str) -> Result<(), ISO6709Error> {
+    reject_exponents_with(str, Symbols::standard(), Leniency::Standard)
+}
+
+/// Same as [`reject_exponents`], with the coordinate read with `symbols` and `leniency`
+pub(crate) fn reject_exponents_with(
+    str: &str,
+    symbols: &Symbols,
+    leniency: Leniency,
+) -> Result<(), ISO6709Error> {
+    match exponent_field(str, symbols, leniency) {
+        Some(number) => Err(ISO6709Error::new(
+            ErrorKind::ScientificNotation,
+            format!("scientific notation `{}` is not allowed", number),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Writes numbers with an exponent out in full. In the string representation they are taken to be decimal degrees,
+/// and are padded with leading zeros to the width of the latitude or longitude they are in.
+pub(crate) fn expand_exponents(str: &str) -> Cow<'_, str> {
+    if !numbers(str).any(|(_, exponent)| exponent.is_some()) {
+        return Cow::Borrowed(str);
+    }
+    Cow::Owned(expand(str).0)
+}
+
+/// `str` with its numbers written out in full, along with where each of them is in the result
+fn expand(str: &str) -> (String, Vec<Span>) {
+    let readable = str.contains('°');
+    let mut out = String::with_capacity(str.len());
+    let mut spans = Vec::new();
+    let mut copied = 0;
+    for (index, (at, exponent)) in numbers(str).enumerate() {
+        let Some(number) = exponent else {
+            continue;
+        };
+        // The pattern only matches valid floats
+        let value = number.parse::<f64>().unwrap_or_default();
+        let width = match index {
+            0 if !readable => 2usize,
+            1 if !readable => 3,
+            _ => 0,
+        };
+        let expanded = value.to_string();
+        let integer = expanded.split('.').next().unwrap_or_default().len();
+        out.push_str(&str[copied..at]);
+        let start = out.len();
+        out.push_str(&"0".repeat(width.saturating_sub(integer)));
+        out.push_str(&expanded);
+        spans.push(Span {
+            start,
+            end: out.len(),
+        });
+        copied = at + number.len();
+    }
+    out.push_str(&str[copied..]);
+    (out, spans)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_reject_exponents() {
+        assert!(reject_exponents("N35.50E170.10+12CRSEPSG:4979/").is_ok());
+        assert!(reject_exponents("15°30′00″N 95°15′00″W 12m").is_ok());
+
+        let err = reject_exponents("+3.55e1-170.10/").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ScientificNotation);
+        assert_eq!(
+            err.to_string(),
+            "Failed to parse ISO6709 coordinate: scientific notation `3.55e1` is not allowed"
+        );
+        assert!(reject_exponents("15°30′00″N 95°15′00″W 1e-2m").is_err());
+
+        assert!(has_exponent("+3.55e1-170.10/"));
+        assert!(!has_exponent("N35.50E170.10+12CRSEPSG:4979/"));
+        assert!(has_exponent("+35.50-1.701e2/"));
+        assert!(has_exponent("N35.50W170.10+1.2e3CRSWGS_84/"));
+    }
+
+    #[test]
+    fn should_ignore_exponents_after_coordinate() {
+        for str in [
+            "+35.50-170.10/ adjusted 2e5",
+            "+35.50-170.10+12CRSWGS_84/ 1e2",
+            "+35.50-170.10+12CRSEPSG:2e5/",
+            "15°30′00″N 95°15′00″W 12m, datum note 2e5",
+        ] {
+            assert!(reject_exponents(str).is_ok(), "{}", str);
+            assert!(!has_exponent(str), "{}", str);
+        }
+        // With no altitude, a number after the longitude is read as one
+        assert!(reject_exponents("15°30′00″N 95°15′00″W 2e5").is_err());
+    }
+
+    #[test]
+    fn should_expand_exponents() {
+        assert!(matches!(
+            expand_exponents("+35.50-170.10/"),
+            Cow::Borrowed(_)
+        ));
+        assert_eq!(expand_exponents("+3.55e1-1.701e2/"), "+35.5-170.1/");
+        assert_eq!(
+            expand_exponents("+5e0+1e1+1.2e3CRSWGS_84/"),
+            "+05+010+1200CRSWGS_84/"
+        );
+        assert_eq!(
+            expand_exponents("15°30′00″N 95°15′00″W 1.2e-1m"),
+            "15°30′00″N 95°15′00″W 0.12m"
+        );
+    }
+}
@@ -0,0 +1,26 @@
+use crate::{parse, FromIso6709, ISO6709Error};
+
+/// Adds [`ParseIso6709::parse_iso6709`] to `str`, to parse coordinates the way `str::parse` parses numbers
+/// ```
+/// use iso6709parse::{ISO6709Coord, ParseIso6709};
+///
+/// let coord = "N35.50W170.10/".parse_iso6709::<ISO6709Coord>().unwrap();
+/// assert_eq!(coord.lat, 35.5);
+/// let coord: ISO6709Coord = String::from("15°30′00″N 95°15′00″W").parse_iso6709().unwrap();
+/// assert_eq!(coord.lon, -95.25);
+/// ```
+pub trait ParseIso6709 {
+    /// Same as [`crate::parse`]
+    fn parse_iso6709<T>(&self) -> Result<T, ISO6709Error>
+    where
+        T: FromIso6709;
+}
+
+impl ParseIso6709 for str {
+    fn parse_iso6709<T>(&self) -> Result<T, ISO6709Error>
+    where
+        T: FromIso6709,
+    {
+        parse(self)
+    }
+}
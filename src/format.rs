@@ -0,0 +1,793 @@
+use crate::parsers::common::MAX_DIGITS;
+use crate::{Crs, ErrorKind, ISO6709Coord, ISO6709Error};
+use std::fmt::{self, Write as _};
+use std::io;
+
+/// Layout of the latitude and longitude written by [`ISO6709Coord::format`]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[non_exhaustive]
+pub enum Format {
+    /// `±DD.DD±DDD.DD/`
+    Degrees,
+    /// `±DDMM.MM±DDDMM.MM/`
+    DegreesMinutes,
+    /// `±DDMMSS.SS±DDDMMSS.SS/`
+    DegreesMinutesSeconds,
+    /// `DD°MM′SS.SS″N DDD°MM′SS.SS″W`
+    Readable,
+    /// `D°M′S.SS″N D°M′S.SS″W`, the compact style of Wikipedia and GeoHack without leading zeros or altitude
+    Wikipedia,
+    /// `{{coord|±D.DD|±D.DD}}`, the Wikipedia coord template in decimal degrees, without altitude
+    WikipediaTemplate,
+}
+
+/// Settings for [`ISO6709Coord::format`]
+#[derive(Debug, PartialEq, Clone)]
+pub struct FormatSpec {
+    pub format: Format,
+    /// Digits after the decimal point of the last component written, and of the altitude, at most 12
+    pub decimals: usize,
+    /// CRS written after the altitude in the string representation, which ISO6709 requires whenever there is an altitude
+    pub crs: Crs,
+    /// Writes a comma as the decimal separator, as in `+35,50-170,10/`, for locales and standards that require it.
+    /// [`crate::ParserOptions::decimal_comma`] reads it back. Not used for [`Format::WikipediaTemplate`], since
+    /// MediaWiki only reads `.`
+    pub decimal_comma: bool,
+}
+
+impl Default for FormatSpec {
+    fn default() -> Self {
+        FormatSpec {
+            format: Format::Degrees,
+            decimals: 5,
+            crs: Crs::Wgs84,
+            decimal_comma: false,
+        }
+    }
+}
+
+pub(crate) const MAX_DECIMALS: usize = 12;
+
+impl ISO6709Coord {
+    /// Writes the coordinate in either ISO6709 format.
+    /// Fails if a value is NaN or infinite, the latitude or longitude is out of range, or the altitude would be written
+    /// with more digits than [`crate::parse`] reads back.
+    /// ```
+    /// # use iso6709parse::{Format, FormatSpec, ISO6709Coord};
+    /// let coord = ISO6709Coord::new(15.5, -95.25, Some(123.45)).unwrap();
+    /// let spec = FormatSpec { format: Format::DegreesMinutes, decimals: 1, ..Default::default() };
+    /// assert_eq!(coord.format(&spec).unwrap(), "+1530.0-09515.0+123.5CRSWGS_84/");
+    ///
+    /// let spec = FormatSpec { format: Format::Readable, decimals: 0, ..Default::default() };
+    /// assert_eq!(coord.format(&spec).unwrap(), "15°30′00″N 095°15′00″W 123m");
+    /// ```
+    pub fn format(&self, spec: &FormatSpec) -> Result<String, ISO6709Error> {
+        let mut out = String::new();
+        format_into(&mut out, self, spec)?;
+        Ok(out)
+    }
+}
+
+/// Writes `coord` into `out` the same as [`ISO6709Coord::format`], so that serializers writing many coordinates can
+/// reuse one buffer rather than allocate a `String` for each. Nothing is written if the coordinate is invalid.
+/// Fails with [`ErrorKind::Write`] if `out` does.
+/// ```
+/// # use iso6709parse::{format_into, FormatSpec, ISO6709Coord};
+/// let coords = [ISO6709Coord::new(35.5, -170.1, None).unwrap(), ISO6709Coord::new(-5.05, 5.05, None).unwrap()];
+/// let spec = FormatSpec { decimals: 2, ..Default::default() };
+/// let mut out = String::new();
+/// for coord in &coords {
+///     format_into(&mut out, coord, &spec).unwrap();
+///     out.push('\n');
+/// }
+/// assert_eq!(out, "+35.50-170.10/\n-05.05+005.05/\n");
+/// ```
+pub fn format_into<W: fmt::Write + ?Sized>(
+    out: &mut W,
+    coord: &ISO6709Coord,
+    spec: &FormatSpec,
+) -> Result<(), ISO6709Error> {
+    validate(coord.lat, coord.lon, coord.altitude)?;
+    validate_altitude_digits(coord.altitude, spec)?;
+    write_coord(&mut Adapter(out), coord, spec).map_err(|_| write_error())
+}
+
+/// Same as [`format_into`], for byte streams such as files and sockets. Fails with [`ErrorKind::Write`] and the
+/// message of the `io::Error` if `out` does.
+/// ```
+/// # use iso6709parse::{write_into, FormatSpec, ISO6709Coord};
+/// let mut out: Vec<u8> = Vec::new();
+/// write_into(&mut out, &ISO6709Coord::new(35.5, -170.1, None).unwrap(), &FormatSpec::default()).unwrap();
+/// assert_eq!(out, b"+35.50000-170.10000/");
+/// ```
+pub fn write_into<W: io::Write + ?Sized>(
+    out: &mut W,
+    coord: &ISO6709Coord,
+    spec: &FormatSpec,
+) -> Result<(), ISO6709Error> {
+    validate(coord.lat, coord.lon, coord.altitude)?;
+    validate_altitude_digits(coord.altitude, spec)?;
+    let mut writer = IoAdapter {
+        inner: out,
+        error: None,
+    };
+    write_coord(&mut writer, coord, spec).map_err(|_| match writer.error {
+        Some(err) => ISO6709Error::new(ErrorKind::Write, err.to_string()),
+        None => write_error(),
+    })
+}
+
+/// Writes a track or other list of points as one solidus separated list, such as `+12+034/+13+035/`, with each
+/// point written by `spec`. Points in the readable and Wikipedia formats are followed by a `/` as well.
+/// Fails on the first point that [`ISO6709Coord::format`] would fail on.
+/// ```
+/// # use iso6709parse::{format_sequence, FormatSpec, ISO6709Coord};
+/// let track = [ISO6709Coord::new(12., 34., None).unwrap(), ISO6709Coord::new(13., 35., None).unwrap()];
+/// let spec = FormatSpec { decimals: 0, ..Default::default() };
+/// assert_eq!(format_sequence(&track, &spec).unwrap(), "+12+034/+13+035/");
+/// ```
+pub fn format_sequence<'a>(
+    coords: impl IntoIterator<Item = &'a ISO6709Coord>,
+    spec: &FormatSpec,
+) -> Result<String, ISO6709Error> {
+    let mut out = String::new();
+    format_sequence_into(&mut out, coords, spec)?;
+    Ok(out)
+}
+
+/// Writes the list of [`format_sequence`] into `out`. The points before one that fails have already been written.
+pub fn format_sequence_into<'a, W: fmt::Write + ?Sized>(
+    out: &mut W,
+    coords: impl IntoIterator<Item = &'a ISO6709Coord>,
+    spec: &FormatSpec,
+) -> Result<(), ISO6709Error> {
+    let solidus = matches!(
+        spec.format,
+        Format::Readable | Format::Wikipedia | Format::WikipediaTemplate
+    );
+    for coord in coords {
+        format_into(out, coord, spec)?;
+        if solidus {
+            out.write_char('/').map_err(|_| write_error())?;
+        }
+    }
+    Ok(())
+}
+
+/// Rounded before it is written, so that an altitude that rounds to zero is written without a sign. An altitude too
+/// large to scale is already a whole number and is left as is.
+fn rounded_altitude(altitude: f64, decimals: usize) -> f64 {
+    let scale = 10f64.powi(decimals as i32);
+    let scaled = altitude * scale;
+    if scaled.is_finite() {
+        scaled.round() / scale + 0.
+    } else {
+        altitude
+    }
+}
+
+/// Fails if the altitude is written with more digits than the parsers read back, see [`crate::parse`]
+fn validate_altitude_digits(altitude: Option<f64>, spec: &FormatSpec) -> Result<(), ISO6709Error> {
+    let Some(altitude) = altitude else {
+        return Ok(());
+    };
+    if matches!(spec.format, Format::Wikipedia | Format::WikipediaTemplate) {
+        return Ok(());
+    }
+    let decimals = spec.decimals.min(MAX_DECIMALS);
+    let mut width = Width(0);
+    let _ = write!(
+        width,
+        "{:.*}",
+        decimals,
+        rounded_altitude(altitude, decimals).abs()
+    );
+    if width.0 > MAX_DIGITS {
+        return Err(ISO6709Error::new(
+            ErrorKind::OutOfRange,
+            format!(
+                "altitude {} needs more than {} digits at {} decimals",
+                altitude, MAX_DIGITS, decimals
+            ),
+        ));
+    }
+    Ok(())
+}
+
+fn write_error() -> ISO6709Error {
+    ISO6709Error::new(
+        ErrorKind::Write,
+        "the formatter failed to write the coordinate",
+    )
+}
+
+/// Counts the bytes written to it
+struct Width(usize);
+
+impl fmt::Write for Width {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.len();
+        Ok(())
+    }
+}
+
+/// Passes a possibly unsized writer on as a `dyn fmt::Write`
+struct Adapter<'a, W: ?Sized>(&'a mut W);
+
+impl<W: fmt::Write + ?Sized> fmt::Write for Adapter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0.write_str(s)
+    }
+}
+
+/// Writes to an `io::Write`, keeping the `io::Error` that `fmt::Error` cannot carry
+struct IoAdapter<'a, W: ?Sized> {
+    inner: &'a mut W,
+    error: Option<io::Error>,
+}
+
+impl<W: io::Write + ?Sized> fmt::Write for IoAdapter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|err| {
+            self.error = Some(err);
+            fmt::Error
+        })
+    }
+}
+
+/// Writes a number with `separator` in place of the decimal point
+struct Separated<'a> {
+    out: &'a mut dyn fmt::Write,
+    separator: char,
+}
+
+impl fmt::Write for Separated<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        match s.find('.') {
+            Some(point) if self.separator != '.' => {
+                self.out.write_str(&s[..point])?;
+                self.out.write_char(self.separator)?;
+                self.out.write_str(&s[point + 1..])
+            }
+            _ => self.out.write_str(s),
+        }
+    }
+}
+
+/// Not generic over the writer, so that the formatting is compiled once
+fn write_coord(out: &mut dyn fmt::Write, coord: &ISO6709Coord, spec: &FormatSpec) -> fmt::Result {
+    let decimals = spec.decimals.min(MAX_DECIMALS);
+    let separator = if spec.decimal_comma && spec.format != Format::WikipediaTemplate {
+        ','
+    } else {
+        '.'
+    };
+    let lat = Components::new(coord.lat, spec.format, decimals, separator);
+    let lon = Components::new(coord.lon, spec.format, decimals, separator);
+    let altitude = coord
+        .altitude
+        .map(|altitude| rounded_altitude(altitude, decimals));
+    let write_altitude = |out: &mut dyn fmt::Write, altitude: f64| {
+        write!(Separated { out, separator }, "{:.*}", decimals, altitude)
+    };
+
+    match spec.format {
+        Format::Readable => {
+            lat.write_readable(out, 2, ('N', 'S'))?;
+            out.write_char(' ')?;
+            lon.write_readable(out, 3, ('E', 'W'))?;
+            if let Some(altitude) = altitude {
+                out.write_char(' ')?;
+                write_altitude(out, altitude)?;
+                out.write_char('m')?;
+            }
+        }
+        Format::Wikipedia => {
+            lat.write_wikipedia(out, ('N', 'S'))?;
+            out.write_char(' ')?;
+            lon.write_wikipedia(out, ('E', 'W'))?;
+        }
+        Format::WikipediaTemplate => {
+            out.write_str("{{coord|")?;
+            lat.write_decimal(out)?;
+            out.write_char('|')?;
+            lon.write_decimal(out)?;
+            out.write_str("}}")?;
+        }
+        _ => {
+            lat.write_string(out, 2)?;
+            lon.write_string(out, 3)?;
+            if let Some(altitude) = altitude {
+                out.write_char(if altitude < 0. { '-' } else { '+' })?;
+                write_altitude(out, altitude.abs())?;
+                write!(out, "CRS{}", spec.crs)?;
+            }
+            out.write_char('/')?;
+        }
+    }
+    Ok(())
+}
+
+impl ISO6709Coord {
+    /// Creates a coordinate, checking that every value is finite and the latitude and longitude are within
+    /// ±90° and ±180°.
+    /// ```
+    /// # use iso6709parse::{ErrorKind, ISO6709Coord};
+    /// assert!(ISO6709Coord::new(15.5, -95.25, None).is_ok());
+    /// assert_eq!(ISO6709Coord::new(f64::NAN, -95.25, None).unwrap_err().kind(), ErrorKind::NonFinite);
+    /// assert_eq!(ISO6709Coord::new(15.5, -195.25, None).unwrap_err().kind(), ErrorKind::OutOfRange);
+    /// ```
+    pub fn new(lat: f64, lon: f64, altitude: Option<f64>) -> Result<ISO6709Coord, ISO6709Error> {
+        validate(lat, lon, altitude)?;
+        Ok(ISO6709Coord { lat, lon, altitude })
+    }
+}
+
+impl ISO6709Coord {
+    /// The string representation in decimal degrees with 12 decimals, which reads back as the same coordinate to well
+    /// below a micrometer. This is how the database integrations store coordinates in text columns.
+    /// Fails as [`ISO6709Coord::format`] does, so an altitude of 1e27 meters or more is refused rather than stored as
+    /// a string that does not read back.
+    #[cfg(any(feature = "diesel", feature = "sea-orm"))]
+    pub fn storage_string(&self) -> Result<String, ISO6709Error> {
+        self.format(&FormatSpec {
+            format: Format::Degrees,
+            decimals: MAX_DECIMALS,
+            ..Default::default()
+        })
+    }
+}
+
+pub(crate) fn validate(lat: f64, lon: f64, altitude: Option<f64>) -> Result<(), ISO6709Error> {
+    for (name, value) in [
+        ("latitude", Some(lat)),
+        ("longitude", Some(lon)),
+        ("altitude", altitude),
+    ] {
+        if let Some(value) = value.filter(|value| !value.is_finite()) {
+            return Err(ISO6709Error::new(
+                ErrorKind::NonFinite,
+                format!("{} is {}", name, value),
+            ));
+        }
+    }
+    if lat.abs() > 90. {
+        return Err(ISO6709Error::new(
+            ErrorKind::OutOfRange,
+            format!("latitude {} is beyond ±90°", lat),
+        ));
+    }
+    if lon.abs() > 180. {
+        return Err(ISO6709Error::new(
+            ErrorKind::OutOfRange,
+            format!("longitude {} is beyond ±180°", lon),
+        ));
+    }
+    Ok(())
+}
+
+/// An angle split into whole degrees, minutes and seconds as needed, with the fraction of the last component kept
+/// as an integer number of `10^-decimals`, so rounding never produces `60` minutes or seconds.
+struct Components {
+    negative: bool,
+    format: Format,
+    decimals: usize,
+    separator: char,
+    degrees: u64,
+    minutes: u64,
+    seconds: u64,
+    fraction: u64,
+}
+
+impl Components {
+    fn new(value: f64, format: Format, decimals: usize, separator: char) -> Components {
+        let scale = 10u64.pow(decimals as u32);
+        let per_degree = match format {
+            Format::Degrees | Format::WikipediaTemplate => 1,
+            Format::DegreesMinutes => 60,
+            Format::DegreesMinutesSeconds | Format::Readable | Format::Wikipedia => 3600,
+        };
+        let units = (value.abs() * (per_degree * scale) as f64).round() as u64;
+        let (whole, fraction) = (units / scale, units % scale);
+        let (degrees, minutes, seconds) = match per_degree {
+            1 => (whole, 0, 0),
+            60 => (whole / 60, whole % 60, 0),
+            _ => (whole / 3600, whole / 60 % 60, whole % 60),
+        };
+        Components {
+            // Values that round to zero are written as positive, as is `-0.0`
+            negative: value < 0. && units > 0,
+            format,
+            decimals,
+            separator,
+            degrees,
+            minutes,
+            seconds,
+            fraction,
+        }
+    }
+
+    fn write_fraction(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        if self.decimals == 0 {
+            Ok(())
+        } else {
+            write!(
+                out,
+                "{}{:0width$}",
+                self.separator,
+                self.fraction,
+                width = self.decimals
+            )
+        }
+    }
+
+    fn write_string(&self, out: &mut dyn fmt::Write, degree_width: usize) -> fmt::Result {
+        out.write_char(if self.negative { '-' } else { '+' })?;
+        write!(out, "{:0width$}", self.degrees, width = degree_width)?;
+        match self.format {
+            Format::Degrees => {}
+            Format::DegreesMinutes => write!(out, "{:02}", self.minutes)?,
+            _ => write!(out, "{:02}{:02}", self.minutes, self.seconds)?,
+        }
+        self.write_fraction(out)
+    }
+
+    fn hemisphere(&self, hemispheres: (char, char)) -> char {
+        if self.negative {
+            hemispheres.1
+        } else {
+            hemispheres.0
+        }
+    }
+
+    fn write_wikipedia(&self, out: &mut dyn fmt::Write, hemispheres: (char, char)) -> fmt::Result {
+        write!(out, "{}°{}′{}", self.degrees, self.minutes, self.seconds)?;
+        self.write_fraction(out)?;
+        write!(out, "″{}", self.hemisphere(hemispheres))
+    }
+
+    /// Degrees with only a `-` sign
+    fn write_decimal(&self, out: &mut dyn fmt::Write) -> fmt::Result {
+        if self.negative {
+            out.write_char('-')?;
+        }
+        write!(out, "{}", self.degrees)?;
+        self.write_fraction(out)
+    }
+
+    fn write_readable(
+        &self,
+        out: &mut dyn fmt::Write,
+        degree_width: usize,
+        hemispheres: (char, char),
+    ) -> fmt::Result {
+        write!(
+            out,
+            "{:0width$}°{:02}′{:02}",
+            self.degrees,
+            self.minutes,
+            self.seconds,
+            width = degree_width
+        )?;
+        self.write_fraction(out)?;
+        write!(out, "″{}", self.hemisphere(hemispheres))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    fn spec(format: Format, decimals: usize) -> FormatSpec {
+        FormatSpec {
+            format,
+            decimals,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn should_format_string_representation() {
+        let coord = ISO6709Coord::new(35.5, -170.1, None).unwrap();
+        assert_eq!(
+            coord.format(&spec(Format::Degrees, 2)).unwrap(),
+            "+35.50-170.10/"
+        );
+        assert_eq!(coord.format(&spec(Format::Degrees, 0)).unwrap(), "+36-170/");
+        assert_eq!(
+            coord.format(&spec(Format::DegreesMinutes, 2)).unwrap(),
+            "+3530.00-17006.00/"
+        );
+        assert_eq!(
+            coord
+                .format(&spec(Format::DegreesMinutesSeconds, 0))
+                .unwrap(),
+            "+353000-1700600/"
+        );
+
+        let coord = ISO6709Coord::new(5.05, 5.05, Some(-12.5)).unwrap();
+        let spec = FormatSpec {
+            crs: Crs::Epsg(4979),
+            ..spec(Format::Degrees, 2)
+        };
+        assert_eq!(
+            coord.format(&spec).unwrap(),
+            "+05.05+005.05-12.50CRSEPSG:4979/"
+        );
+    }
+
+    #[test]
+    fn should_format_wikipedia() {
+        let coord = ISO6709Coord::new(50.679573, -95.807370, Some(123.45)).unwrap();
+        assert_eq!(
+            coord.format(&spec(Format::Wikipedia, 0)).unwrap(),
+            "50°40′46″N 95°48′27″W"
+        );
+        assert_eq!(
+            coord.format(&spec(Format::WikipediaTemplate, 4)).unwrap(),
+            "{{coord|50.6796|-95.8074}}"
+        );
+
+        let coord = ISO6709Coord::new(-5.0875, 0.1275, None).unwrap();
+        assert_eq!(
+            coord.format(&spec(Format::Wikipedia, 1)).unwrap(),
+            "5°5′15.0″S 0°7′39.0″E"
+        );
+        assert_eq!(
+            coord.format(&spec(Format::WikipediaTemplate, 2)).unwrap(),
+            "{{coord|-5.09|0.13}}"
+        );
+    }
+
+    #[test]
+    fn should_carry_rounding() {
+        // 59.9999 minutes rounds up into the next degree rather than writing 60 minutes
+        let coord = ISO6709Coord::new(10.999999, -10.999999, None).unwrap();
+        assert_eq!(
+            coord
+                .format(&spec(Format::DegreesMinutesSeconds, 2))
+                .unwrap(),
+            "+110000.00-0110000.00/"
+        );
+        assert_eq!(
+            coord.format(&spec(Format::Readable, 1)).unwrap(),
+            "11°00′00.0″N 011°00′00.0″W"
+        );
+    }
+
+    #[test]
+    fn should_write_zero_without_sign() {
+        let coord = ISO6709Coord::new(-0., -0.000001, Some(-0.)).unwrap();
+        assert_eq!(
+            coord.format(&spec(Format::Degrees, 2)).unwrap(),
+            "+00.00+000.00+0.00CRSWGS_84/"
+        );
+        assert_eq!(
+            coord.format(&spec(Format::Readable, 0)).unwrap(),
+            "00°00′00″N 000°00′00″E 0m"
+        );
+    }
+
+    #[test]
+    fn should_write_decimal_comma() {
+        let coord = ISO6709Coord::new(35.5, -170.1, Some(8712.5)).unwrap();
+        let comma = |format, decimals| FormatSpec {
+            decimal_comma: true,
+            ..spec(format, decimals)
+        };
+        assert_eq!(
+            coord.format(&comma(Format::Degrees, 2)).unwrap(),
+            "+35,50-170,10+8712,50CRSWGS_84/"
+        );
+        assert_eq!(
+            coord.format(&comma(Format::Readable, 1)).unwrap(),
+            "35°30′00,0″N 170°06′00,0″W 8712,5m"
+        );
+        assert_eq!(
+            coord.format(&comma(Format::WikipediaTemplate, 2)).unwrap(),
+            "{{coord|35.50|-170.10}}"
+        );
+
+        let options = crate::ParserOptions {
+            decimal_comma: true,
+            ..Default::default()
+        };
+        for format in [Format::Degrees, Format::DegreesMinutes, Format::Readable] {
+            let formatted = coord.format(&comma(format, 3)).unwrap();
+            assert_eq!(
+                crate::parse_position(&formatted, &options).unwrap().coord,
+                coord,
+                "{}",
+                formatted
+            );
+        }
+    }
+
+    #[test]
+    fn should_format_into_writer() {
+        let coords = [
+            ISO6709Coord::new(35.5, -170.1, Some(8712.5)).unwrap(),
+            ISO6709Coord::new(-5.0875, 0.1275, None).unwrap(),
+        ];
+        let comma = FormatSpec {
+            decimal_comma: true,
+            ..spec(Format::Readable, 1)
+        };
+        for spec in [spec(Format::DegreesMinutes, 2), comma] {
+            let mut out = String::from("[");
+            let mut bytes = Vec::new();
+            for coord in &coords {
+                format_into(&mut out, coord, &spec).unwrap();
+                write_into(&mut bytes, coord, &spec).unwrap();
+            }
+            let expected: String = coords.iter().map(|c| c.format(&spec).unwrap()).collect();
+            assert_eq!(out, format!("[{}", expected));
+            assert_eq!(String::from_utf8(bytes).unwrap(), expected);
+        }
+
+        let nan = ISO6709Coord {
+            lat: f64::NAN,
+            lon: 0.,
+            altitude: None,
+        };
+        let mut out = String::new();
+        assert_eq!(
+            format_into(&mut out, &nan, &FormatSpec::default())
+                .unwrap_err()
+                .kind(),
+            ErrorKind::NonFinite
+        );
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn should_format_sequence() {
+        let track = [
+            ISO6709Coord::new(12., 34., None).unwrap(),
+            ISO6709Coord::new(-13.5, 35., Some(10.)).unwrap(),
+        ];
+        assert_eq!(
+            format_sequence(&track, &spec(Format::DegreesMinutes, 0)).unwrap(),
+            "+1200+03400/-1330+03500+10CRSWGS_84/"
+        );
+        assert_eq!(
+            format_sequence(&track, &spec(Format::Wikipedia, 0)).unwrap(),
+            "12°0′0″N 34°0′0″E/13°30′0″S 35°0′0″E/"
+        );
+        assert_eq!(format_sequence([], &FormatSpec::default()).unwrap(), "");
+
+        let mut out = String::new();
+        let invalid = [
+            track[0],
+            ISO6709Coord {
+                lat: 95.,
+                lon: 0.,
+                altitude: None,
+            },
+        ];
+        let err = format_sequence_into(&mut out, &invalid, &spec(Format::Degrees, 0)).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::OutOfRange);
+        assert_eq!(out, "+12+034/");
+    }
+
+    #[test]
+    fn should_err_on_failed_writer() {
+        struct Full;
+        impl fmt::Write for Full {
+            fn write_str(&mut self, _: &str) -> fmt::Result {
+                Err(fmt::Error)
+            }
+        }
+        let coord = ISO6709Coord::new(35.5, -170.1, None).unwrap();
+        let err = format_into(&mut Full, &coord, &FormatSpec::default()).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Write);
+
+        let mut buffer = [0u8; 4];
+        let err = write_into(&mut &mut buffer[..], &coord, &FormatSpec::default()).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Write);
+        assert!(err.to_string().contains("whole buffer"), "{}", err);
+    }
+
+    #[test]
+    fn should_round_trip() {
+        let strings = [
+            "+35.50000-170.10000/",
+            "-4520.123+14520.000+12.500CRSWGS_84/",
+            "-452035.1528+1452018.0000/",
+            "50°40′46.461″N 095°48′26.533″W 123.450m",
+        ];
+        let formats = [
+            spec(Format::Degrees, 5),
+            spec(Format::DegreesMinutes, 3),
+            spec(Format::DegreesMinutesSeconds, 4),
+            spec(Format::Readable, 3),
+        ];
+        for (str, spec) in strings.iter().zip(formats.iter()) {
+            let coord: ISO6709Coord = parse(str).unwrap();
+            assert_eq!(&coord.format(spec).unwrap(), str);
+        }
+    }
+
+    #[test]
+    fn should_reject_non_finite() {
+        let nan = ISO6709Coord {
+            lat: f64::NAN,
+            lon: 0.,
+            altitude: None,
+        };
+        let err = nan.format(&FormatSpec::default()).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NonFinite);
+        assert!(err.to_string().contains("latitude is NaN"), "{}", err);
+
+        let infinite_altitude = ISO6709Coord {
+            lat: 0.,
+            lon: 0.,
+            altitude: Some(f64::NEG_INFINITY),
+        };
+        let err = infinite_altitude
+            .format(&spec(Format::Readable, 2))
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NonFinite);
+        assert!(err.to_string().contains("altitude is -inf"), "{}", err);
+
+        assert_eq!(
+            ISO6709Coord::new(0., f64::INFINITY, None)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::NonFinite
+        );
+        assert_eq!(
+            ISO6709Coord::new(0., 0., Some(f64::NAN))
+                .unwrap_err()
+                .kind(),
+            ErrorKind::NonFinite
+        );
+    }
+
+    #[test]
+    fn should_round_trip_largest_altitudes() {
+        for (altitude, decimals) in [(9.99e26, 12), (-9.99e26, 12), (9.9e38, 0), (1e30, 5)] {
+            let coord = ISO6709Coord::new(35.5, -170.1, Some(altitude)).unwrap();
+            for format in [Format::Degrees, Format::Readable] {
+                let str = coord.format(&spec(format, decimals)).unwrap();
+                let read: ISO6709Coord = parse(&str).unwrap();
+                assert_eq!(read.altitude, Some(altitude), "{}", str);
+            }
+        }
+        for (altitude, decimals) in [(1e28, 12), (-1e300, 12), (f64::MAX, 0), (1e40, 0)] {
+            let coord = ISO6709Coord::new(35.5, -170.1, Some(altitude)).unwrap();
+            for format in [Format::Degrees, Format::Readable] {
+                assert_eq!(
+                    coord.format(&spec(format, decimals)).unwrap_err().kind(),
+                    ErrorKind::OutOfRange,
+                    "{}",
+                    altitude
+                );
+            }
+            assert_eq!(
+                write_into(&mut Vec::new(), &coord, &spec(Format::Degrees, decimals))
+                    .unwrap_err()
+                    .kind(),
+                ErrorKind::OutOfRange
+            );
+            // Not written in the Wikipedia formats
+            assert!(coord.format(&spec(Format::Wikipedia, decimals)).is_ok());
+        }
+    }
+
+    #[test]
+    fn should_reject_out_of_range() {
+        let coord = ISO6709Coord {
+            lat: 90.5,
+            lon: 0.,
+            altitude: None,
+        };
+        assert_eq!(
+            coord.format(&FormatSpec::default()).unwrap_err().kind(),
+            ErrorKind::OutOfRange
+        );
+        assert!(ISO6709Coord::new(-90., 180., None).is_ok());
+        assert!(ISO6709Coord::new(-90.1, 180., None).is_err());
+    }
+}
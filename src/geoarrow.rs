@@ -0,0 +1,135 @@
+use crate::{parse_position, ISO6709Coord, ParserOptions};
+use arrow_array::{ArrayRef, Float64Array, StructArray};
+use arrow_buffer::NullBuffer;
+use arrow_schema::{DataType, Field, Fields};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Parses a batch of strings, such as the values of an Arrow `StringArray`, into a GeoArrow point array in the
+/// separated (struct) encoding, along with the field describing it. Longitude is `x` and latitude is `y`.
+///
+/// Strings that are null or fail to parse are null points. If any coordinate has an altitude the points have a `z`
+/// child, which is NaN for the points without one.
+/// ```
+/// # use iso6709parse::{parse_geoarrow, ParserOptions};
+/// use arrow_array::{Array, StringArray};
+///
+/// let strings = StringArray::from(vec![Some("+35.50-170.10/"), None, Some("not a coordinate")]);
+/// let (field, points) = parse_geoarrow(&strings, &ParserOptions::default());
+/// assert_eq!(field.extension_type_name(), Some("geoarrow.point"));
+/// assert_eq!(points.len(), 3);
+/// assert_eq!(points.null_count(), 2);
+/// ```
+pub fn parse_geoarrow<'a>(
+    inputs: impl IntoIterator<Item = Option<&'a str>>,
+    options: &ParserOptions,
+) -> (Field, StructArray) {
+    let coords: Vec<_> = inputs
+        .into_iter()
+        .map(|input| input.and_then(|input| parse_position(input, options).ok()))
+        .map(|position| position.map(|position| position.coord))
+        .collect();
+    let with_z = coords
+        .iter()
+        .flatten()
+        .any(|coord| coord.altitude.is_some());
+
+    let column = |value: &dyn Fn(&ISO6709Coord) -> f64| -> ArrayRef {
+        Arc::new(Float64Array::from_iter_values(
+            coords
+                .iter()
+                .map(|coord| coord.as_ref().map_or(f64::NAN, value)),
+        ))
+    };
+    let mut children = vec![
+        (
+            Field::new("x", DataType::Float64, false),
+            column(&|coord| coord.lon),
+        ),
+        (
+            Field::new("y", DataType::Float64, false),
+            column(&|coord| coord.lat),
+        ),
+    ];
+    if with_z {
+        children.push((
+            Field::new("z", DataType::Float64, false),
+            column(&|coord| coord.altitude.unwrap_or(f64::NAN)),
+        ));
+    }
+    let (fields, arrays): (Vec<_>, Vec<_>) = children.into_iter().unzip();
+    let fields = Fields::from(fields);
+    let nulls = NullBuffer::from_iter(coords.iter().map(Option::is_some));
+    let points = StructArray::new(
+        fields.clone(),
+        arrays,
+        Some(nulls).filter(|nulls| nulls.null_count() > 0),
+    );
+
+    let crs = if with_z { "OGC:CRS84h" } else { "OGC:CRS84" };
+    let field =
+        Field::new("geometry", DataType::Struct(fields), true).with_metadata(HashMap::from([
+            (
+                "ARROW:extension:name".to_string(),
+                "geoarrow.point".to_string(),
+            ),
+            (
+                "ARROW:extension:metadata".to_string(),
+                format!(r#"{{"crs":"{}"}}"#, crs),
+            ),
+        ]));
+    (field, points)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Float64Type;
+    use arrow_array::Array;
+
+    #[test]
+    fn should_parse_geoarrow() {
+        let inputs = vec![
+            Some("+35.50-170.10+12CRSWGS_84/"),
+            Some("15°30′00″N 95°15′00″W"),
+        ];
+        let (field, points) = parse_geoarrow(inputs, &ParserOptions::default());
+        assert_eq!(points.num_columns(), 3);
+        assert_eq!(points.null_count(), 0);
+        assert_eq!(
+            points.column(0).as_primitive::<Float64Type>().values(),
+            &[-170.1, -95.25]
+        );
+        assert_eq!(
+            points.column(1).as_primitive::<Float64Type>().value(1),
+            15.5
+        );
+        assert!(points
+            .column(2)
+            .as_primitive::<Float64Type>()
+            .value(1)
+            .is_nan());
+        assert_eq!(
+            field.metadata()["ARROW:extension:metadata"],
+            r#"{"crs":"OGC:CRS84h"}"#
+        );
+    }
+
+    #[test]
+    fn should_null_failures() {
+        let (field, points) = parse_geoarrow(
+            [None, Some("+35.50-170.10/"), Some("")],
+            &ParserOptions::default(),
+        );
+        assert_eq!(points.num_columns(), 2);
+        assert!(points.is_null(0) && points.is_valid(1) && points.is_null(2));
+        assert_eq!(
+            field.data_type(),
+            &DataType::Struct(points.fields().clone())
+        );
+
+        let (_, points) = parse_geoarrow([], &ParserOptions::default());
+        assert!(points.is_empty());
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{Crs, Position, VerticalDatum};
+
+/// Geoid undulations (the height of the geoid above the WGS84 ellipsoid, in meters) on a regular latitude/longitude
+/// grid, used to convert between ellipsoidal heights and orthometric elevations.
+///
+/// The grid is supplied by the caller, for example by sampling the EGM96 or EGM2008 grids published by NGA
+/// at whatever resolution the application can afford. Rows run from 90° down to -90°, and each row runs from
+/// -180° to 180° inclusive, so a grid with a spacing of 15° has 13 rows of 25 values.
+#[derive(Debug, PartialEq, Clone)]
+pub struct GeoidGrid {
+    datum: VerticalDatum,
+    spacing: f64,
+    columns: usize,
+    undulations: Vec<f64>,
+}
+
+impl GeoidGrid {
+    /// Returns `None` if `datum` is not a geoid, `spacing` does not divide 180° evenly, or `undulations`
+    /// is not the expected length.
+    /// ```
+    /// # use iso6709parse::{GeoidGrid, VerticalDatum};
+    /// // A 90° grid has 3 rows of 5 values
+    /// let grid = GeoidGrid::new(VerticalDatum::Egm96, 90., vec![0.; 15]).unwrap();
+    /// assert!(GeoidGrid::new(VerticalDatum::Egm96, 90., vec![0.; 14]).is_none());
+    /// assert!(GeoidGrid::new(VerticalDatum::Ellipsoidal, 90., vec![0.; 15]).is_none());
+    /// ```
+    pub fn new(datum: VerticalDatum, spacing: f64, undulations: Vec<f64>) -> Option<GeoidGrid> {
+        if !(datum.is_orthometric() && spacing > 0. && spacing <= 180.) {
+            return None;
+        }
+        let steps = 180. / spacing;
+        if (steps - steps.round()).abs() > 1e-9 {
+            return None;
+        }
+        let rows = steps.round() as usize + 1;
+        let columns = 2 * (rows - 1) + 1;
+        if undulations.len() != rows * columns {
+            return None;
+        }
+        Some(GeoidGrid {
+            datum,
+            spacing,
+            columns,
+            undulations,
+        })
+    }
+
+    /// The geoid that the grid describes
+    pub fn datum(&self) -> VerticalDatum {
+        self.datum
+    }
+
+    /// Height of the geoid above the ellipsoid at the given location, bilinearly interpolated from the grid
+    pub fn undulation(&self, lat: f64, lon: f64) -> f64 {
+        let row = ((90. - lat.clamp(-90., 90.)) / self.spacing).max(0.);
+        let column = ((lon.clamp(-180., 180.) + 180.) / self.spacing).max(0.);
+        let last_row = (self.undulations.len() / self.columns - 1) as f64;
+        let last_column = (self.columns - 1) as f64;
+
+        let (row0, column0) = (row.floor().min(last_row), column.floor().min(last_column));
+        let (row1, column1) = ((row0 + 1.).min(last_row), (column0 + 1.).min(last_column));
+        let (row_fraction, column_fraction) = (row - row0, column - column0);
+        let value =
+            |row: f64, column: f64| self.undulations[row as usize * self.columns + column as usize];
+
+        let top =
+            value(row0, column0) * (1. - column_fraction) + value(row0, column1) * column_fraction;
+        let bottom =
+            value(row1, column0) * (1. - column_fraction) + value(row1, column1) * column_fraction;
+        top * (1. - row_fraction) + bottom * row_fraction
+    }
+}
+
+impl Position {
+    /// Converts the altitude to another vertical datum, such as turning the ellipsoidal height from a GNSS receiver
+    /// into an elevation above mean sea level. `grid` must describe whichever geoid is involved in the conversion.
+    /// Returns `None` when there is no altitude, the current vertical datum is unknown, or the grid is for a different geoid.
+    /// ```
+    /// # use iso6709parse::{parse_position, Crs, GeoidGrid, ParserOptions, VerticalDatum};
+    /// let grid = GeoidGrid::new(VerticalDatum::Egm2008, 90., vec![-30.; 15]).unwrap();
+    /// let position = parse_position("N35.50W170.10+100CRSWGS_84_3D/", &ParserOptions::default()).unwrap();
+    /// let elevation = position.with_vertical_datum(VerticalDatum::Egm2008, &grid).unwrap();
+    /// assert_eq!(elevation.coord.altitude, Some(130.));
+    /// assert_eq!(elevation.crs, Some(Crs::from("EPSG:4326+3855")));
+    /// ```
+    pub fn with_vertical_datum(&self, target: VerticalDatum, grid: &GeoidGrid) -> Option<Position> {
+        let current = self.vertical_datum?;
+        let altitude = self.coord.altitude?;
+        let undulation = grid.undulation(self.coord.lat, self.coord.lon);
+
+        let ellipsoidal = match current {
+            VerticalDatum::Ellipsoidal => altitude,
+            geoid if geoid == grid.datum => altitude + undulation,
+            _ => return None,
+        };
+        let (altitude, crs) = match target {
+            VerticalDatum::Ellipsoidal => (ellipsoidal, Crs::Wgs84_3D),
+            VerticalDatum::Egm96 if grid.datum == target => {
+                (ellipsoidal - undulation, Crs::from("EPSG:4326+5773"))
+            }
+            VerticalDatum::Egm2008 if grid.datum == target => {
+                (ellipsoidal - undulation, Crs::from("EPSG:4326+3855"))
+            }
+            _ => return None,
+        };
+
+        let mut position = self.clone();
+        position.coord.altitude = Some(altitude);
+        position.crs = Some(crs);
+        position.crs_assumed = false;
+        position.vertical_datum = Some(target);
+        Some(position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_position, ParserOptions};
+
+    fn grid() -> GeoidGrid {
+        // 90° spacing: rows at 90, 0, -90 and columns at -180, -90, 0, 90, 180
+        #[rustfmt::skip]
+        let undulations = vec![
+            10., 10., 10., 10., 10.,
+            -20., 0., 20., 40., -20.,
+            -30., -30., -30., -30., -30.,
+        ];
+        GeoidGrid::new(VerticalDatum::Egm96, 90., undulations).unwrap()
+    }
+
+    #[test]
+    fn should_interpolate_undulation() {
+        let grid = grid();
+        assert_eq!(grid.undulation(0., 0.), 20.);
+        assert_eq!(grid.undulation(0., 45.), 30.);
+        assert_eq!(grid.undulation(45., 0.), 15.);
+        assert_eq!(grid.undulation(90., 180.), 10.);
+        assert_eq!(grid.undulation(-90., -180.), -30.);
+        assert_eq!(grid.undulation(-45., 180.), -25.);
+    }
+
+    #[test]
+    fn should_convert_vertical_datum() {
+        let options = ParserOptions::default();
+        let position = parse_position("+00+090+100CRSWGS_84_3D/", &options).unwrap();
+        let elevation = position
+            .with_vertical_datum(VerticalDatum::Egm96, &grid())
+            .unwrap();
+        assert_eq!(elevation.coord.altitude, Some(60.));
+        assert_eq!(elevation.vertical_datum, Some(VerticalDatum::Egm96));
+
+        let height = elevation
+            .with_vertical_datum(VerticalDatum::Ellipsoidal, &grid())
+            .unwrap();
+        assert_eq!(
+            height,
+            position
+                .with_vertical_datum(VerticalDatum::Ellipsoidal, &grid())
+                .unwrap()
+        );
+        assert_eq!(height.coord.altitude, Some(100.));
+
+        assert!(position
+            .with_vertical_datum(VerticalDatum::Egm2008, &grid())
+            .is_none());
+        let unknown = parse_position("+00+090+100CRSWGS_84/", &options).unwrap();
+        assert!(unknown
+            .with_vertical_datum(VerticalDatum::Egm96, &grid())
+            .is_none());
+    }
+}
@@ -0,0 +1,108 @@
+use crate::{ErrorKind, ISO6709Coord, ISO6709Error};
+use geojson::{Feature, Geometry, Value};
+
+/// The position is longitude, latitude and the altitude, if any, as RFC 7946 orders them. `geojson::Geometry`
+/// converts from anything that converts to a `Value`, so it converts from `ISO6709Coord` too.
+/// ```
+/// # use iso6709parse::{parse, ISO6709Coord};
+/// let coord: ISO6709Coord = parse("+35.50-170.10+12CRSWGS_84/").unwrap();
+/// assert_eq!(geojson::Value::from(coord), geojson::Value::Point(vec![-170.1, 35.5, 12.]));
+///
+/// let feature: geojson::Feature = r#"{"type": "Feature", "properties": null,
+///     "geometry": {"type": "Point", "coordinates": [-170.1, 35.5]}}"#.parse().unwrap();
+/// assert_eq!(ISO6709Coord::try_from(feature).unwrap().lat, 35.5);
+/// ```
+impl From<ISO6709Coord> for Value {
+    fn from(value: ISO6709Coord) -> Self {
+        let mut position = vec![value.lon, value.lat];
+        position.extend(value.altitude);
+        Value::Point(position)
+    }
+}
+
+/// Fails with [`ErrorKind::Syntax`] for anything but a point of two or three values, and checks the values the same way
+/// as [`ISO6709Coord::new`]
+impl TryFrom<Value> for ISO6709Coord {
+    type Error = ISO6709Error;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        let Value::Point(position) = value else {
+            return Err(ISO6709Error::new(
+                ErrorKind::Syntax,
+                format!("a {} is not a point", value.type_name()),
+            ));
+        };
+        match position[..] {
+            [lon, lat] => ISO6709Coord::new(lat, lon, None),
+            [lon, lat, altitude] => ISO6709Coord::new(lat, lon, Some(altitude)),
+            _ => Err(ISO6709Error::new(
+                ErrorKind::Syntax,
+                format!("a position has {} values instead of 2 or 3", position.len()),
+            )),
+        }
+    }
+}
+
+impl TryFrom<Geometry> for ISO6709Coord {
+    type Error = ISO6709Error;
+
+    fn try_from(value: Geometry) -> Result<Self, Self::Error> {
+        value.value.try_into()
+    }
+}
+
+/// Fails with [`ErrorKind::Syntax`] if the feature has no geometry
+impl TryFrom<Feature> for ISO6709Coord {
+    type Error = ISO6709Error;
+
+    fn try_from(value: Feature) -> Result<Self, Self::Error> {
+        value
+            .geometry
+            .ok_or_else(|| ISO6709Error::new(ErrorKind::Syntax, "the feature has no geometry"))?
+            .try_into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_convert_to_geojson() {
+        let coord = ISO6709Coord::new(35.5, -170.1, None).unwrap();
+        assert_eq!(
+            Geometry::from(coord).to_string(),
+            r#"{"type":"Point","coordinates":[-170.1,35.5]}"#
+        );
+        assert_eq!(ISO6709Coord::try_from(Geometry::from(coord)), Ok(coord));
+
+        let coord = ISO6709Coord::new(35.5, -170.1, Some(-12.5)).unwrap();
+        assert_eq!(ISO6709Coord::try_from(Value::from(coord)), Ok(coord));
+    }
+
+    #[test]
+    fn should_err_from_geojson() {
+        for value in [
+            Value::Point(vec![35.5]),
+            Value::Point(vec![35.5, -170.1, 12., 1.]),
+            Value::MultiPoint(vec![vec![-170.1, 35.5]]),
+        ] {
+            assert_eq!(
+                ISO6709Coord::try_from(value).unwrap_err().kind(),
+                ErrorKind::Syntax
+            );
+        }
+        assert_eq!(
+            ISO6709Coord::try_from(Value::Point(vec![35.5, -170.1]))
+                .unwrap_err()
+                .kind(),
+            ErrorKind::OutOfRange
+        );
+        assert_eq!(
+            ISO6709Coord::try_from(Feature::default())
+                .unwrap_err()
+                .to_string(),
+            "Failed to parse ISO6709 coordinate: the feature has no geometry"
+        );
+    }
+}
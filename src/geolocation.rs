@@ -0,0 +1,140 @@
+use crate::{parse, ISO6709Coord, ISO6709Error};
+use serde::{de, Deserialize, Deserializer, Serialize};
+
+/// The `coords` of a position from the W3C Geolocation API, in the JSON shape browsers produce. Angles are in decimal
+/// degrees, distances in meters, and the speed in meters per second.
+/// ```
+/// # use iso6709parse::{GeolocationCoordinates, ISO6709Coord};
+/// let json = r#"{"latitude": 35.5, "longitude": -170.1, "altitude": null, "accuracy": 12.5}"#;
+/// let coords: GeolocationCoordinates = serde_json::from_str(json).unwrap();
+/// let coord = ISO6709Coord::try_from(coords).unwrap();
+/// assert_eq!((coord.lat, coord.lon, coord.altitude), (35.5, -170.1, None));
+///
+/// let coords = GeolocationCoordinates::from(coord);
+/// assert_eq!(serde_json::to_string(&coords).unwrap(), r#"{"latitude":35.5,"longitude":-170.1,"altitude":null}"#);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct GeolocationCoordinates {
+    pub latitude: f64,
+    pub longitude: f64,
+    /// Height above the WGS84 ellipsoid
+    #[serde(default)]
+    pub altitude: Option<f64>,
+    /// Accuracy of the latitude and longitude
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accuracy: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub altitude_accuracy: Option<f64>,
+    /// Direction of travel, clockwise from true north
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub heading: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speed: Option<f64>,
+}
+
+/// Checks the values the same way as [`ISO6709Coord::new`]
+impl TryFrom<GeolocationCoordinates> for ISO6709Coord {
+    type Error = ISO6709Error;
+
+    fn try_from(value: GeolocationCoordinates) -> Result<Self, Self::Error> {
+        ISO6709Coord::new(value.latitude, value.longitude, value.altitude)
+    }
+}
+
+/// The accuracy, heading and speed are unknown
+impl From<ISO6709Coord> for GeolocationCoordinates {
+    fn from(value: ISO6709Coord) -> Self {
+        GeolocationCoordinates {
+            latitude: value.lat,
+            longitude: value.lon,
+            altitude: value.altitude,
+            accuracy: None,
+            altitude_accuracy: None,
+            heading: None,
+            speed: None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Input {
+    Iso6709(String),
+    Geolocation(GeolocationCoordinates),
+}
+
+/// Reads a string in either ISO6709 format, or a [`GeolocationCoordinates`] object, so that services can
+/// accept both from their clients.
+/// ```
+/// # use iso6709parse::ISO6709Coord;
+/// let from_string: ISO6709Coord = serde_json::from_str(r#""N35.50W170.10/""#).unwrap();
+/// let from_object: ISO6709Coord = serde_json::from_str(r#"{"latitude": 35.5, "longitude": -170.1}"#).unwrap();
+/// assert_eq!(from_string, from_object);
+/// ```
+impl<'de> Deserialize<'de> for ISO6709Coord {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Input::deserialize(deserializer)? {
+            Input::Iso6709(str) => parse(&str),
+            Input::Geolocation(coords) => ISO6709Coord::try_from(coords),
+        }
+        .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ErrorKind;
+
+    #[test]
+    fn should_convert_geolocation() {
+        let json = r#"{
+            "latitude": 27.9881,
+            "longitude": 86.925,
+            "altitude": 8848.86,
+            "accuracy": 5,
+            "altitudeAccuracy": 10,
+            "heading": null,
+            "speed": 0.5
+        }"#;
+        let coords: GeolocationCoordinates = serde_json::from_str(json).unwrap();
+        assert_eq!(coords.altitude_accuracy, Some(10.));
+        assert_eq!(coords.heading, None);
+        assert_eq!(
+            ISO6709Coord::try_from(coords),
+            Ok(ISO6709Coord::from_parts(27.9881, 86.925, Some(8848.86)))
+        );
+
+        let coords = GeolocationCoordinates {
+            latitude: 95.,
+            ..coords
+        };
+        assert_eq!(
+            ISO6709Coord::try_from(coords).unwrap_err().kind(),
+            ErrorKind::OutOfRange
+        );
+    }
+
+    #[test]
+    fn should_deserialize_either_form() {
+        let coords: Vec<ISO6709Coord> = serde_json::from_str(
+            r#"["15°30′00″N 95°15′00″W 123.45m", {"latitude": 15.5, "longitude": -95.25, "altitude": 123.45}]"#,
+        )
+        .unwrap();
+        assert_eq!(coords[0], coords[1]);
+
+        let err = serde_json::from_str::<ISO6709Coord>(r#""N95.50W170.10/""#).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Failed to parse ISO6709 coordinate"));
+        let err = serde_json::from_str::<ISO6709Coord>(r#"{"latitude": 1, "longitude": 200}"#)
+            .unwrap_err();
+        assert!(err.to_string().starts_with("Invalid ISO6709 coordinate"));
+        assert!(serde_json::from_str::<ISO6709Coord>(r#"{"latitude": 1}"#).is_err());
+    }
+}
@@ -0,0 +1,120 @@
+use crate::xml::{elements, number};
+use crate::{ErrorKind, FromIso6709, ISO6709Coord, ISO6709Error};
+
+/// Parses a GeoRSS `<georss:point>lat lon</georss:point>` into any struct that implements [`FromIso6709`]. The
+/// point can be on its own or anywhere in a feed entry, and a `georss:elev` element alongside it is read as the
+/// altitude.
+/// ```
+/// # use iso6709parse::{parse_georss, ISO6709Coord};
+/// let coord: ISO6709Coord = parse_georss("<georss:point>45.256 -71.92</georss:point>").unwrap();
+/// assert_eq!((coord.lon, coord.lat), (-71.92, 45.256));
+/// ```
+pub fn parse_georss<T>(str: &str) -> Result<T, ISO6709Error>
+where
+    T: FromIso6709,
+{
+    read_georss(str)
+        .map(T::from_iso6709)
+        .map_err(|err| err.with_input(str))
+}
+
+fn read_georss(str: &str) -> Result<ISO6709Coord, ISO6709Error> {
+    let elements = elements(str)?;
+    let point = elements
+        .iter()
+        .find(|element| element.name == "point")
+        .ok_or_else(|| ISO6709Error::new(ErrorKind::Syntax, "missing `point` element"))?;
+    let values = point
+        .text
+        .split_whitespace()
+        .map(|value| number(value, "point"))
+        .collect::<Result<Vec<_>, _>>()?;
+    let [lat, lon] = values[..] else {
+        return Err(ISO6709Error::new(
+            ErrorKind::Syntax,
+            format!("`point` has {} values instead of 2", values.len()),
+        ));
+    };
+    let altitude = elements
+        .iter()
+        .find(|element| element.name == "elev")
+        .map(|elev| number(&elev.text, "elev"))
+        .transpose()?;
+    let coord = ISO6709Coord::new(lat, lon, altitude)?;
+    Ok(ISO6709Coord::unsigned_zero(
+        coord.lat,
+        coord.lon,
+        coord.altitude,
+    ))
+}
+
+impl ISO6709Coord {
+    /// Writes the coordinate as a GeoRSS `georss:point`, followed by a `georss:elev` if there is an altitude.
+    /// Fails if a value is NaN or infinite, or the latitude or longitude is out of range.
+    /// ```
+    /// # use iso6709parse::ISO6709Coord;
+    /// let coord = ISO6709Coord::new(45.256, -71.92, None).unwrap();
+    /// assert_eq!(coord.to_georss().unwrap(), "<georss:point>45.256 -71.92</georss:point>");
+    /// ```
+    pub fn to_georss(&self) -> Result<String, ISO6709Error> {
+        ISO6709Coord::new(self.lat, self.lon, self.altitude)?;
+        let mut out = format!(
+            "<georss:point>{} {}</georss:point>",
+            self.lat + 0.,
+            self.lon + 0.
+        );
+        if let Some(altitude) = self.altitude {
+            out.push_str(&format!("<georss:elev>{}</georss:elev>", altitude + 0.));
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_georss() {
+        let entry = r#"<entry xmlns:georss="http://www.georss.org/georss">
+            <title>Summit</title>
+            <georss:point>
+                27.9881 86.925
+            </georss:point>
+            <georss:elev>8848.86</georss:elev>
+        </entry>"#;
+        assert_eq!(
+            parse_georss::<ISO6709Coord>(entry),
+            Ok(ISO6709Coord::from_parts(27.9881, 86.925, Some(8848.86)))
+        );
+
+        let coord = ISO6709Coord::from_parts(-35.5, 170.1, Some(-12.5));
+        assert_eq!(
+            parse_georss::<ISO6709Coord>(&coord.to_georss().unwrap()),
+            Ok(coord)
+        );
+    }
+
+    #[test]
+    fn should_err_georss() {
+        for georss in [
+            "<georss:line>45.256 -110.45 46.46 -109.48</georss:line>",
+            "<georss:point>45.256</georss:point>",
+            "<georss:point>45.256,-71.92</georss:point>",
+            "<georss:point>45.256 -71.92</georss:point><georss:elev>high</georss:elev>",
+        ] {
+            let err = parse_georss::<ISO6709Coord>(georss).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::Syntax, "{}", georss);
+        }
+        let err = parse_georss::<ISO6709Coord>("<georss:point>-71.92 245.256</georss:point>")
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::OutOfRange);
+        assert_eq!(
+            ISO6709Coord::from_parts(95., 0., None)
+                .to_georss()
+                .unwrap_err()
+                .kind(),
+            ErrorKind::OutOfRange
+        );
+    }
+}
@@ -0,0 +1,193 @@
+use crate::xml::{elements, number};
+use crate::{Crs, ErrorKind, FromIso6709, ISO6709Coord, ISO6709Error, Position};
+
+const GML_NAMESPACE: &str = "http://www.opengis.net/gml/3.2";
+
+/// Parses a GML `gml:Point` or `gml:pos` into any struct that implements [`FromIso6709`], see
+/// [`parse_gml_position`].
+/// ```
+/// # use iso6709parse::{parse_gml, ISO6709Coord};
+/// let gml = r#"<gml:Point srsName="EPSG:4326"><gml:pos>35.5 -170.1</gml:pos></gml:Point>"#;
+/// let coord: ISO6709Coord = parse_gml(gml).unwrap();
+/// assert_eq!((coord.lon, coord.lat), (-170.1, 35.5));
+/// ```
+pub fn parse_gml<T>(str: &str) -> Result<T, ISO6709Error>
+where
+    T: FromIso6709,
+{
+    parse_gml_position(str).map(|position| T::from_iso6709(position.coord))
+}
+
+/// Parses a GML `gml:Point` or `gml:pos` into a [`Position`]. The `gml:pos` holds the latitude, longitude and
+/// optionally the height, separated by spaces, in the axis order of EPSG:4326 and EPSG:4979. Only for the `CRS84`
+/// identifiers of OGC is the longitude first. The `srsName` and `srsDimension` attributes are read from the
+/// `gml:pos` or the `gml:Point` around it, and EPSG identifiers in any of their URN or URL forms are read into
+/// [`Crs::Epsg`].
+/// ```
+/// # use iso6709parse::{parse_gml_position, Crs};
+/// let gml = r#"<gml:Point xmlns:gml="http://www.opengis.net/gml/3.2" srsName="urn:ogc:def:crs:EPSG::4979">
+///     <gml:pos srsDimension="3">35.5 -170.1 8712</gml:pos>
+/// </gml:Point>"#;
+/// let position = parse_gml_position(gml).unwrap();
+/// assert_eq!(position.coord.altitude, Some(8712.));
+/// assert_eq!(position.crs, Some(Crs::Epsg(4979)));
+/// ```
+pub fn parse_gml_position(str: &str) -> Result<Position, ISO6709Error> {
+    read_gml(str).map_err(|err| err.with_input(str))
+}
+
+fn read_gml(str: &str) -> Result<Position, ISO6709Error> {
+    let elements = elements(str)?;
+    let pos = elements
+        .iter()
+        .find(|element| element.name == "pos")
+        .ok_or_else(|| ISO6709Error::new(ErrorKind::Syntax, "missing `pos` element"))?;
+    let point = elements.iter().find(|element| element.name == "Point");
+    let attribute = |name: &str| {
+        pos.attribute(name)
+            .or_else(|| point.and_then(|point| point.attribute(name)))
+    };
+
+    let values = pos
+        .text
+        .split_whitespace()
+        .map(|value| number(value, "pos"))
+        .collect::<Result<Vec<_>, _>>()?;
+    let dimension = match attribute("srsDimension") {
+        Some(dimension) => dimension.trim().parse().ok(),
+        None => Some(values.len()),
+    };
+    if dimension != Some(values.len()) || !(2..=3).contains(&values.len()) {
+        return Err(ISO6709Error::new(
+            ErrorKind::Syntax,
+            format!(
+                "`pos` has {} values, but needs 2 or 3 matching its `srsDimension`",
+                values.len()
+            ),
+        ));
+    }
+
+    let srs_name = attribute("srsName").map(str::trim);
+    let (lat, lon) = match srs_name {
+        Some(name) if is_crs84(name) => (values[1], values[0]),
+        _ => (values[0], values[1]),
+    };
+    let coord = ISO6709Coord::new(lat, lon, values.get(2).copied())?;
+    Ok(Position::new(
+        ISO6709Coord::unsigned_zero(coord.lat, coord.lon, coord.altitude),
+        srs_name.map(crs),
+    ))
+}
+
+/// OGC's longitude first CRS, as in `OGC:CRS84` or `urn:ogc:def:crs:OGC:1.3:CRS84`
+fn is_crs84(srs_name: &str) -> bool {
+    srs_name.ends_with("CRS84") || srs_name.ends_with("CRS84h")
+}
+
+/// Reads the EPSG code out of `EPSG:4326`, `urn:ogc:def:crs:EPSG::4326`,
+/// `http://www.opengis.net/def/crs/EPSG/0/4326` and `http://www.opengis.net/gml/srs/epsg.xml#4326`
+fn crs(srs_name: &str) -> Crs {
+    let lower = srs_name.to_ascii_lowercase();
+    let code = srs_name
+        .rsplit([':', '/', '#'])
+        .next()
+        .and_then(|code| code.parse().ok())
+        .filter(|_| lower.contains("epsg"));
+    match code {
+        Some(code) => Crs::Epsg(code),
+        None => Crs::Other(srs_name.to_string()),
+    }
+}
+
+impl ISO6709Coord {
+    /// Writes the coordinate as a GML `gml:Point` in EPSG:4326, or EPSG:4979 along with the height when there is an
+    /// altitude. Fails if a value is NaN or infinite, or the latitude or longitude is out of range.
+    /// ```
+    /// # use iso6709parse::ISO6709Coord;
+    /// let coord = ISO6709Coord::new(35.5, -170.1, None).unwrap();
+    /// assert_eq!(
+    ///     coord.to_gml().unwrap(),
+    ///     r#"<gml:Point xmlns:gml="http://www.opengis.net/gml/3.2" srsName="http://www.opengis.net/def/crs/EPSG/0/4326" srsDimension="2"><gml:pos>35.5 -170.1</gml:pos></gml:Point>"#
+    /// );
+    /// ```
+    pub fn to_gml(&self) -> Result<String, ISO6709Error> {
+        ISO6709Coord::new(self.lat, self.lon, self.altitude)?;
+        let (code, dimension, pos) = match self.altitude {
+            Some(altitude) => (
+                4979,
+                3,
+                format!("{} {} {}", self.lat + 0., self.lon + 0., altitude + 0.),
+            ),
+            None => (4326, 2, format!("{} {}", self.lat + 0., self.lon + 0.)),
+        };
+        Ok(format!(
+            "<gml:Point xmlns:gml=\"{}\" srsName=\"http://www.opengis.net/def/crs/EPSG/0/{}\" \
+             srsDimension=\"{}\"><gml:pos>{}</gml:pos></gml:Point>",
+            GML_NAMESPACE, code, dimension, pos
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_gml() {
+        let position = parse_gml_position("<gml:pos>-35.5 170.1</gml:pos>").unwrap();
+        assert_eq!(position.coord, ISO6709Coord::from_parts(-35.5, 170.1, None));
+        assert_eq!(position.crs, None);
+
+        let position = parse_gml_position(
+            r#"<Point srsName="http://www.opengis.net/def/crs/OGC/1.3/CRS84"><pos>-170.1 35.5</pos></Point>"#,
+        )
+        .unwrap();
+        assert_eq!(position.coord, ISO6709Coord::from_parts(35.5, -170.1, None));
+        assert_eq!(
+            position.crs,
+            Some(Crs::Other(
+                "http://www.opengis.net/def/crs/OGC/1.3/CRS84".to_string()
+            ))
+        );
+
+        for srs_name in [
+            "EPSG:4326",
+            "urn:ogc:def:crs:EPSG::4326",
+            "urn:ogc:def:crs:EPSG:6.6:4326",
+            "http://www.opengis.net/def/crs/EPSG/0/4326",
+            "http://www.opengis.net/gml/srs/epsg.xml#4326",
+        ] {
+            assert_eq!(crs(srs_name), Crs::Epsg(4326), "{}", srs_name);
+        }
+    }
+
+    #[test]
+    fn should_err_gml() {
+        for gml in [
+            "<gml:Point></gml:Point>",
+            "<gml:pos>35.5</gml:pos>",
+            "<gml:pos>35.5 -170.1 1 2</gml:pos>",
+            r#"<gml:pos srsDimension="3">35.5 -170.1</gml:pos>"#,
+            "<gml:pos>35.5 west</gml:pos>",
+        ] {
+            let err = parse_gml::<ISO6709Coord>(gml).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::Syntax, "{}", gml);
+        }
+        let err = parse_gml::<ISO6709Coord>("<gml:pos>-170.1 35.5</gml:pos>").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::OutOfRange);
+    }
+
+    #[test]
+    fn should_write_gml() {
+        let coord = ISO6709Coord::from_parts(35.5, -170.1, Some(8712.));
+        let gml = coord.to_gml().unwrap();
+        assert!(gml
+            .contains(r#"srsName="http://www.opengis.net/def/crs/EPSG/0/4979" srsDimension="3""#));
+        let position = parse_gml_position(&gml).unwrap();
+        assert_eq!(position.coord, coord);
+        assert_eq!(position.crs, Some(Crs::Epsg(4979)));
+
+        let coord = ISO6709Coord::from_parts(35.5, f64::NAN, None);
+        assert_eq!(coord.to_gml().unwrap_err().kind(), ErrorKind::NonFinite);
+    }
+}
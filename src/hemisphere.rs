@@ -0,0 +1,344 @@
+use crate::parsers::common::human_readable::{parse_dms_with, parse_hemisphere_with};
+use crate::parsers::latitude::string_expression::latitude_body;
+use crate::parsers::longitude::string_expression::longitude_body;
+use crate::{Hemispheres, Symbols};
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::{multispace0, one_of, space1};
+use nom::combinator::{consumed, opt, recognize, rest, value};
+use nom::sequence::{pair, tuple};
+use nom::IResult;
+
+/// An angle written with both a sign and a hemisphere, such as `-95°15′00″W` or `+35.50S`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub(crate) struct SignConflict {
+    pub(crate) sign: char,
+    pub(crate) hemisphere: String,
+    /// Whether the hemisphere is south or west
+    negative: bool,
+}
+
+impl SignConflict {
+    /// `false` if the sign and the hemisphere point the same way, as in `-95°15′00″W`
+    pub(crate) fn is_contradictory(&self) -> bool {
+        (self.sign == '-') != self.negative
+    }
+}
+
+/// An angle as written, before it is put back together by [`resolve_sign_conflicts`]
+struct Angle<'a> {
+    sign: Option<char>,
+    body: &'a str,
+    /// The text of the hemisphere, along with `1.` or `-1.`
+    hemisphere: Option<(&'a str, f64)>,
+}
+
+/// The hemispheres of the latitude and longitude, the positive one first
+type Letters<'s> = (&'s [String], &'s [String]);
+
+fn latitude_letters(symbols: &Symbols) -> Letters<'_> {
+    (&symbols.north, &symbols.south)
+}
+
+fn longitude_letters(symbols: &Symbols) -> Letters<'_> {
+    (&symbols.east, &symbols.west)
+}
+
+/// The letters of the string representation, which are not configurable
+fn string_letters(hemispheres: &'static str) -> impl Fn(&str) -> IResult<&str, (&str, f64)> {
+    move |inp| {
+        let (positive, negative) = hemispheres.split_at(1);
+        consumed(alt((value(1., tag(positive)), value(-1., tag(negative)))))(inp)
+    }
+}
+
+/// `[+-]` then the degrees, minutes and seconds, then the hemisphere, which is optional unless `required`
+fn readable_angle<'s, 'a>(
+    symbols: &'s Symbols,
+    hemispheres: Letters<'s>,
+    required: bool,
+) -> impl Fn(&'a str) -> IResult<&'a str, Angle<'a>> + 's {
+    move |inp| {
+        let (rem, (sign, body)) = pair(opt(one_of("+-")), recognize(parse_dms_with(symbols)))(inp)?;
+        let (rem, hemisphere) = if required {
+            parse_hemisphere_with(hemispheres)(rem)
+                .map(|(rem, hemisphere)| (rem, Some(hemisphere)))?
+        } else {
+            opt(parse_hemisphere_with(hemispheres))(rem)?
+        };
+        Ok((
+            rem,
+            Angle {
+                sign,
+                body,
+                hemisphere,
+            },
+        ))
+    }
+}
+
+/// Either a leading hemisphere letter as ISO6709 allows, or a number with a sign, a trailing hemisphere letter, or both
+fn string_angle<'a>(
+    hemispheres: &'static str,
+    number: fn(&str) -> IResult<&str, f64>,
+) -> impl Fn(&'a str) -> IResult<&'a str, Angle<'a>> {
+    move |inp| {
+        if let Ok((rem, (hemisphere, body))) =
+            pair(string_letters(hemispheres), recognize(number))(inp)
+        {
+            return Ok((
+                rem,
+                Angle {
+                    sign: None,
+                    body,
+                    hemisphere: Some(hemisphere),
+                },
+            ));
+        }
+        let (rem, (sign, body, hemisphere)) = tuple((
+            opt(one_of("+-")),
+            recognize(number),
+            opt(string_letters(hemispheres)),
+        ))(inp)?;
+        Ok((
+            rem,
+            Angle {
+                sign,
+                body,
+                hemisphere,
+            },
+        ))
+    }
+}
+
+/// Finds latitudes and longitudes written with both a sign and a hemisphere, and rewrites the input with only the
+/// hemisphere, which is taken to be what the author meant. Returns `None` if there are none. Readable angles are
+/// read with the markers in `symbols`.
+pub(crate) fn resolve_sign_conflicts(
+    str: &str,
+    symbols: &Symbols,
+) -> Option<(String, Vec<SignConflict>)> {
+    let readable = tuple((
+        multispace0,
+        readable_angle(symbols, latitude_letters(symbols), true),
+        space1,
+        readable_angle(symbols, longitude_letters(symbols), true),
+        rest,
+    ));
+    let string = tuple((
+        multispace0,
+        string_angle("NS", latitude_body),
+        tag(""),
+        string_angle("EW", longitude_body),
+        rest,
+    ));
+    let (_, (lead, lat, separator, lon, tail)) = alt((readable, string))(str).ok()?;
+    // Checked before rewriting, so inputs without conflicts are not copied
+    if [&lat, &lon]
+        .iter()
+        .all(|angle| angle.sign.is_none() || angle.hemisphere.is_none())
+    {
+        return None;
+    }
+
+    // Readable angles end with the hemisphere, the string representation starts with it
+    let readable = !separator.is_empty();
+    let mut conflicts = vec![];
+    let mut rewritten = String::from(lead);
+    for (angle, separator) in [(lat, separator), (lon, "")] {
+        match (angle.sign, angle.hemisphere) {
+            (sign, Some((hemisphere, multiplier))) => {
+                if let Some(sign) = sign {
+                    conflicts.push(SignConflict {
+                        sign,
+                        hemisphere: hemisphere.to_string(),
+                        negative: multiplier < 0.,
+                    });
+                }
+                if readable {
+                    rewritten.push_str(angle.body);
+                    rewritten.push_str(hemisphere);
+                } else {
+                    rewritten.push_str(hemisphere);
+                    rewritten.push_str(angle.body);
+                }
+            }
+            (Some(sign), None) => {
+                rewritten.push(sign);
+                rewritten.push_str(angle.body);
+            }
+            // Only seen after a trailing hemisphere letter, as in `+35.50S170.10`
+            (None, None) => {
+                rewritten.push('+');
+                rewritten.push_str(angle.body);
+            }
+        }
+        rewritten.push_str(separator);
+    }
+    rewritten.push_str(tail);
+
+    if conflicts.is_empty() {
+        None
+    } else {
+        Some((rewritten, conflicts))
+    }
+}
+
+/// Puts the hemisphere of `hemispheres` on a latitude or longitude written without a sign or hemisphere:
+/// the first one in `symbols` after a readable angle, or a sign before the latitude of the string representation.
+/// Returns `None` if every angle has one.
+pub(crate) fn assume_hemispheres(
+    str: &str,
+    hemispheres: Hemispheres,
+    symbols: &Symbols,
+) -> Option<String> {
+    let mut readable = tuple((
+        multispace0,
+        readable_angle(symbols, latitude_letters(symbols), false),
+        space1,
+        readable_angle(symbols, longitude_letters(symbols), false),
+        rest,
+    ));
+    if let Ok((_, (lead, lat, separator, lon, tail))) = readable(str) {
+        let letter = |letters: &[String]| letters.first().cloned().unwrap_or_default();
+        let lat_letter = letter(if hemispheres.south {
+            &symbols.south
+        } else {
+            &symbols.north
+        });
+        let lon_letter = letter(if hemispheres.west {
+            &symbols.west
+        } else {
+            &symbols.east
+        });
+        let mut assumed = false;
+        let mut rewritten = String::from(lead);
+        for (angle, letter, separator) in [(lat, lat_letter, separator), (lon, lon_letter, "")] {
+            rewritten.extend(angle.sign);
+            rewritten.push_str(angle.body);
+            match angle.hemisphere {
+                Some((hemisphere, _)) => rewritten.push_str(hemisphere),
+                None if angle.sign.is_none() => {
+                    assumed = true;
+                    rewritten.push_str(&letter);
+                }
+                None => {}
+            }
+            rewritten.push_str(separator);
+        }
+        rewritten.push_str(tail);
+        return assumed.then_some(rewritten);
+    }
+
+    // Without a sign or hemisphere letter on the longitude, there is no telling where the latitude ends
+    let (_, (lead, lat, lon)) = tuple((
+        multispace0,
+        string_angle("NS", latitude_body),
+        recognize(string_angle("EW", longitude_body)),
+    ))(str)
+    .ok()?;
+    if lat.sign.is_some() || lat.hemisphere.is_some() || !lon.starts_with(['+', '-', 'E', 'W']) {
+        return None;
+    }
+    let sign = if hemispheres.south { '-' } else { '+' };
+    Some(format!("{}{}{}", lead, sign, &str[lead.len()..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_assume_hemispheres() {
+        let north_east = Hemispheres::default();
+        let south_west = Hemispheres {
+            south: true,
+            west: true,
+        };
+        let symbols = Symbols::default();
+        let assume = |str, hemispheres| assume_hemispheres(str, hemispheres, &symbols);
+        assert_eq!(
+            assume(" 15°30′00″ 95°15′00″ 123m", north_east).as_deref(),
+            Some(" 15°30′00″N 95°15′00″E 123m")
+        );
+        assert_eq!(
+            assume("15°30′00″ 95°15′00″W", south_west).as_deref(),
+            Some("15°30′00″S 95°15′00″W")
+        );
+        assert_eq!(assume("15°30′00″N -95°15′00″", south_west), None);
+        assert_eq!(assume("15°30′00″N 95°15′00″W", north_east), None);
+
+        assert_eq!(
+            assume("35.50-170.10/", south_west).as_deref(),
+            Some("-35.50-170.10/")
+        );
+        assert_eq!(
+            assume("3530W17006/", north_east).as_deref(),
+            Some("+3530W17006/")
+        );
+        assert_eq!(assume("+35.50-170.10/", north_east), None);
+        assert_eq!(assume("N35.50W170.10/", north_east), None);
+        assert_eq!(assume("35.50170.10/", north_east), None);
+        assert_eq!(assume("50.68 North, 95.81 West", north_east), None);
+
+        let symbols = Symbols {
+            degree: vec!["º".to_string()],
+            south: vec!["Sur".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(
+            assume_hemispheres("15º30′00″ 95º15′00″W", south_west, &symbols).as_deref(),
+            Some("15º30′00″Sur 95º15′00″W")
+        );
+        assert_eq!(
+            assume_hemispheres("15º30′00″Sur 95º15′00″", south_west, &symbols).as_deref(),
+            Some("15º30′00″Sur 95º15′00″W")
+        );
+    }
+
+    #[test]
+    fn should_find_sign_conflicts() {
+        let symbols = Symbols::default();
+        let resolve = |str| resolve_sign_conflicts(str, &symbols);
+        assert_eq!(resolve("+35.50-170.10/"), None);
+        assert_eq!(resolve("N35.50W170.10/"), None);
+        assert_eq!(resolve("15°30′00.000″N 95°15′00.000″W"), None);
+
+        let (rewritten, conflicts) = resolve("+35.50S-170.10/").unwrap();
+        assert_eq!(rewritten, "S35.50-170.10/");
+        assert_eq!(
+            conflicts,
+            vec![SignConflict {
+                sign: '+',
+                hemisphere: "S".to_string(),
+                negative: true
+            }]
+        );
+        assert!(conflicts[0].is_contradictory());
+
+        let (rewritten, conflicts) = resolve(" 15°30′00″N -95°15′00″W 123.45m").unwrap();
+        assert_eq!(rewritten, " 15°30′00″N 95°15′00″W 123.45m");
+        assert!(!conflicts[0].is_contradictory());
+
+        let (rewritten, _) = resolve("+35.50S170.10/").unwrap();
+        assert_eq!(rewritten, "S35.50+170.10/");
+
+        let (rewritten, conflicts) = resolve("-3530N+17006E+12CRSWGS_84/").unwrap();
+        assert_eq!(rewritten, "N3530E17006+12CRSWGS_84/");
+        assert_eq!(conflicts.len(), 2);
+
+        let symbols = Symbols {
+            degree: vec!["º".to_string()],
+            south: vec!["Sur".to_string()],
+            ..Default::default()
+        };
+        let (rewritten, conflicts) =
+            resolve_sign_conflicts("+15º30′00″Sur 95º15′00″W", &symbols).unwrap();
+        assert_eq!(rewritten, "15º30′00″Sur 95º15′00″W");
+        assert!(conflicts[0].is_contradictory());
+        assert_eq!(
+            resolve_sign_conflicts("+15°30′00″S 95°15′00″W", &symbols),
+            None
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{ISO6709Coord, ISO6709Error};
+use uom::si::f64::Length;
+use uom::si::length::meter;
+
+impl ISO6709Coord {
+    /// The altitude as a length, as ISO6709 gives it in meters
+    /// ```
+    /// # use iso6709parse::{parse, ISO6709Coord};
+    /// use uom::si::length::foot;
+    ///
+    /// let coord: ISO6709Coord = parse("+27.99+086.93+8848CRSWGS_84/").unwrap();
+    /// assert_eq!(coord.altitude_length().unwrap().get::<foot>().round(), 29029.);
+    /// ```
+    pub fn altitude_length(&self) -> Option<Length> {
+        self.altitude.map(Length::new::<meter>)
+    }
+
+    /// Same as [`ISO6709Coord::new`], with the altitude as a length
+    /// ```
+    /// # use iso6709parse::ISO6709Coord;
+    /// use uom::si::f64::Length;
+    /// use uom::si::length::foot;
+    ///
+    /// let coord = ISO6709Coord::new_with_length(15.5, -95.25, Some(Length::new::<foot>(1000.))).unwrap();
+    /// assert_eq!(coord.altitude, Some(304.8));
+    /// ```
+    pub fn new_with_length(
+        lat: f64,
+        lon: f64,
+        altitude: Option<Length>,
+    ) -> Result<ISO6709Coord, ISO6709Error> {
+        ISO6709Coord::new(lat, lon, altitude.map(|altitude| altitude.get::<meter>()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ErrorKind;
+    use uom::si::length::kilometer;
+
+    #[test]
+    fn should_convert_length() {
+        let altitude = Length::new::<kilometer>(-0.5);
+        let coord = ISO6709Coord::new_with_length(35.5, -170.1, Some(altitude)).unwrap();
+        assert_eq!(coord.altitude, Some(-500.));
+        assert_eq!(coord.altitude_length(), Some(altitude));
+        assert_eq!(
+            ISO6709Coord::new(35.5, -170.1, None)
+                .unwrap()
+                .altitude_length(),
+            None
+        );
+
+        let err = ISO6709Coord::new_with_length(35.5, -170.1, Some(Length::new::<meter>(f64::NAN)));
+        assert_eq!(err.unwrap_err().kind(), ErrorKind::NonFinite);
+    }
+}
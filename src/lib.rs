@@ -4,35 +4,255 @@ use nom::sequence::delimited;
 use nom::Finish;
 use nom::IResult;
 use parsers::iso6709;
+use std::borrow::Cow;
 
 pub mod parsers {
-    mod altitude;
+    pub(crate) mod altitude;
     pub(crate) mod common;
     pub mod iso6709;
-    mod latitude;
-    mod longitude;
+    pub(crate) mod latitude;
+    pub(crate) mod longitude;
 }
+#[cfg(feature = "approx")]
+mod approx_eq;
+pub mod ast;
+mod aviation;
+#[cfg(feature = "geo-types")]
+mod bbox;
+mod canonical;
+mod cardinal;
+mod check;
+mod collection;
+pub mod conformance;
+mod convert;
+mod coord3d;
+mod cross_check;
+mod crs;
+#[cfg(feature = "proj")]
+mod datum;
+#[cfg(feature = "decimal")]
+mod decimal;
+mod distance;
+#[cfg(feature = "ecef")]
+mod ecef;
 mod error;
-use crate::error::ISO6709Error;
+#[cfg(any(feature = "sqlx-postgres", feature = "diesel"))]
+mod ewkb;
+mod exiftool;
+mod explain;
+mod exponent;
+mod ext;
+mod format;
+#[cfg(feature = "geoarrow")]
+mod geoarrow;
+#[cfg(feature = "geoid")]
+mod geoid;
+#[cfg(feature = "geojson")]
+mod geojson_types;
+#[cfg(feature = "serde")]
+mod geolocation;
+#[cfg(feature = "xml")]
+mod georss;
+#[cfg(feature = "xml")]
+mod gml;
+mod hemisphere;
+#[cfg(feature = "uom")]
+mod length;
+mod literal;
+mod localize;
+mod location;
+mod morton;
+mod number;
+mod options;
+#[cfg(feature = "polars")]
+mod polars_expr;
+mod position;
+#[cfg(feature = "sqlx-postgres")]
+mod postgis;
+mod precision;
+pub mod prefix;
+mod profile;
+mod repair;
+mod rounding;
+mod sanitize;
+#[cfg(feature = "sea-orm")]
+mod seaorm;
+mod sink;
+#[cfg(feature = "diesel")]
+pub mod sql_types;
+mod symbols;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod tile;
+mod timestamp;
+#[cfg(feature = "validator")]
+mod validation;
+#[cfg(kani)]
+mod verification;
+mod wikipedia;
+#[cfg(feature = "wkt")]
+mod wkt_types;
+#[cfg(feature = "xml")]
+mod xml;
+pub use crate::aviation::{
+    parse_altitude_notation, AltitudeNotation, AltitudeReference, AltitudeUnit,
+};
+#[cfg(feature = "geo-types")]
+pub use crate::bbox::{parse_bbox, parse_bbox_corners};
+pub use crate::canonical::CanonicalCoord;
+pub use crate::check::{is_valid, is_valid_readable, is_valid_string_representation};
+pub use crate::collection::CoordCollection;
+pub use crate::convert::{FromIso6709, ToIso6709};
+pub use crate::coord3d::{parse_with_altitude, ISO6709Coord3D};
+pub use crate::cross_check::cross_check;
+pub use crate::crs::{Crs, CrsRegistry, VerticalDatum};
+#[cfg(feature = "decimal")]
+pub use crate::decimal::parse_decimal;
+pub use crate::error::{ErrorKind, ISO6709Error};
+pub use crate::exiftool::parse_exiftool;
+pub use crate::explain::{explain, AltitudeExplanation, AngleExplanation, Explanation};
+pub use crate::ext::ParseIso6709;
+pub use crate::format::{
+    format_into, format_sequence, format_sequence_into, write_into, Format, FormatSpec,
+};
+#[cfg(feature = "geoarrow")]
+pub use crate::geoarrow::parse_geoarrow;
+#[cfg(feature = "geoid")]
+pub use crate::geoid::GeoidGrid;
+#[cfg(feature = "serde")]
+pub use crate::geolocation::GeolocationCoordinates;
+#[cfg(feature = "xml")]
+pub use crate::georss::parse_georss;
+#[cfg(feature = "xml")]
+pub use crate::gml::{parse_gml, parse_gml_position};
+pub use crate::localize::MessageCatalog;
+pub use crate::location::{parse_location, Location};
+pub use crate::number::{parse_as, CoordNumber};
+pub use crate::options::{AltitudeMode, Hemispheres, Leniency, ParserOptions, Validator};
+#[cfg(feature = "polars")]
+pub use crate::polars_expr::Iso6709Expr;
+pub use crate::position::{Position, Warning};
+pub use crate::precision::{AnglePrecision, AngleUnit, Precision, PrecisionTarget};
+pub use crate::profile::Profile;
+pub use crate::repair::{parse_with_repairs, Repair};
+pub use crate::sanitize::sanitize;
+pub use crate::sink::{parse_into, CoordSink};
+pub use crate::symbols::Symbols;
+pub use crate::timestamp::parse_timestamped;
+#[cfg(feature = "validator")]
+pub use crate::validation::validate_str;
+pub use crate::wikipedia::parse_wikipedia_template;
+#[cfg(feature = "xml")]
+pub use crate::xml::{parse_xml, parse_xml_position};
 
-/// The struct that this library's parses create.  `geo_types` `Point` and `Coord` have the `Into` traits  
-/// implemented for this struct, so using this struct is only needed if you wish to create your own struct or
-/// enum that implements `From<ISO6709Coord>`  
-#[derive(Debug, PartialEq, Clone)]
-pub struct ISO6709Coord {
-    pub lat: f64,
-    pub lon: f64,
-    pub altitude: Option<f64>,
+/// The struct that this library's parses create.  With the default `geo-types` feature, `geo_types` `Point` and
+/// `Coord` have the `Into` traits implemented for this struct, so using this struct is only needed if you wish to
+/// create your own struct or enum that implements `From<ISO6709Coord>` or [`FromIso6709`]  
+/// The values are `f64` by default.  [`parse_as`] reads the values straight into `f32` for memory constrained
+/// storage, or into any other [`CoordNumber`], such as `rust_decimal::Decimal` with the `decimal` feature for more
+/// precision than `f64`.  The other parsers work in `f64`, and `ISO6709Coord<f32>`, `geo_types::Point<f32>` and
+/// `geo_types::Coord<f32>` are narrowed from their result.
+/// ```
+/// # use iso6709parse::{parse, parse_as, ISO6709Coord};
+/// let coord: ISO6709Coord<f32> = parse("N35.50W170.10/").unwrap();
+/// assert_eq!(coord.lon, -170.1f32);
+/// assert_eq!(parse_as::<f32>("N35.50W170.10/").unwrap(), coord);
+/// ```
+/// More fields may be added in the future, so outside of this crate the struct is created with
+/// [`ISO6709Coord::from_parts`] or [`ISO6709Coord::new`] rather than a struct expression.
+#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow)
+)]
+#[cfg_attr(feature = "diesel", diesel(sql_type = diesel::sql_types::Text))]
+#[cfg_attr(feature = "diesel", diesel(sql_type = crate::sql_types::Geometry))]
+#[non_exhaustive]
+pub struct ISO6709Coord<T = f64> {
+    pub lat: T,
+    pub lon: T,
+    pub altitude: Option<T>,
 }
 
-impl From<ISO6709Coord> for geo_types::Point {
-    fn from(value: ISO6709Coord) -> Self {
+impl<T> ISO6709Coord<T> {
+    /// Creates a coordinate from every one of its values, without any checks. See [`ISO6709Coord::new`] to check
+    /// that the values are finite and in range.
+    /// ```
+    /// # use iso6709parse::ISO6709Coord;
+    /// let coord = ISO6709Coord::from_parts(15.5, -95.25, Some(123.45));
+    /// assert_eq!(coord.altitude(), Some(123.45));
+    /// ```
+    pub const fn from_parts(lat: T, lon: T, altitude: Option<T>) -> Self {
+        ISO6709Coord { lat, lon, altitude }
+    }
+}
+
+impl<T: Copy> ISO6709Coord<T> {
+    pub fn lat(&self) -> T {
+        self.lat
+    }
+
+    pub fn lon(&self) -> T {
+        self.lon
+    }
+
+    pub fn altitude(&self) -> Option<T> {
+        self.altitude
+    }
+
+    /// The longitude, latitude and altitude, in the x, y, z order of `geo_types` and GeoJSON
+    /// ```
+    /// # use iso6709parse::{parse, ISO6709Coord};
+    /// let coord: ISO6709Coord = parse("+35.50-170.10/").unwrap();
+    /// assert_eq!(coord.to_xyz(), (-170.1, 35.5, None));
+    /// ```
+    pub fn to_xyz(&self) -> (T, T, Option<T>) {
+        (self.lon, self.lat, self.altitude)
+    }
+}
+
+impl ISO6709Coord {
+    /// Creates a coordinate from a latitude and longitude in radians, without any checks, as
+    /// [`ISO6709Coord::from_parts`] does. The altitude is in meters as always.
+    /// ```
+    /// # use iso6709parse::ISO6709Coord;
+    /// use std::f64::consts::FRAC_PI_4;
+    ///
+    /// let coord = ISO6709Coord::from_radians(FRAC_PI_4, -FRAC_PI_4, None);
+    /// assert_eq!((coord.lat, coord.lon), (45., -45.));
+    /// assert_eq!(coord.lat_radians(), FRAC_PI_4);
+    /// ```
+    pub fn from_radians(lat: f64, lon: f64, altitude: Option<f64>) -> Self {
+        ISO6709Coord::from_parts(lat.to_degrees(), lon.to_degrees(), altitude)
+    }
+
+    /// The latitude in radians, as the trigonometric functions take it
+    pub fn lat_radians(&self) -> f64 {
+        self.lat.to_radians()
+    }
+
+    /// The longitude in radians, as the trigonometric functions take it
+    pub fn lon_radians(&self) -> f64 {
+        self.lon.to_radians()
+    }
+}
+
+/// The altitude is left out, as a `Point` has none; see [`ISO6709Coord::to_point_and_altitude`] to keep it.
+/// ```
+/// # use iso6709parse::parse;
+/// let point: geo_types::Point = parse("N35.50W170.10/").unwrap();
+/// assert_eq!(point, geo_types::Point::new(-170.1, 35.5));
+/// ```
+#[cfg(feature = "geo-types")]
+impl<T: geo_types::CoordNum> From<ISO6709Coord<T>> for geo_types::Point<T> {
+    fn from(value: ISO6709Coord<T>) -> Self {
         geo_types::Point::new(value.lon, value.lat)
     }
 }
 
-impl From<ISO6709Coord> for geo_types::Coord {
-    fn from(value: ISO6709Coord) -> Self {
+#[cfg(feature = "geo-types")]
+impl<T: geo_types::CoordNum> From<ISO6709Coord<T>> for geo_types::Coord<T> {
+    fn from(value: ISO6709Coord<T>) -> Self {
         geo_types::Coord {
             x: value.lon,
             y: value.lat,
@@ -40,26 +260,75 @@ impl From<ISO6709Coord> for geo_types::Coord {
     }
 }
 
-/// Parses a string in ISO6709 human readable format into any struct that implements `From<ISO6709Coord>`.  
+#[cfg(feature = "geo-types")]
+impl<T: geo_types::CoordNum> ISO6709Coord<T> {
+    /// Converts to a `geo_types::Point` without losing the altitude, which the `From` conversions leave out
+    /// ```
+    /// # use iso6709parse::{parse, ISO6709Coord};
+    /// let coord: ISO6709Coord = parse("+35.50-170.10+12CRSWGS_84/").unwrap();
+    /// let (point, altitude) = coord.to_point_and_altitude();
+    /// assert_eq!((point, altitude), (geo_types::Point::new(-170.1, 35.5), Some(12.)));
+    /// ```
+    pub fn to_point_and_altitude(&self) -> (geo_types::Point<T>, Option<T>) {
+        (geo_types::Point::new(self.lon, self.lat), self.altitude)
+    }
+}
+
+impl From<ISO6709Coord> for ISO6709Coord<f32> {
+    fn from(value: ISO6709Coord) -> Self {
+        ISO6709Coord {
+            lat: value.lat as f32,
+            lon: value.lon as f32,
+            altitude: value.altitude.map(|altitude| altitude as f32),
+        }
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl From<ISO6709Coord> for geo_types::Point<f32> {
+    fn from(value: ISO6709Coord) -> Self {
+        ISO6709Coord::<f32>::from(value).into()
+    }
+}
+
+#[cfg(feature = "geo-types")]
+impl From<ISO6709Coord> for geo_types::Coord<f32> {
+    fn from(value: ISO6709Coord) -> Self {
+        ISO6709Coord::<f32>::from(value).into()
+    }
+}
+
+/// Parses a string in ISO6709 human readable format into any struct that implements [`FromIso6709`].  
 /// Using a normal single quote `'` in place of `′`, and a double quote `"` in place of `″` is acceptable.  
 /// An error will be returned if the resulting coordinate exceeds 90° for latitude and 180° for longitude in either direction.  
 /// ```
-/// # use iso6709parse::parse_readable;
+/// # use iso6709parse::{parse_readable, ISO6709Coord};
 /// let str = "15°30′00.000″N 95°15′00.000″W";
-/// let geo_coord = parse_readable::<geo_types::Coord>(str).unwrap();
-/// assert_eq!(geo_coord.x, -95.25);
-/// assert_eq!(geo_coord.y, 15.5);
+/// let coord = parse_readable::<ISO6709Coord>(str).unwrap();
+/// assert_eq!(coord.lon, -95.25);
+/// assert_eq!(coord.lat, 15.5);
 /// ```
 pub fn parse_readable<T>(str: &str) -> Result<T, ISO6709Error>
 where
-    ISO6709Coord: Into<T>,
+    T: FromIso6709,
 {
+    parse_readable_coord(str).map(T::from_iso6709)
+}
+
+/// Same as [`parse_readable`], without being generic. See [`parse_coord`].
+pub fn parse_readable_coord(str: &str) -> Result<ISO6709Coord, ISO6709Error> {
+    read_readable(str, Symbols::standard()).map_err(|err| err.with_input(str))
+}
+
+fn read_readable(str: &str, symbols: &Symbols) -> Result<ISO6709Coord, ISO6709Error> {
+    exponent::reject_exponents(str)?;
     let (_, ((lat, lon), altitude)) =
-        trim(iso6709::human_readable::latlong_altitude_option_parser)(str).finish()?;
-    Ok(ISO6709Coord { lat, lon, altitude }.into())
+        trim(iso6709::human_readable::latlong_altitude_option_parser_with(symbols))(str)
+            .finish()?;
+    Ok(ISO6709Coord::unsigned_zero(lat, lon, altitude))
 }
 
-/// Parses a string in ISO6709 string representation format into any struct that implements `From<ISO6709Coord>`  
+/// Parses a string in ISO6709 string representation format into any struct that implements [`FromIso6709`]  
 /// Supports the formats:  
 /// DD.DDD  
 /// DDMM.MMMM  
@@ -68,44 +337,332 @@ where
 /// NOTE: digits less than 10 in the degree, minutes, or seconds column need to have a leading zero, as is IAW ISO6709  
 /// An error will be returned if the resulting coordinate exceeds 90° for latitude and 180° for longitude in either direction.  
 /// ```
-/// # use iso6709parse::parse_string_representation;
+/// # use iso6709parse::{parse_string_representation, ISO6709Coord};
 /// let str = "N35.50W170.10+8712CRSWGS_85/";
-/// let geo_coord = parse_string_representation::<geo_types::Coord>(str).unwrap();
-/// assert_eq!(geo_coord.x, -170.1);
-/// assert_eq!(geo_coord.y, 35.5);
+/// let coord = parse_string_representation::<ISO6709Coord>(str).unwrap();
+/// assert_eq!(coord.lon, -170.1);
+/// assert_eq!(coord.lat, 35.5);
 /// ```
 pub fn parse_string_representation<T>(str: &str) -> Result<T, ISO6709Error>
 where
-    ISO6709Coord: Into<T>,
+    T: FromIso6709,
 {
+    parse_string_representation_coord(str).map(T::from_iso6709)
+}
+
+/// Same as [`parse_string_representation`], without being generic. See [`parse_coord`].
+pub fn parse_string_representation_coord(str: &str) -> Result<ISO6709Coord, ISO6709Error> {
+    read_string_representation(str).map_err(|err| err.with_input(str))
+}
+
+fn read_string_representation(str: &str) -> Result<ISO6709Coord, ISO6709Error> {
+    exponent::reject_exponents(str)?;
     let (_, ((lat, lon), altitude)) =
         trim(iso6709::string_expression::latlong_altitude_option_parser)(str).finish()?;
-    Ok(ISO6709Coord { lat, lon, altitude }.into())
+    Ok(ISO6709Coord::unsigned_zero(lat, lon, altitude))
 }
 
 /// Parse either of the two different formats.  
+/// Numbers are limited to 40 digits, as no more can be meaningful for an `f64`. Longer runs of digits fail early
+/// with [`ErrorKind::TooManyDigits`], so hostile input cannot cause excessive work.
+/// A latitude, longitude or altitude written with an exponent, such as `1e2`, fails with
+/// [`ErrorKind::ScientificNotation`] instead of being partly read; see [`ParserOptions::allow_exponent`] to accept
+/// them. Numbers in the CRS or in text after the coordinate may have one.
+/// An altitude in the string representation is only read when it is followed by a CRS, as ISO6709 requires, so
+/// `+35.50-170.10+12/` is read without its altitude. [`parse_with_options`] fails on it instead, see
+/// [`ParserOptions::require_crs`].
+/// None of the parse functions panic, whatever the input; the `no_panic` fuzz target checks this.
+/// Successful parses do not allocate, apart from a CRS in [`Position::crs`] that is not one of the known ones, and
+/// text after the coordinate with a number written with an exponent, which is read to tell it from the coordinate.
 /// ```rust
-///use iso6709parse::parse;
+///use iso6709parse::{parse, ISO6709Coord};
 ///
-///let coord: geo_types::Coord = parse("N35.50W170.10+8712CRSWGS_85/").unwrap();
-///assert_eq!(coord.y, 35.5);
+///let coord: ISO6709Coord = parse("N35.50W170.10+8712CRSWGS_85/").unwrap();
+///assert_eq!(coord.lat, 35.5);
 ///
 ///```
 pub fn parse<T>(str: &str) -> Result<T, ISO6709Error>
 where
-    ISO6709Coord: Into<T>,
+    T: FromIso6709,
+{
+    parse_coord(str).map(T::from_iso6709)
+}
+
+/// Same as [`parse`], without being generic. The generic parse functions are thin wrappers around these, so all of
+/// the parsing is compiled once however many types are parsed into; call these directly to keep even the wrappers
+/// out of a binary.
+/// ```
+/// # use iso6709parse::parse_coord;
+/// let coord = parse_coord("N35.50W170.10/").unwrap();
+/// assert_eq!((coord.lat, coord.lon), (35.5, -170.1));
+/// ```
+pub fn parse_coord(str: &str) -> Result<ISO6709Coord, ISO6709Error> {
+    read(str).map_err(|err| err.with_input(str))
+}
+
+fn read(str: &str) -> Result<ISO6709Coord, ISO6709Error> {
+    exponent::reject_exponents(str)?;
+    match try_readable(str, Symbols::standard())? {
+        Some((coord, rest)) => {
+            Ok(aviation::altitude_suffix(coord, rest).map_or(coord, |(coord, _, _)| coord))
+        }
+        None => read_string_representation(str),
+    }
+}
+
+/// Parse either of the two different formats, with additional checks set in `options`.
+/// ```rust
+///use iso6709parse::{parse_with_options, CrsRegistry, ErrorKind, ISO6709Coord, ParserOptions};
+///
+///let options = ParserOptions {
+///    crs_registry: Some(CrsRegistry::default()),
+///    ..Default::default()
+///};
+///let coord: ISO6709Coord = parse_with_options("N35.50W170.10+8712CRSWGS_84/", &options).unwrap();
+///assert_eq!(coord.lat, 35.5);
+///
+///let err = parse_with_options::<ISO6709Coord>("N35.50W170.10+8712CRSWGS_85/", &options).unwrap_err();
+///assert_eq!(err.kind(), ErrorKind::UnknownCrs);
+///```
+pub fn parse_with_options<T>(str: &str, options: &ParserOptions) -> Result<T, ISO6709Error>
+where
+    T: FromIso6709,
 {
-    match parse_readable(str) {
-        Ok(x) => Ok(x),
-        Err(_) => parse_string_representation(str),
+    parse_position(str, options).map(|position| T::from_iso6709(position.coord))
+}
+
+/// Parse either of the two different formats into a [`Position`], which keeps the CRS of the string representation.
+/// EPSG codes such as `EPSG:4979` are read into [`Crs::Epsg`].
+/// ```rust
+///use iso6709parse::{parse_position, Crs, ParserOptions};
+///
+///let position = parse_position("N35.50W170.10+8712CRSEPSG:4979/", &ParserOptions::default()).unwrap();
+///assert_eq!(position.coord.altitude, Some(8712.));
+///assert_eq!(position.crs, Some(Crs::Epsg(4979)));
+///```
+pub fn parse_position(str: &str, options: &ParserOptions) -> Result<Position, ISO6709Error> {
+    read_position(str, options).map_err(|err| err.with_input(str))
+}
+
+fn read_position(str: &str, options: &ParserOptions) -> Result<Position, ISO6709Error> {
+    let input = str;
+    if let Some(max) = options.max_input_len.filter(|&max| str.len() > max) {
+        return Err(ISO6709Error::new(
+            ErrorKind::InputTooLong,
+            format!(
+                "input is {} bytes, more than the limit of {}",
+                str.len(),
+                max
+            ),
+        ));
+    }
+    let sanitized = match options.leniency {
+        Leniency::Lenient => sanitize(str),
+        _ => Cow::Borrowed(str),
+    };
+    let str = match options.leniency {
+        Leniency::Lenient => sanitize::unwrap(sanitized.as_ref()),
+        _ => sanitized.as_ref(),
+    };
+    let decimals = if options.decimal_comma {
+        sanitize::decimal_commas(str)
+    } else {
+        Cow::Borrowed(str)
+    };
+    let str = decimals.as_ref();
+    let expanded = if options.allow_exponent {
+        exponent::expand_exponents(str)
+    } else {
+        exponent::reject_exponents_with(str, &options.symbols, options.leniency)?;
+        Cow::Borrowed(str)
+    };
+    let str = expanded.as_ref();
+    // Otherwise, conflicts are only looked for once the input fails to parse, so that they are not found in text
+    // after the coordinate
+    let resolved = match options.leniency {
+        Leniency::Lenient => hemisphere::resolve_sign_conflicts(str, &options.symbols),
+        _ => None,
+    };
+    let sign_conflict = resolved.is_some();
+    let str = resolved.as_ref().map_or(str, |(input, _)| input.as_str());
+    let assumed = options
+        .default_hemispheres
+        .and_then(|hemispheres| hemisphere::assume_hemispheres(str, hemispheres, &options.symbols));
+    let str = assumed.as_deref().unwrap_or(str);
+    let spelled_out = match options.leniency {
+        Leniency::Lenient => cardinal::spelled_out(str)?,
+        _ => None,
+    };
+    let readable = match spelled_out {
+        Some(_) => None,
+        None => try_readable(str, &options.symbols)?,
+    };
+    let (mut position, rest) = match (spelled_out, readable) {
+        (Some(coord), _) => (Position::new(coord, None), ""),
+        (None, Some((coord, rest))) => match aviation::altitude_suffix(coord, rest) {
+            Some((coord, notation, rest)) => {
+                let mut position = Position::new(coord, crs::datum_suffix(rest, false));
+                position.altitude_notation = Some(notation);
+                let rest = if position.crs.is_some() { "" } else { rest };
+                (position, rest)
+            }
+            None => {
+                let crs = crs::datum_suffix(rest, coord.altitude.is_some());
+                let rest = match crs {
+                    Some(_) => "",
+                    // The unit of the altitude
+                    None if coord.altitude.is_some() => {
+                        rest.trim_start().trim_start_matches(char::is_alphabetic)
+                    }
+                    None => rest,
+                };
+                (Position::new(coord, crs), rest)
+            }
+        },
+        (None, None) => parse_string_position(str, options)
+            .map_err(|err| sign_conflict_error(str, &options.symbols).unwrap_or(err))?,
+    };
+    // Offsets into a rewritten input would not point at the same text in the original, while stripping wrappers
+    // leaves a slice of it
+    if let Some(offset) = (str.as_ptr() as usize)
+        .checked_sub(input.as_ptr() as usize)
+        .filter(|offset| offset + str.len() <= input.len())
+    {
+        let start = offset + str.len() - str.trim_start().len();
+        let end = offset + str[..str.len() - rest.len()].trim_end().len();
+        position.span = Some(start..end);
+    }
+    position.precision = ast::parse_ast_prefix_with(str, &options.symbols, options.leniency)
+        .map(|ast| Precision::from_ast(str, &ast));
+    match options.altitude {
+        AltitudeMode::Keep => {}
+        AltitudeMode::Ignore => {
+            position.coord.altitude = None;
+            position.vertical_datum = None;
+            position.altitude_notation = None;
+            if let Some(precision) = &mut position.precision {
+                precision.altitude = None;
+            }
+        }
+        AltitudeMode::Reject => {
+            if position.coord.altitude.is_some() {
+                return Err(ISO6709Error::new(
+                    ErrorKind::UnexpectedAltitude,
+                    "the coordinate has an altitude, but only 2D coordinates are accepted",
+                ));
+            }
+        }
+    }
+    #[cfg(feature = "geo-types")]
+    if let Some(region) = &options.expected_region {
+        if position::likely_swapped(&position.coord, region) {
+            position.warnings.push(Warning::LikelySwapped);
+        }
+    }
+    position.hemisphere_assumed = assumed.is_some();
+    if sign_conflict {
+        position.warnings.push(Warning::SignConflict);
+    }
+    if let Some(target) = &options.precision_target {
+        if position.loses_precision(target) {
+            position.warnings.push(Warning::PrecisionLoss);
+        }
+    }
+    if let Some(validator) = &options.validator {
+        validator
+            .validate(&position.coord)
+            .map_err(|message| ISO6709Error::new(ErrorKind::Rejected, message))?;
+    }
+    position.assume_crs(options.default_crs.as_ref());
+    Ok(position)
+}
+
+/// The error for the first sign along with a hemisphere letter in `str`, which explains why it failed to parse
+/// better than where the parser gave up does
+fn sign_conflict_error(str: &str, symbols: &Symbols) -> Option<ISO6709Error> {
+    let (_, conflicts) = hemisphere::resolve_sign_conflicts(str, symbols)?;
+    let conflict = &conflicts[0];
+    Some(ISO6709Error::new(
+        ErrorKind::SignConflict,
+        format!(
+            "{} sign `{}` along with hemisphere `{}`",
+            if conflict.is_contradictory() {
+                "contradictory"
+            } else {
+                "redundant"
+            },
+            conflict.sign,
+            conflict.hemisphere
+        ),
+    ))
+}
+
+/// Reads the readable format, or gives `None` if `str` may be in the string representation instead. No error is
+/// built for that case, so that falling back to the string representation does not allocate. The rest of the input,
+/// such as a unit or datum, is given along with the coordinate.
+fn try_readable<'a>(
+    str: &'a str,
+    symbols: &'a Symbols,
+) -> Result<Option<(ISO6709Coord, &'a str)>, ISO6709Error> {
+    match trim(iso6709::human_readable::latlong_altitude_option_parser_with(symbols))(str) {
+        Ok((rest, ((lat, lon), altitude))) => Ok(Some((
+            ISO6709Coord::unsigned_zero(lat, lon, altitude),
+            rest,
+        ))),
+        // The string representation would fail with too many digits too
+        Err(nom::Err::Error(err) | nom::Err::Failure(err))
+            if err.code == nom::error::ErrorKind::TooLarge =>
+        {
+            Err(err.into())
+        }
+        Err(_) => Ok(None),
+    }
+}
+
+/// Gives the position along with the rest of the input after it
+fn parse_string_position<'a>(
+    str: &'a str,
+    options: &'a ParserOptions,
+) -> Result<(Position, &'a str), ISO6709Error> {
+    let (rest, ((lat, lon), altitude)) =
+        trim(iso6709::string_expression::latlong_altitude_crs_option_parser_with(options))(str)
+            .finish()?;
+    if matches!(altitude, Some((_, None))) && options.requires_crs() {
+        return Err(ISO6709Error::new(
+            ErrorKind::MissingCrs,
+            "an altitude must be followed by a CRS",
+        ));
+    }
+    let crs = altitude.and_then(|(_, crs)| crs).map(str::trim_end);
+    if let (Some(registry), Some(crs)) = (&options.crs_registry, crs) {
+        if !registry.contains(crs) {
+            return Err(ISO6709Error::new(
+                ErrorKind::UnknownCrs,
+                format!("unknown CRS `{}`", crs),
+            ));
+        }
+    }
+    let coord = ISO6709Coord::unsigned_zero(lat, lon, altitude.map(|(altitude, _)| altitude));
+    // The solidus that ends a coordinate without an altitude is not read by the parser
+    let rest = rest.strip_prefix('/').unwrap_or(rest);
+    Ok((Position::new(coord, crs.map(Crs::from)), rest))
+}
+
+impl ISO6709Coord {
+    /// Builds a parsed coordinate with `-0.0` turned into `0.0`, so `S00.00` and `N00.00` give identical values
+    fn unsigned_zero(lat: f64, lon: f64, altitude: Option<f64>) -> ISO6709Coord {
+        // Adding positive zero only changes the sign of a negative zero
+        ISO6709Coord {
+            lat: lat + 0.,
+            lon: lon + 0.,
+            altitude: altitude.map(|altitude| altitude + 0.),
+        }
     }
 }
 
-fn trim<'a, F: 'a, O, E: ParseError<&'a str>>(
-    inner: F,
-) -> impl FnMut(&'a str) -> IResult<&'a str, O, E>
+fn trim<'a, F, O, E: ParseError<&'a str>>(inner: F) -> impl FnMut(&'a str) -> IResult<&'a str, O, E>
 where
-    F: Fn(&'a str) -> IResult<&'a str, O, E>,
+    F: 'a + Fn(&'a str) -> IResult<&'a str, O, E>,
 {
     delimited(multispace0, inner, multispace0)
 }
@@ -114,6 +671,24 @@ where
 mod tests {
     use super::*;
 
+    #[test]
+    fn should_parse_without_generics() {
+        let readable = "15°30′00.000″N 95°15′00.000″W";
+        let string = "N35.50W170.10+8712CRSWGS_84/";
+        assert_eq!(parse_coord(readable), parse::<ISO6709Coord>(readable));
+        assert_eq!(parse_coord(string), parse::<ISO6709Coord>(string));
+        assert_eq!(parse_readable_coord(readable), parse_readable(readable));
+        assert_eq!(
+            parse_string_representation_coord(string),
+            parse_string_representation(string)
+        );
+        assert!(parse_readable_coord(string).is_err());
+        assert_eq!(
+            parse_coord("N95.50W170.10/").unwrap_err().input(),
+            Some("N95.50W170.10/")
+        );
+    }
+
     #[test]
     fn should_parse_readable_format() {
         let mut expected = ISO6709Coord {
@@ -123,17 +698,19 @@ mod tests {
         };
 
         let coord = "15°30′00.000″N 95°15′00.000″W";
-        assert_eq!(parse_readable::<ISO6709Coord>(coord), Ok(expected.clone()));
+        assert_eq!(parse_readable::<ISO6709Coord>(coord), Ok(expected));
         let coord = " 15°30′00.000″N 95°15′00.000″W";
-        assert_eq!(parse_readable::<ISO6709Coord>(coord), Ok(expected.clone()));
+        assert_eq!(parse_readable::<ISO6709Coord>(coord), Ok(expected));
         let coord = " 15°30′00.000″N 95°15′00.000″W ";
-        assert_eq!(parse_readable::<ISO6709Coord>(coord), Ok(expected.clone()));
+        assert_eq!(parse_readable::<ISO6709Coord>(coord), Ok(expected));
 
         expected.altitude = Some(123.45);
         let coord = "15°30′00.000″N 95°15′00.000″W 123.45m";
-        assert_eq!(parse_readable::<ISO6709Coord>(coord), Ok(expected.clone()));
+        assert_eq!(parse_readable::<ISO6709Coord>(coord), Ok(expected));
         let coord = " 15°30′00.000″N 95°15′00.000″W 123.45m ";
-        assert_eq!(parse_readable::<ISO6709Coord>(coord), Ok(expected.clone()));
+        assert_eq!(parse_readable::<ISO6709Coord>(coord), Ok(expected));
+        let coord = "15°30′00.000″N 95°15′00.000″W 123.45 m";
+        assert_eq!(parse_readable::<ISO6709Coord>(coord), Ok(expected));
     }
 
     #[test]
@@ -147,19 +724,19 @@ mod tests {
         let coord = "N35.50W170.10/";
         assert_eq!(
             parse_string_representation::<ISO6709Coord>(coord),
-            Ok(expected.clone())
+            Ok(expected)
         );
         let coord = " N35.50W170.10/ ";
         assert_eq!(
             parse_string_representation::<ISO6709Coord>(coord),
-            Ok(expected.clone())
+            Ok(expected)
         );
 
         expected.altitude = Some(8712.);
         let coord = "N35.50W170.10+8712CRSWGS_85/";
         assert_eq!(
             parse_string_representation::<ISO6709Coord>(coord),
-            Ok(expected.clone())
+            Ok(expected)
         );
     }
 
@@ -171,7 +748,7 @@ mod tests {
             altitude: None,
         };
         let coord = "15°30′00.000″N 95°15′00.000″W";
-        assert_eq!(parse::<ISO6709Coord>(coord), Ok(expected.clone()));
+        assert_eq!(parse::<ISO6709Coord>(coord), Ok(expected));
 
         let expected = ISO6709Coord {
             lat: 35.5,
@@ -180,6 +757,771 @@ mod tests {
         };
 
         let coord = "N35.50W170.10/";
-        assert_eq!(parse::<ISO6709Coord>(coord), Ok(expected.clone()));
+        assert_eq!(parse::<ISO6709Coord>(coord), Ok(expected));
+    }
+
+    #[test]
+    fn should_parse_f32() {
+        let expected = ISO6709Coord {
+            lat: 35.5f32,
+            lon: -170.1f32,
+            altitude: Some(8712f32),
+        };
+        let coord = "N35.50W170.10+8712CRSWGS_84/";
+        assert_eq!(parse::<ISO6709Coord<f32>>(coord), Ok(expected));
+    }
+
+    #[test]
+    fn should_convert_radians() {
+        let coord: ISO6709Coord = parse("-4530.00+17006.00+12CRSWGS_84/").unwrap();
+        assert_eq!(coord.lat_radians(), (-45.5f64).to_radians());
+        assert_eq!(coord.lon_radians(), (170.1f64).to_radians());
+        let back =
+            ISO6709Coord::from_radians(coord.lat_radians(), coord.lon_radians(), coord.altitude);
+        assert!((back.lat - coord.lat).abs() < 1e-12 && (back.lon - coord.lon).abs() < 1e-12);
+        assert_eq!(back.altitude, Some(12.));
+    }
+
+    #[test]
+    #[cfg(feature = "geo-types")]
+    fn should_parse_geo_types_f32() {
+        let coord = "N35.50W170.10+8712CRSWGS_84/";
+        let point: geo_types::Point<f32> = parse(coord).unwrap();
+        assert_eq!(point, geo_types::Point::new(-170.1f32, 35.5f32));
+        let coord: geo_types::Coord<f32> = parse("15°30′00.000″N 95°15′00.000″W").unwrap();
+        assert_eq!(coord, geo_types::coord! { x: -95.25f32, y: 15.5f32 });
+    }
+
+    #[test]
+    fn should_validate_crs() {
+        let options = ParserOptions {
+            crs_registry: Some(CrsRegistry::default().with("NAD_83")),
+            ..Default::default()
+        };
+        let expected = ISO6709Coord {
+            lat: 35.5,
+            lon: -170.1,
+            altitude: Some(8712.),
+        };
+
+        let coord = "N35.50W170.10+8712CRSWGS_84_3D/";
+        assert_eq!(parse_with_options(coord, &options), Ok(expected));
+        let coord = " N35.50W170.10+8712CRSNAD_83 ";
+        assert_eq!(parse_with_options(coord, &options), Ok(expected));
+        let coord = "N35.50W170.10/";
+        assert!(parse_with_options::<ISO6709Coord>(coord, &options).is_ok());
+        let coord = "15°30′00.000″N 95°15′00.000″W 123.45m";
+        assert!(parse_with_options::<ISO6709Coord>(coord, &options).is_ok());
+
+        let coord = "N35.50W170.10+8712CRSWGS_85/";
+        let err = parse_with_options::<ISO6709Coord>(coord, &options).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnknownCrs);
+        assert_eq!(
+            parse_with_options::<ISO6709Coord>(coord, &ParserOptions::default()),
+            Ok(expected)
+        );
+    }
+
+    #[test]
+    fn should_parse_position_crs() {
+        let options = ParserOptions::default();
+        let position = parse_position("N35.50W170.10+8712CRSWGS_84/", &options).unwrap();
+        assert_eq!(position.crs, Some(Crs::Wgs84));
+        let position = parse_position("N35.50W170.10+8712CRSEPSG:4326/", &options).unwrap();
+        assert_eq!(position.crs, Some(Crs::Epsg(4326)));
+        assert!(!position.crs_assumed);
+        let position = parse_position("N35.50W170.10/", &options).unwrap();
+        assert_eq!(position.crs, Some(Crs::Wgs84));
+        assert!(position.crs_assumed);
+        let position = parse_position("15°30′00.000″N 95°15′00.000″W", &options).unwrap();
+        assert_eq!(position.crs, Some(Crs::Wgs84));
+        assert!(position.crs_assumed);
+
+        let options = ParserOptions {
+            default_crs: Some(Crs::Wgs84_3D),
+            leniency: Leniency::Lenient,
+            ..Default::default()
+        };
+        let position = parse_position("N35.50W170.10+12/", &options).unwrap();
+        assert_eq!(position.crs, Some(Crs::Wgs84_3D));
+        assert_eq!(position.vertical_datum, Some(VerticalDatum::Ellipsoidal));
+        let options = ParserOptions {
+            default_crs: None,
+            ..Default::default()
+        };
+        let position = parse_position("N35.50W170.10/", &options).unwrap();
+        assert_eq!(position.crs, None);
+        assert!(!position.crs_assumed);
+
+        let options = ParserOptions {
+            crs_registry: Some(CrsRegistry::default()),
+            ..Default::default()
+        };
+        assert!(parse_position("N35.50W170.10+8712CRSEPSG:4979/", &options).is_ok());
+        assert!(parse_position("N35.50W170.10+8712CRSEPSG:2193/", &options).is_err());
+    }
+
+    #[test]
+    fn should_capture_datum_suffix() {
+        let options = ParserOptions::default();
+        let position = parse_position("50°40′46″N 95°48′27″W (WGS84)", &options).unwrap();
+        assert_eq!(position.crs, Some(Crs::Wgs84));
+        assert!(!position.crs_assumed);
+        let position = parse_position("50°40′46″N 95°48′27″W ED50", &options).unwrap();
+        assert_eq!(position.crs, Some(Crs::Epsg(4230)));
+        let position = parse_position("50°40′46″N 95°48′27″W 123.45 m (NAD 27)", &options).unwrap();
+        assert_eq!(position.coord.altitude, Some(123.45));
+        assert_eq!(position.crs, Some(Crs::Epsg(4267)));
+        let position = parse_position("50°40′46″N 95°48′27″W 123.45m", &options).unwrap();
+        assert_eq!(position.crs, Some(Crs::Wgs84));
+        assert!(position.crs_assumed);
+        assert_eq!(
+            parse::<ISO6709Coord>("50°40′46″N 95°48′27″W (ED50)"),
+            parse::<ISO6709Coord>("50°40′46″N 95°48′27″W")
+        );
+    }
+
+    #[test]
+    fn should_give_matched_span() {
+        let options = ParserOptions::default();
+        for (input, matched) in [
+            ("N35.50W170.10/", "N35.50W170.10/"),
+            (
+                " 15°30′00″N 95°15′00″W 123.45 m near the dam",
+                "15°30′00″N 95°15′00″W 123.45 m",
+            ),
+            (
+                "15°30′00″N 95°15′00″W (ED50) ",
+                "15°30′00″N 95°15′00″W (ED50)",
+            ),
+            (
+                "15°30′00″N 95°15′00″W FL350, climbing",
+                "15°30′00″N 95°15′00″W FL350",
+            ),
+            ("15°30′00″N 95°15′00″W, climbing", "15°30′00″N 95°15′00″W"),
+        ] {
+            let position = parse_position(input, &options).unwrap();
+            assert_eq!(position.matched(input), Some(matched), "{}", input);
+        }
+
+        let options = ParserOptions {
+            leniency: Leniency::Lenient,
+            ..Default::default()
+        };
+        let position = parse_position("N35.50W170.10/", &options).unwrap();
+        assert_eq!(position.span, Some(0..14));
+        let position = parse_position("-15°30′00″S 95°15′00″W", &options).unwrap();
+        assert_eq!(position.span, None);
+    }
+
+    #[test]
+    fn should_read_aviation_altitudes() {
+        let options = ParserOptions::default();
+        let position = parse_position("50°40′46″N 95°48′27″W FL350", &options).unwrap();
+        assert_eq!(position.coord.altitude, Some(10668.));
+        assert_eq!(
+            position.altitude_notation.unwrap().reference,
+            Some(AltitudeReference::FlightLevel)
+        );
+        let position =
+            parse_position("50°40′46″N 95°48′27″W 3500 ft MSL (NAD83)", &options).unwrap();
+        assert_eq!(position.coord.altitude, Some(1066.8));
+        assert_eq!(position.altitude_notation.unwrap().unit, AltitudeUnit::Feet);
+        assert_eq!(position.crs, Some(Crs::Epsg(4269)));
+        let position = parse_position("50°40′46″N 95°48′27″W 123.45m", &options).unwrap();
+        assert_eq!(position.coord.altitude, Some(123.45));
+        assert_eq!(position.altitude_notation, None);
+
+        let coord: ISO6709Coord = parse("50°40′46″N 95°48′27″W 1200 AGL").unwrap();
+        assert_eq!(coord.altitude, Some(365.76));
+    }
+
+    #[test]
+    fn should_parse_vertical_datum() {
+        let options = ParserOptions::default();
+        let position = parse_position("N35.50W170.10+8712CRSWGS_84_3D/", &options).unwrap();
+        assert_eq!(position.vertical_datum, Some(VerticalDatum::Ellipsoidal));
+        let position = parse_position("N35.50W170.10+8712CRSEPSG:4326+3855/", &options).unwrap();
+        assert_eq!(position.vertical_datum, Some(VerticalDatum::Egm2008));
+        let position = parse_position("N35.50W170.10+8712CRSWGS_84/", &options).unwrap();
+        assert_eq!(position.vertical_datum, None);
+    }
+
+    #[test]
+    fn should_track_precision() {
+        let options = ParserOptions::default();
+        let position = parse_position("+35.50-170.1234567/", &options).unwrap();
+        let precision = position.precision.unwrap();
+        assert_eq!(precision.lat.decimals, 2);
+        assert_eq!(precision.lon.decimals, 7);
+
+        let position = parse_position("15°30′00.0″N 95°15′00″W 123.45m", &options).unwrap();
+        let precision = position.precision.unwrap();
+        assert_eq!(precision.lat.unit, AngleUnit::Seconds);
+        assert_eq!(precision.lat.decimals, 1);
+        assert_eq!(precision.altitude, Some(2));
+    }
+
+    #[test]
+    #[cfg(feature = "geo-types")]
+    fn should_warn_likely_swapped() {
+        let coord = "+02.35+048.86/";
+        let position = parse_position(coord, &ParserOptions::default()).unwrap();
+        assert!(position.warnings.is_empty());
+
+        let options = ParserOptions {
+            expected_region: Some(parse_bbox("+35-010/+70+030/").unwrap()),
+            ..Default::default()
+        };
+        let position = parse_position(coord, &options).unwrap();
+        assert_eq!(position.warnings, vec![Warning::LikelySwapped]);
+        let position = parse_position("+48.86+002.35/", &options).unwrap();
+        assert!(position.warnings.is_empty());
+        // Outside of the region either way
+        let position = parse_position("-33.87+151.21/", &options).unwrap();
+        assert!(position.warnings.is_empty());
+    }
+
+    #[test]
+    fn should_accept_crs_tag_case_when_lenient() {
+        let coord = "N35.50W170.10+8712crsWGS_84/";
+        let position = parse_position(coord, &ParserOptions::default()).unwrap();
+        assert_eq!(position.coord.altitude, None);
+        assert!(position.crs_assumed);
+
+        let options = ParserOptions {
+            leniency: Leniency::Lenient,
+            ..Default::default()
+        };
+        let position = parse_position(coord, &options).unwrap();
+        assert_eq!(position.coord.altitude, Some(8712.));
+        assert_eq!(position.crs, Some(Crs::Wgs84));
+    }
+
+    #[test]
+    fn should_parse_zero_without_sign() {
+        let north: ISO6709Coord = parse("N00.00E000.00+0CRSWGS_84/").unwrap();
+        let south: ISO6709Coord = parse("S00.00W000.00-0CRSWGS_84/").unwrap();
+        assert_eq!(CanonicalCoord(north), CanonicalCoord(south));
+        assert!(south.lat.is_sign_positive());
+        assert!(south.lon.is_sign_positive());
+        assert!(south.altitude.unwrap().is_sign_positive());
+
+        let south: ISO6709Coord = parse("00°00′00″S 00°00′00″W").unwrap();
+        assert!(south.lat.is_sign_positive());
+        assert!(south.lon.is_sign_positive());
+
+        let position = parse_position("-00-000/", &ParserOptions::default()).unwrap();
+        assert!(position.coord.lat.is_sign_positive());
+        assert!(position.coord.lon.is_sign_positive());
+    }
+
+    #[test]
+    fn should_reject_long_digit_runs() {
+        let digits = "0".repeat(10_000);
+        let inputs = [
+            format!("+35.{}-170.10/", digits),
+            format!("+35.50-170.10+1{}CRSWGS_84/", digits),
+            format!("15°30′00.{}″N 95°15′00.000″W", digits),
+            format!("15°30′00.000″N 95°15′00.000″W 1{}m", digits),
+            format!("{}°30′00.000″N 95°15′00.000″W", digits),
+        ];
+        for inp in inputs.iter() {
+            assert_eq!(
+                parse::<ISO6709Coord>(inp).unwrap_err().kind(),
+                ErrorKind::TooManyDigits
+            );
+            let options = ParserOptions {
+                max_input_len: None,
+                ..Default::default()
+            };
+            assert_eq!(
+                parse_position(inp, &options).unwrap_err().kind(),
+                ErrorKind::TooManyDigits
+            );
+        }
+
+        // Up to the cap is still accepted
+        let coord = format!("+35.{}-170.10/", "5".repeat(39));
+        assert!(parse::<ISO6709Coord>(&coord).is_ok());
+    }
+
+    #[test]
+    fn should_limit_input_length() {
+        let padded = format!("N35.50W170.10/{}", " ".repeat(300));
+        assert!(parse::<ISO6709Coord>(&padded).is_ok());
+
+        let err = parse_position(&padded, &ParserOptions::default()).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InputTooLong);
+        assert_eq!(
+            err.to_string(),
+            "Failed to parse ISO6709 coordinate: input is 314 bytes, more than the limit of 256"
+        );
+
+        let options = ParserOptions {
+            max_input_len: None,
+            ..Default::default()
+        };
+        assert!(parse_position(&padded, &options).is_ok());
+        let options = ParserOptions {
+            max_input_len: Some(14),
+            ..Default::default()
+        };
+        assert!(parse_position("N35.50W170.10/", &options).is_ok());
+        assert!(parse_position("N35.50W170.10/ ", &options).is_err());
+    }
+
+    #[test]
+    fn should_detect_sign_conflicts() {
+        let err = parse_position("+35.50S170.10/", &ParserOptions::default()).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SignConflict);
+        assert_eq!(
+            err.to_string(),
+            "Failed to parse ISO6709 coordinate: contradictory sign `+` along with hemisphere `S`"
+        );
+        let err = parse_position("15°30′00″N -95°15′00″W", &ParserOptions::default()).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::SignConflict);
+        // The trailing letter is not part of the coordinate that is read
+        let str = "+35.50+170.10E is the spot";
+        let position = parse_position(str, &ParserOptions::default()).unwrap();
+        assert_eq!(position.coord, parse::<ISO6709Coord>(str).unwrap());
+        assert!(position.warnings.is_empty());
+
+        let options = ParserOptions {
+            leniency: Leniency::Lenient,
+            ..Default::default()
+        };
+        let position = parse_position("+35.50S170.10/", &options).unwrap();
+        assert_eq!((position.coord.lat, position.coord.lon), (-35.5, 170.1));
+        assert_eq!(position.warnings, vec![Warning::SignConflict]);
+
+        let position = parse_position("15°30′00″N -95°15′00″W 123.45m", &options).unwrap();
+        assert_eq!(position.coord.lon, -95.25);
+        assert_eq!(position.coord.altitude, Some(123.45));
+        assert_eq!(position.warnings, vec![Warning::SignConflict]);
+
+        let position = parse_position("+35.50-170.10/", &options).unwrap();
+        assert!(position.warnings.is_empty());
+    }
+
+    #[test]
+    fn should_warn_of_precision_loss() {
+        let options = ParserOptions {
+            precision_target: Some(PrecisionTarget::F32),
+            ..Default::default()
+        };
+        let position = parse_position("+35.1234567-170.10/", &options).unwrap();
+        assert_eq!(position.warnings, vec![Warning::PrecisionLoss]);
+        let position = parse_position("+35.50-170.10+12.5CRSWGS_84/", &options).unwrap();
+        assert!(position.warnings.is_empty());
+
+        let options = ParserOptions {
+            precision_target: Some(PrecisionTarget::Format(FormatSpec {
+                format: Format::DegreesMinutes,
+                decimals: 2,
+                ..Default::default()
+            })),
+            ..Default::default()
+        };
+        let position = parse_position("15°30′00.000″N 95°15′00″W", &options).unwrap();
+        assert_eq!(position.warnings, vec![Warning::PrecisionLoss]);
+        let position = parse_position("+3530.1-17006/", &options).unwrap();
+        assert!(position.warnings.is_empty());
+    }
+
+    #[test]
+    fn should_sanitize_when_lenient() {
+        let copied = "15º30’00.000”N\u{a0}95º15’00.000”W 123.45m\r\n";
+        assert!(parse_position(copied, &ParserOptions::default()).is_err());
+
+        let options = ParserOptions {
+            leniency: Leniency::Lenient,
+            ..Default::default()
+        };
+        let position = parse_position(copied, &options).unwrap();
+        assert_eq!(position.coord.lat, 15.5);
+        assert_eq!(position.coord.altitude, Some(123.45));
+        let position = parse_position("+35.50−170.10/", &options).unwrap();
+        assert_eq!(position.coord.lon, -170.1);
+        let decomposed = "15\u{30a}30\u{301}00\u{30b}N 95 \u{30a}15 \u{301}00''W";
+        let position = parse_position(decomposed, &options).unwrap();
+        assert_eq!(position.coord, ISO6709Coord::from_parts(15.5, -95.25, None));
+    }
+
+    #[test]
+    fn should_unwrap_when_lenient() {
+        let wrapped = "\"(15°30′00″N 95°15′00″W)\"";
+        assert!(parse_position(wrapped, &ParserOptions::default()).is_err());
+
+        let options = ParserOptions {
+            leniency: Leniency::Lenient,
+            ..Default::default()
+        };
+        let position = parse_position(wrapped, &options).unwrap();
+        assert_eq!(position.coord, ISO6709Coord::from_parts(15.5, -95.25, None));
+        assert_eq!(position.matched(wrapped), Some("15°30′00″N 95°15′00″W"));
+        let position = parse_position(" [ +35.50-170.10+12CRSWGS_84/ ]", &options).unwrap();
+        assert_eq!(position.coord.altitude, Some(12.));
+        assert_eq!(position.span, Some(3..29));
+        let position = parse_position("“+35.50−170.10/”", &options).unwrap();
+        assert_eq!((position.coord.lon, position.span), (-170.1, None));
+    }
+
+    #[test]
+    fn should_drop_or_reject_altitude() {
+        let str = "15°30′00″N 95°15′00″W 3500 ft MSL";
+        let options = ParserOptions {
+            altitude: AltitudeMode::Ignore,
+            ..Default::default()
+        };
+        let position = parse_position(str, &options).unwrap();
+        assert_eq!(position.coord, ISO6709Coord::from_parts(15.5, -95.25, None));
+        assert_eq!(position.altitude_notation, None);
+        assert_eq!(position.matched(str), Some(str));
+        let position = parse_position("+35.50-170.10+12.5CRSWGS_84/", &options).unwrap();
+        assert_eq!(position.coord.altitude, None);
+        assert_eq!(
+            (
+                position.vertical_datum,
+                position.precision.unwrap().altitude
+            ),
+            (None, None)
+        );
+        assert_eq!(position.crs, Some(Crs::Wgs84));
+        assert_eq!(
+            parse_with_options::<ISO6709Coord>("+35.50-170.10+12/", &options)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::MissingCrs
+        );
+
+        let options = ParserOptions {
+            altitude: AltitudeMode::Reject,
+            ..Default::default()
+        };
+        let err = parse_position("+35.50-170.10+12CRSWGS_84/", &options).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnexpectedAltitude);
+        assert_eq!(err.input(), Some("+35.50-170.10+12CRSWGS_84/"));
+        assert!(parse_position("+35.50-170.10CRSWGS_84/", &options).is_ok());
+    }
+
+    #[test]
+    fn should_assume_default_hemispheres() {
+        let bare = "15°30′00″ 95°15′00″";
+        assert!(parse_position(bare, &ParserOptions::default()).is_err());
+
+        let options = ParserOptions {
+            default_hemispheres: Some(Hemispheres::default()),
+            ..Default::default()
+        };
+        let position = parse_position(bare, &options).unwrap();
+        assert_eq!(position.coord, ISO6709Coord::from_parts(15.5, 95.25, None));
+        assert!(position.hemisphere_assumed);
+        let position = parse_position("15°30′00″N 95°15′00″W", &options).unwrap();
+        assert!(!position.hemisphere_assumed);
+
+        let options = ParserOptions {
+            default_hemispheres: Some(Hemispheres {
+                south: true,
+                west: true,
+            }),
+            ..Default::default()
+        };
+        let position = parse_position("35.50+170.10+12CRSWGS_84/", &options).unwrap();
+        assert_eq!(
+            position.coord,
+            ISO6709Coord::from_parts(-35.5, 170.1, Some(12.))
+        );
+        assert!(position.hemisphere_assumed);
+        let position = parse_position(bare, &options).unwrap();
+        assert_eq!(
+            position.coord,
+            ISO6709Coord::from_parts(-15.5, -95.25, None)
+        );
+    }
+
+    #[test]
+    fn should_ignore_sentence_punctuation_when_lenient() {
+        let options = ParserOptions {
+            leniency: Leniency::Lenient,
+            ..Default::default()
+        };
+        let sentence = "15°30′00″N 95°15′00″W 12.";
+        let position = parse_position(sentence, &ParserOptions::default()).unwrap();
+        assert_eq!(position.matched(sentence), Some(sentence));
+        let position = parse_position(sentence, &options).unwrap();
+        assert_eq!(position.coord.altitude, Some(12.));
+        assert_eq!(position.matched(sentence), Some("15°30′00″N 95°15′00″W 12"));
+
+        let position = parse_position("+35-170+12CRSWGS_84.", &options).unwrap();
+        assert_eq!(position.crs, Some(Crs::Wgs84));
+        for str in [
+            "+35.50-170.10/,",
+            "+35.50-170.10/;",
+            "+35.50-170.10/)",
+            "(+35.50-170.10/).",
+        ] {
+            let position = parse_position(str, &options).unwrap();
+            assert_eq!(position.matched(str), Some("+35.50-170.10/"), "{}", str);
+        }
+    }
+
+    #[test]
+    fn should_handle_exponents() {
+        let coord = "+35.50-170.10+1.2e3CRSWGS_84/";
+        let err = parse::<ISO6709Coord>(coord).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ScientificNotation);
+        let err = parse_position(coord, &ParserOptions::default()).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ScientificNotation);
+        let err = parse::<ISO6709Coord>("15°30′00″N 95°15′00″W 1e5m").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ScientificNotation);
+
+        // Only the numbers of the coordinate itself are looked at
+        let trailing = "+35.50-170.10+12CRSWGS_84/ datum shift 2e5";
+        assert_eq!(
+            parse::<ISO6709Coord>(trailing),
+            Ok(ISO6709Coord::from_parts(35.5, -170.1, Some(12.)))
+        );
+        assert!(is_valid(trailing));
+        assert!(parse_position(trailing, &ParserOptions::default()).is_ok());
+        let trailing = "15°30′00″N 95°15′00″W 12m (1e3 ft)";
+        assert_eq!(
+            parse::<ISO6709Coord>(trailing),
+            Ok(ISO6709Coord::from_parts(15.5, -95.25, Some(12.)))
+        );
+
+        let options = ParserOptions {
+            allow_exponent: true,
+            ..Default::default()
+        };
+        let position = parse_position(coord, &options).unwrap();
+        assert_eq!(position.coord.altitude, Some(1200.));
+        let position = parse_position("+3.55e1-1.701e2/", &options).unwrap();
+        assert_eq!((position.coord.lat, position.coord.lon), (35.5, -170.1));
+        let position = parse_position("N35.50E170.10/", &options).unwrap();
+        assert_eq!(position.coord.lon, 170.1);
+    }
+
+    #[test]
+    fn should_require_crs_with_altitude() {
+        let coord = "+35.50-170.10+12/";
+        let err = parse_position(coord, &ParserOptions::default()).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingCrs);
+        // parse reads the coordinate without the altitude, as it always has
+        assert_eq!(
+            parse::<ISO6709Coord>(coord),
+            Ok(ISO6709Coord::from_parts(35.5, -170.1, None))
+        );
+        assert_eq!(
+            parse::<ISO6709Coord>("+35.50-170.10-12.5"),
+            Ok(ISO6709Coord::from_parts(35.5, -170.1, None))
+        );
+        assert!(is_valid(coord));
+        assert_eq!(
+            parse_with_options::<ISO6709Coord>(coord, &ParserOptions::default())
+                .unwrap_err()
+                .kind(),
+            ErrorKind::MissingCrs
+        );
+
+        let options = ParserOptions {
+            leniency: Leniency::Lenient,
+            ..Default::default()
+        };
+        let position = parse_position(coord, &options).unwrap();
+        assert_eq!(position.coord.altitude, Some(12.));
+        assert!(position.crs_assumed);
+
+        let options = ParserOptions {
+            require_crs: Some(false),
+            ..Default::default()
+        };
+        let position = parse_position("+35.50-170.10-12.5", &options).unwrap();
+        assert_eq!(position.coord.altitude, Some(-12.5));
+        let options = ParserOptions {
+            leniency: Leniency::Lenient,
+            require_crs: Some(true),
+            ..Default::default()
+        };
+        assert!(parse_position(coord, &options).is_err());
+        assert!(parse_position("+35.50-170.10+12CRSWGS_84/", &options).is_ok());
+    }
+
+    #[test]
+    fn should_read_spelled_out_in_lenient_mode() {
+        let str = "North 50 degrees 40 minutes 46 seconds, West 95 degrees 48 minutes 27 seconds";
+        assert!(parse_position(str, &ParserOptions::default()).is_err());
+
+        let options = ParserOptions {
+            leniency: Leniency::Lenient,
+            ..Default::default()
+        };
+        let position = parse_position(str, &options).unwrap();
+        assert!((position.coord.lat - 50.679444).abs() < 1e-6);
+        assert!((position.coord.lon + 95.8075).abs() < 1e-6);
+        let position = parse_position("50.68 North, 95.81 West", &options).unwrap();
+        assert_eq!(
+            position.coord,
+            ISO6709Coord::from_parts(50.68, -95.81, None)
+        );
+        assert_eq!(
+            parse_position("95.5 North, 95.81 West", &options)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::OutOfRange
+        );
+    }
+
+    #[test]
+    fn should_read_decimal_commas() {
+        let coord = "+35,50-170,10+8712,5CRSWGS_84/";
+        assert!(parse_position(coord, &ParserOptions::default()).is_err());
+
+        let options = ParserOptions {
+            decimal_comma: true,
+            ..Default::default()
+        };
+        let position = parse_position(coord, &options).unwrap();
+        assert_eq!(position.coord.lat, 35.5);
+        assert_eq!(position.coord.altitude, Some(8712.5));
+        assert_eq!(position.precision.unwrap().altitude, Some(1));
+        let position = parse_position("15°30′00″N 95°15′00″W 123,45m", &options).unwrap();
+        assert_eq!(position.coord.altitude, Some(123.45));
+    }
+
+    #[test]
+    fn should_keep_error_context() {
+        use std::error::Error;
+
+        let err = parse::<ISO6709Coord>(" N35.50W1x0.10/").unwrap_err();
+        assert_eq!(err.input(), Some(" N35.50W1x0.10/"));
+        assert!(err.source().is_some());
+        assert_eq!(err.clone(), err);
+
+        let copied = "15º30’00.000”N 95º15’00.000”X";
+        let options = ParserOptions {
+            leniency: Leniency::Lenient,
+            ..Default::default()
+        };
+        let err = parse_position(copied, &options).unwrap_err();
+        assert_eq!(err.input(), Some(copied));
+
+        let err = parse_position("+35.50-170.10+12/", &ParserOptions::default()).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingCrs);
+        assert!(err.source().is_none());
+        let err = ISO6709Coord::new(f64::NAN, 0., None).unwrap_err();
+        assert_eq!(err.input(), None);
+    }
+
+    #[test]
+    fn should_parse_with_symbols() {
+        let options = ParserOptions {
+            symbols: Symbols {
+                degree: vec!["°".to_string(), "º".to_string()],
+                decimal: vec![",".to_string()],
+                north: vec!["N".to_string(), "n".to_string()],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let position = parse_position("15º30′00,5″n 95°15′00″W 123,45m", &options).unwrap();
+        assert_eq!(position.coord.lat, 15.5 + 0.5 / 3600.);
+        assert_eq!(position.coord.altitude, Some(123.45));
+        let precision = position.precision.unwrap();
+        assert_eq!(precision.lat.decimals, 1);
+        assert_eq!(precision.lon.unit, AngleUnit::Seconds);
+        assert_eq!(precision.altitude, Some(2));
+        assert!(parse_position("15º30′00″N 95°15′00″W", &ParserOptions::default()).is_err());
+
+        let options = ParserOptions {
+            leniency: Leniency::Lenient,
+            default_hemispheres: Some(Hemispheres::default()),
+            symbols: Symbols {
+                degree: vec!["d".to_string()],
+                west: vec!["O".to_string()],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let position = parse_position("15d30′00″N -95d15′00″O", &options).unwrap();
+        assert_eq!(position.coord.lon, -95.25);
+        assert_eq!(position.warnings, vec![Warning::SignConflict]);
+        let position = parse_position("15d30′00″ 95d15′00″", &options).unwrap();
+        assert_eq!(position.coord.lon, 95.25);
+        assert!(position.hemisphere_assumed);
+        assert!(parse::<ISO6709Coord>("15°30′00″n 95°15′00″W").is_err());
+    }
+
+    #[test]
+    fn should_run_validator() {
+        let options = ParserOptions {
+            validator: Some(Validator::new(|coord: &ISO6709Coord| {
+                if coord.lon < 0. {
+                    Err("west of Greenwich".to_string())
+                } else {
+                    Ok(())
+                }
+            })),
+            ..Default::default()
+        };
+        assert_eq!(options.clone(), options);
+        assert_ne!(options, ParserOptions::default());
+        assert!(parse_position("+35.50+170.10/", &options).is_ok());
+
+        let err = parse_position("15°30′00″N 95°15′00″W", &options).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Rejected);
+        assert_eq!(err.input(), Some("15°30′00″N 95°15′00″W"));
+        assert!(err.to_string().contains("west of Greenwich"));
+        let err = parse_with_options::<ISO6709Coord>("+35.50-170.10/", &options).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Rejected);
+    }
+
+    #[test]
+    fn should_not_panic() {
+        let digits = "9".repeat(300);
+        let inputs = [
+            "",
+            "+",
+            "N",
+            "°′″",
+            "15°",
+            "15°30′",
+            "15°30′00″",
+            "15°30′00″N 95°",
+            "+35.50-170.10+",
+            "+35.50-170.10+12CRS",
+            "+35.50-170.10+12CRS/",
+            "+1e308-1e308/",
+            "+1e-308+1e-308+1e308CRSWGS_84/",
+            "+9e999999999+0/",
+            "NaN",
+            "+inf-inf/",
+            "+35.é50-170.10/",
+            "15°30′00″Ň 95°15′00″W",
+            "\u{0}",
+            &digits,
+        ];
+        let options = [
+            ParserOptions::default(),
+            ParserOptions {
+                leniency: Leniency::Lenient,
+                decimal_comma: true,
+                allow_exponent: true,
+                max_input_len: None,
+                ..Default::default()
+            },
+        ];
+        for input in inputs {
+            let _ = parse::<ISO6709Coord>(input);
+            #[cfg(feature = "geo-types")]
+            let _ = parse_bbox(input);
+            for options in &options {
+                if let Ok(position) = parse_position(input, options) {
+                    assert!(position.coord.lat.abs() <= 90., "{:?}", input);
+                    assert!(position.coord.lon.abs() <= 180., "{:?}", input);
+                }
+                let _ = parse_with_repairs(input, options);
+            }
+        }
     }
 }
@@ -0,0 +1,348 @@
+use crate::ISO6709Coord;
+
+/// Builds an [`ISO6709Coord`] from a string literal that is checked at compile time, so that hard-coded locations
+/// need no runtime parsing or unwrapping. Either format is accepted, the same as [`crate::parse`] reads it, and the
+/// values are identical to what it gives.
+/// ```
+/// use iso6709parse::{coord, parse, ISO6709Coord};
+///
+/// const OFFICE: ISO6709Coord = coord!("15°30′00″N 95°15′00″W");
+/// assert_eq!(OFFICE.lon, -95.25);
+/// assert_eq!(coord!("N35.50W170.10+12CRSWGS_84/"), parse::<ISO6709Coord>("N35.50W170.10+12CRSWGS_84/").unwrap());
+/// ```
+/// An invalid literal fails to compile:
+/// ```compile_fail
+/// # use iso6709parse::coord;
+/// let coord = coord!("95°30′00″N 95°15′00″W");
+/// ```
+#[macro_export]
+macro_rules! coord {
+    ($str:literal) => {
+        const { $crate::ISO6709Coord::from_literal($str) }
+    };
+}
+
+/// Exact powers of ten. Dividing an integer of at most 53 bits by one of these is correctly rounded, the same as
+/// `str::parse::<f64>` of the decimal.
+const POWERS_OF_TEN: [f64; 23] = [
+    1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11, 1e12, 1e13, 1e14, 1e15, 1e16,
+    1e17, 1e18, 1e19, 1e20, 1e21, 1e22,
+];
+
+impl ISO6709Coord {
+    /// Parses `str` in a const context, which is what [`coord!`] expands to.
+    /// Only the standard symbols are read, and each number may have at most 15 significant digits and 22 decimals.
+    ///
+    /// # Panics
+    /// If `str` is not a valid coordinate, which is a compile error when evaluated as a constant.
+    /// ```
+    /// # use iso6709parse::ISO6709Coord;
+    /// const SUMMIT: ISO6709Coord = ISO6709Coord::from_literal("+27.9881+086.9250+8848.86/");
+    /// assert_eq!(SUMMIT.altitude, Some(8848.86));
+    /// ```
+    pub const fn from_literal(str: &str) -> ISO6709Coord {
+        let bytes = str.as_bytes();
+        let (start, end) = trimmed(bytes);
+        let (lat, lon, altitude) = if start < end && bytes[start].is_ascii_digit() {
+            readable(bytes, start, end)
+        } else {
+            string_representation(bytes, start, end)
+        };
+        // Adding positive zero only changes the sign of a negative zero, as in `ISO6709Coord::unsigned_zero`
+        ISO6709Coord {
+            lat: lat + 0.,
+            lon: lon + 0.,
+            altitude: match altitude {
+                Some(altitude) => Some(altitude + 0.),
+                None => None,
+            },
+        }
+    }
+}
+
+const fn trimmed(bytes: &[u8]) -> (usize, usize) {
+    let (mut start, mut end) = (0, bytes.len());
+    while start < end && bytes[start].is_ascii_whitespace() {
+        start += 1;
+    }
+    while end > start && bytes[end - 1].is_ascii_whitespace() {
+        end -= 1;
+    }
+    (start, end)
+}
+
+/// `true` if `pattern` is at `at`, and before `end`
+const fn starts_with(bytes: &[u8], at: usize, end: usize, pattern: &[u8]) -> bool {
+    if at + pattern.len() > end {
+        return false;
+    }
+    let mut index = 0;
+    while index < pattern.len() {
+        if bytes[at + index] != pattern[index] {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+/// Where the run of ascii digits starting at `at` ends
+const fn digits_end(bytes: &[u8], mut at: usize, end: usize) -> usize {
+    while at < end && bytes[at].is_ascii_digit() {
+        at += 1;
+    }
+    at
+}
+
+/// Reads digits with at most one `.` from `start` to `end`, as `str::parse::<f64>` would
+const fn decimal(bytes: &[u8], start: usize, end: usize) -> f64 {
+    let mut mantissa: u64 = 0;
+    let mut decimals = 0;
+    let mut point = false;
+    let mut digits = 0;
+    let mut at = start;
+    while at < end {
+        let byte = bytes[at];
+        if byte == b'.' {
+            if point {
+                panic!("a number has more than one decimal point");
+            }
+            point = true;
+        } else {
+            mantissa = mantissa * 10 + (byte - b'0') as u64;
+            digits += 1;
+            if point {
+                decimals += 1;
+            }
+            if mantissa >= 1 << 53 || decimals >= POWERS_OF_TEN.len() {
+                panic!("a number has too many digits for `coord!`");
+            }
+        }
+        at += 1;
+    }
+    if digits == 0 {
+        panic!("expected a number");
+    }
+    mantissa as f64 / POWERS_OF_TEN[decimals]
+}
+
+/// `50°40′46.461″N 95°48′26.533″W 123.45m`
+const fn readable(bytes: &[u8], start: usize, end: usize) -> (f64, f64, Option<f64>) {
+    let (lat, at) = readable_angle(bytes, start, end, b'N', b'S', 90.);
+    let mut lon_start = at;
+    while lon_start < end && (bytes[lon_start] == b' ' || bytes[lon_start] == b'\t') {
+        lon_start += 1;
+    }
+    if lon_start == at {
+        panic!("expected a space after the latitude");
+    }
+    let (lon, mut at) = readable_angle(bytes, lon_start, end, b'E', b'W', 180.);
+    if at == end {
+        return (lat, lon, None);
+    }
+    while at < end && (bytes[at] == b' ' || bytes[at] == b'\t') {
+        at += 1;
+    }
+    let sign = if starts_with(bytes, at, end, b"-") {
+        at += 1;
+        -1.
+    } else {
+        1.
+    };
+    let altitude_start = at;
+    while at < end && (bytes[at].is_ascii_digit() || bytes[at] == b'.') {
+        at += 1;
+    }
+    let altitude = sign * decimal(bytes, altitude_start, at);
+    // The unit, which may be separated from the value by spaces
+    while at < end && (bytes[at] == b' ' || bytes[at] == b'\t') {
+        at += 1;
+    }
+    while at < end && bytes[at].is_ascii_alphabetic() {
+        at += 1;
+    }
+    if at != end {
+        panic!("unexpected text after the coordinate");
+    }
+    (lat, lon, Some(altitude))
+}
+
+/// Degrees, minutes, seconds and the hemisphere, giving the signed angle and where it ends
+const fn readable_angle(
+    bytes: &[u8],
+    start: usize,
+    end: usize,
+    positive: u8,
+    negative: u8,
+    max: f64,
+) -> (f64, usize) {
+    let at = digits_end(bytes, start, end);
+    let degrees = decimal(bytes, start, at);
+    if !starts_with(bytes, at, end, "°".as_bytes()) {
+        panic!("expected `°` after the degrees");
+    }
+    let minutes_start = at + "°".len();
+    let at = digits_end(bytes, minutes_start, end);
+    let minutes = decimal(bytes, minutes_start, at);
+    let at = if starts_with(bytes, at, end, b"'") {
+        at + 1
+    } else if starts_with(bytes, at, end, "′".as_bytes()) {
+        at + "′".len()
+    } else {
+        panic!("expected `′` or `'` after the minutes")
+    };
+    let seconds_start = at;
+    let mut at = digits_end(bytes, seconds_start, end);
+    if starts_with(bytes, at, end, b".") {
+        let fraction_end = digits_end(bytes, at + 1, end);
+        if fraction_end == at + 1 {
+            panic!("expected digits after the decimal point");
+        }
+        at = fraction_end;
+    }
+    let seconds = decimal(bytes, seconds_start, at);
+    let at = if starts_with(bytes, at, end, b"\"") {
+        at + 1
+    } else if starts_with(bytes, at, end, "″".as_bytes()) {
+        at + "″".len()
+    } else {
+        panic!("expected `″` or `\"` after the seconds")
+    };
+    if minutes >= 60. || seconds >= 60. {
+        panic!("minutes and seconds must be less than 60");
+    }
+    let value = degrees + minutes / 60. + seconds / 3600.;
+    if value > max {
+        panic!("the latitude or longitude is out of range");
+    }
+    if at < end && bytes[at] == positive {
+        (value, at + 1)
+    } else if at < end && bytes[at] == negative {
+        (-value, at + 1)
+    } else {
+        panic!("expected the hemisphere after the seconds")
+    }
+}
+
+/// `N35.50W170.10+8712CRSWGS_84/`
+const fn string_representation(bytes: &[u8], start: usize, end: usize) -> (f64, f64, Option<f64>) {
+    let (lat, at) = string_angle(bytes, start, end, b'N', b'S', 2);
+    if lat > 90. || lat < -90. {
+        panic!("the latitude is out of range");
+    }
+    let (lon, mut at) = string_angle(bytes, at, end, b'E', b'W', 3);
+    if lon > 180. || lon < -180. {
+        panic!("the longitude is out of range");
+    }
+    let mut altitude = None;
+    if at < end && (bytes[at] == b'+' || bytes[at] == b'-') {
+        let sign = if bytes[at] == b'-' { -1. } else { 1. };
+        let altitude_start = at + 1;
+        at = altitude_start;
+        while at < end && (bytes[at].is_ascii_digit() || bytes[at] == b'.') {
+            at += 1;
+        }
+        altitude = Some(sign * decimal(bytes, altitude_start, at));
+        if starts_with(bytes, at, end, b"CRS") {
+            at += 3;
+            let crs_start = at;
+            while at < end && bytes[at] != b'/' {
+                at += 1;
+            }
+            if at == crs_start {
+                panic!("expected a CRS after `CRS`");
+            }
+        }
+    }
+    if starts_with(bytes, at, end, b"/") {
+        at += 1;
+    }
+    if at != end {
+        panic!("unexpected text in the coordinate");
+    }
+    (lat, lon, altitude)
+}
+
+/// The hemisphere or sign, then degrees of `width` digits, optionally followed by two digits each of minutes and
+/// seconds, and a decimal fraction of the last of them
+const fn string_angle(
+    bytes: &[u8],
+    start: usize,
+    end: usize,
+    positive: u8,
+    negative: u8,
+    width: usize,
+) -> (f64, usize) {
+    if start >= end {
+        panic!("expected a latitude and longitude");
+    }
+    let sign = match bytes[start] {
+        b'+' => 1.,
+        b'-' => -1.,
+        byte if byte == positive => 1.,
+        byte if byte == negative => -1.,
+        _ => panic!("expected a hemisphere or sign"),
+    };
+    let digits_start = start + 1;
+    let at = digits_end(bytes, digits_start, end);
+    let count = at - digits_start;
+    let value = if count == width {
+        decimal(bytes, digits_start, at)
+    } else if count == width + 2 {
+        let degrees = decimal(bytes, digits_start, digits_start + width);
+        let minutes = decimal(bytes, digits_start + width, at);
+        if minutes >= 60. {
+            panic!("minutes must be less than 60");
+        }
+        degrees + minutes / 60.
+    } else if count == width + 4 {
+        let degrees = decimal(bytes, digits_start, digits_start + width);
+        let minutes = decimal(bytes, digits_start + width, digits_start + width + 2);
+        let seconds = decimal(bytes, digits_start + width + 2, at);
+        if minutes >= 60. || seconds >= 60. {
+            panic!("minutes and seconds must be less than 60");
+        }
+        degrees + minutes / 60. + seconds / 3600.
+    } else {
+        panic!("unexpected number of digits in an angle")
+    };
+    if !starts_with(bytes, at, end, b".") {
+        return (sign * value, at);
+    }
+    let fraction_end = digits_end(bytes, at + 1, end);
+    let fraction = decimal(bytes, at, fraction_end);
+    let value = if count == width {
+        value + fraction
+    } else if count == width + 2 {
+        value + fraction / 60.
+    } else {
+        value + fraction / 3600.
+    };
+    (sign * value, fraction_end)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, ISO6709Coord};
+
+    #[test]
+    fn should_match_runtime_parse() {
+        for (literal, coord) in [
+            ("15°30′00″N 95°15′00″W", coord!("15°30′00″N 95°15′00″W")),
+            (
+                "50°40'46.461\"S 95°48'26.533\"E -123.45 m",
+                coord!("50°40'46.461\"S 95°48'26.533\"E -123.45 m"),
+            ),
+            ("N35.50W170.10/", coord!("N35.50W170.10/")),
+            ("+3530.25-17030.5", coord!("+3530.25-17030.5")),
+            (
+                " S353012.5E1703012.25+8712.3CRSWGS_84/ ",
+                coord!(" S353012.5E1703012.25+8712.3CRSWGS_84/ "),
+            ),
+            ("-00.00+000.00", coord!("-00.00+000.00")),
+        ] {
+            assert_eq!(Ok(coord), parse::<ISO6709Coord>(literal), "{}", literal);
+        }
+        assert!(coord!("00°00′00″S 000°00′00″W").lat.is_sign_positive());
+    }
+}
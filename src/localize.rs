@@ -0,0 +1,277 @@
+use crate::{ErrorKind, ISO6709Error};
+use std::collections::HashMap;
+
+/// Error messages in another language, for applications that show parse errors to their users.
+/// Each kind of error has a template, in which `{input}` is replaced by the input that failed, and `{detail}` by the
+/// English message. Errors of a kind without a template are given in English, as their `Display` gives them.
+/// ```
+/// # use iso6709parse::{parse_coord, ErrorKind, ISO6709Coord, MessageCatalog};
+/// let catalog = MessageCatalog::german().with(ErrorKind::Syntax, "`{input}` ist keine Koordinate");
+/// let err = parse_coord("N35.50W1x0.10/").unwrap_err();
+/// assert_eq!(err.localized(&catalog), "`N35.50W1x0.10/` ist keine Koordinate");
+/// assert_eq!(
+///     ISO6709Coord::new(0., 0., Some(f64::NAN)).unwrap_err().localized(&catalog),
+///     "Ein Wert ist keine endliche Zahl"
+/// );
+/// ```
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct MessageCatalog {
+    templates: HashMap<ErrorKind, String>,
+}
+
+impl MessageCatalog {
+    /// A catalog with no templates, which gives every error in English
+    pub fn empty() -> Self {
+        MessageCatalog::default()
+    }
+
+    /// Sets the template for `kind`
+    pub fn with(mut self, kind: ErrorKind, template: impl Into<String>) -> Self {
+        self.insert(kind, template);
+        self
+    }
+
+    /// Sets the template for `kind`
+    pub fn insert(&mut self, kind: ErrorKind, template: impl Into<String>) {
+        self.templates.insert(kind, template.into());
+    }
+
+    fn from_table(table: [(ErrorKind, &str); 15]) -> Self {
+        MessageCatalog {
+            templates: table
+                .into_iter()
+                .map(|(kind, template)| (kind, template.to_string()))
+                .collect(),
+        }
+    }
+
+    pub fn german() -> Self {
+        MessageCatalog::from_table([
+            (
+                ErrorKind::Syntax,
+                "Die Eingabe ist keine ISO-6709-Koordinate",
+            ),
+            (
+                ErrorKind::UnknownCrs,
+                "Das Koordinatenreferenzsystem ist unbekannt",
+            ),
+            (
+                ErrorKind::InvalidBoundingBox,
+                "Der Begrenzungsrahmen ist ungültig",
+            ),
+            (ErrorKind::NonFinite, "Ein Wert ist keine endliche Zahl"),
+            (ErrorKind::TooManyDigits, "Eine Zahl hat zu viele Ziffern"),
+            (ErrorKind::InputTooLong, "Die Eingabe ist zu lang"),
+            (
+                ErrorKind::SignConflict,
+                "Ein Wert hat sowohl ein Vorzeichen als auch eine Himmelsrichtung",
+            ),
+            (
+                ErrorKind::OutOfRange,
+                "Die Breite muss zwischen -90° und 90° liegen, die Länge zwischen -180° und 180°",
+            ),
+            (
+                ErrorKind::ScientificNotation,
+                "Zahlen mit Exponent sind nicht erlaubt",
+            ),
+            (ErrorKind::MissingAltitude, "Die Höhe fehlt"),
+            (
+                ErrorKind::MissingCrs,
+                "Auf die Höhe muss ein Koordinatenreferenzsystem folgen",
+            ),
+            (
+                ErrorKind::Rejected,
+                "Die Koordinate wurde abgelehnt: {detail}",
+            ),
+            (
+                ErrorKind::Write,
+                "Die Koordinate konnte nicht geschrieben werden",
+            ),
+            (
+                ErrorKind::Mismatch,
+                "Die beiden Angaben der Koordinate stimmen nicht überein",
+            ),
+            (
+                ErrorKind::UnexpectedAltitude,
+                "Die Koordinate darf keine Höhe haben",
+            ),
+        ])
+    }
+
+    pub fn french() -> Self {
+        MessageCatalog::from_table([
+            (
+                ErrorKind::Syntax,
+                "L'entrée n'est pas une coordonnée ISO 6709",
+            ),
+            (
+                ErrorKind::UnknownCrs,
+                "Le système de référence de coordonnées est inconnu",
+            ),
+            (
+                ErrorKind::InvalidBoundingBox,
+                "Le rectangle englobant n'est pas valide",
+            ),
+            (ErrorKind::NonFinite, "Une valeur n'est pas un nombre fini"),
+            (ErrorKind::TooManyDigits, "Un nombre a trop de chiffres"),
+            (ErrorKind::InputTooLong, "L'entrée est trop longue"),
+            (
+                ErrorKind::SignConflict,
+                "Une valeur a à la fois un signe et un hémisphère",
+            ),
+            (
+                ErrorKind::OutOfRange,
+                "La latitude doit être comprise entre -90° et 90°, la longitude entre -180° et 180°",
+            ),
+            (
+                ErrorKind::ScientificNotation,
+                "Les nombres avec exposant ne sont pas acceptés",
+            ),
+            (ErrorKind::MissingAltitude, "L'altitude est manquante"),
+            (
+                ErrorKind::MissingCrs,
+                "L'altitude doit être suivie d'un système de référence de coordonnées",
+            ),
+            (ErrorKind::Rejected, "La coordonnée a été refusée : {detail}"),
+            (ErrorKind::Write, "La coordonnée n'a pas pu être écrite"),
+            (
+                ErrorKind::Mismatch,
+                "Les deux formes de la coordonnée ne concordent pas",
+            ),
+            (
+                ErrorKind::UnexpectedAltitude,
+                "La coordonnée ne doit pas avoir d'altitude",
+            ),
+        ])
+    }
+
+    pub fn spanish() -> Self {
+        MessageCatalog::from_table([
+            (
+                ErrorKind::Syntax,
+                "La entrada no es una coordenada ISO 6709",
+            ),
+            (
+                ErrorKind::UnknownCrs,
+                "El sistema de referencia de coordenadas es desconocido",
+            ),
+            (
+                ErrorKind::InvalidBoundingBox,
+                "El cuadro delimitador no es válido",
+            ),
+            (ErrorKind::NonFinite, "Un valor no es un número finito"),
+            (
+                ErrorKind::TooManyDigits,
+                "Un número tiene demasiados dígitos",
+            ),
+            (ErrorKind::InputTooLong, "La entrada es demasiado larga"),
+            (
+                ErrorKind::SignConflict,
+                "Un valor tiene a la vez un signo y un hemisferio",
+            ),
+            (
+                ErrorKind::OutOfRange,
+                "La latitud debe estar entre -90° y 90°, y la longitud entre -180° y 180°",
+            ),
+            (
+                ErrorKind::ScientificNotation,
+                "No se admiten números con exponente",
+            ),
+            (ErrorKind::MissingAltitude, "Falta la altitud"),
+            (
+                ErrorKind::MissingCrs,
+                "La altitud debe ir seguida de un sistema de referencia de coordenadas",
+            ),
+            (ErrorKind::Rejected, "La coordenada fue rechazada: {detail}"),
+            (ErrorKind::Write, "No se pudo escribir la coordenada"),
+            (
+                ErrorKind::Mismatch,
+                "Las dos formas de la coordenada no coinciden",
+            ),
+            (
+                ErrorKind::UnexpectedAltitude,
+                "La coordenada no debe tener altitud",
+            ),
+        ])
+    }
+}
+
+impl ISO6709Error {
+    /// The message from `catalog` for this kind of error, or the English message if it has none
+    pub fn localized(&self, catalog: &MessageCatalog) -> String {
+        match catalog.templates.get(&self.kind()) {
+            Some(template) => template
+                .replace("{input}", self.input().unwrap_or_default())
+                .replace("{detail}", self.message()),
+            None => self.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_position, ISO6709Coord, ParserOptions};
+
+    const KINDS: [ErrorKind; 15] = [
+        ErrorKind::Syntax,
+        ErrorKind::UnknownCrs,
+        ErrorKind::InvalidBoundingBox,
+        ErrorKind::NonFinite,
+        ErrorKind::TooManyDigits,
+        ErrorKind::InputTooLong,
+        ErrorKind::SignConflict,
+        ErrorKind::OutOfRange,
+        ErrorKind::ScientificNotation,
+        ErrorKind::MissingAltitude,
+        ErrorKind::MissingCrs,
+        ErrorKind::Rejected,
+        ErrorKind::Write,
+        ErrorKind::Mismatch,
+        ErrorKind::UnexpectedAltitude,
+    ];
+
+    #[test]
+    fn should_translate_every_kind() {
+        for catalog in [
+            MessageCatalog::german(),
+            MessageCatalog::french(),
+            MessageCatalog::spanish(),
+        ] {
+            for kind in KINDS {
+                assert!(catalog.templates.contains_key(&kind), "{:?}", kind);
+            }
+        }
+    }
+
+    #[test]
+    fn should_localize() {
+        let err = ISO6709Coord::new(95., 0., None).unwrap_err();
+        assert_eq!(
+            err.localized(&MessageCatalog::spanish()),
+            "La latitud debe estar entre -90° y 90°, y la longitud entre -180° y 180°"
+        );
+        assert_eq!(err.localized(&MessageCatalog::empty()), err.to_string());
+
+        let options = ParserOptions {
+            validator: Some(crate::Validator::new(|_| {
+                Err("outside the site".to_string())
+            })),
+            ..Default::default()
+        };
+        let err = parse_position("+35.50-170.10/", &options).unwrap_err();
+        assert_eq!(
+            err.localized(&MessageCatalog::french()),
+            "La coordonnée a été refusée : outside the site"
+        );
+
+        let catalog = MessageCatalog::empty().with(ErrorKind::OutOfRange, "{input}!");
+        assert_eq!(err.localized(&catalog), err.to_string());
+        let err = crate::parse_coord("+95.50-170.10/").unwrap_err();
+        let catalog = catalog.with(err.kind(), "{input} ({detail})");
+        assert_eq!(
+            err.localized(&catalog),
+            format!("+95.50-170.10/ ({})", err.message())
+        );
+    }
+}
@@ -0,0 +1,288 @@
+use crate::parsers::common::{digits0, digits1};
+use crate::{parse_coord, trim, ErrorKind, FormatSpec, ISO6709Coord, ISO6709Error};
+use nom::branch::alt;
+use nom::bytes::complete::{tag, tag_no_case, take_while1};
+use nom::character::complete::space0;
+use nom::combinator::{all_consuming, map_res, opt, recognize};
+use nom::multi::many0;
+use nom::sequence::{pair, preceded, separated_pair, terminated, tuple};
+use nom::{Finish, IResult};
+
+/// A measured position: the coordinate, and how far the true position may be from it
+/// ```
+/// # use iso6709parse::{parse_location, FormatSpec};
+/// let location = parse_location("N35.50W170.10/ ±10m").unwrap();
+/// assert_eq!((location.coord.lat, location.uncertainty), (35.5, Some(10.)));
+///
+/// let spec = FormatSpec { decimals: 2, ..Default::default() };
+/// assert_eq!(location.format(&spec).unwrap(), "+35.50-170.10/ ±10m");
+/// assert_eq!(location.to_geo_uri(), "geo:35.5,-170.1;u=10");
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Location {
+    pub coord: ISO6709Coord,
+    /// Radius in meters around the coordinate. `None` if it is not known, which is not the same as exact.
+    pub uncertainty: Option<f64>,
+}
+
+impl Location {
+    /// Writes the coordinate as [`ISO6709Coord::format`] does, followed by the uncertainty, such as ` ±10m`.
+    /// Fails if the coordinate does, or the uncertainty is not a finite distance of 0 or more.
+    pub fn format(&self, spec: &FormatSpec) -> Result<String, ISO6709Error> {
+        let mut out = self.coord.format(spec)?;
+        if let Some(uncertainty) = self.uncertainty {
+            let uncertainty = check_uncertainty(uncertainty)?.to_string();
+            out.push_str(" ±");
+            if spec.decimal_comma {
+                out.push_str(&uncertainty.replace('.', ","));
+            } else {
+                out.push_str(&uncertainty);
+            }
+            out.push('m');
+        }
+        Ok(out)
+    }
+
+    /// Writes the location as an RFC 5870 geo URI, with the uncertainty as the `u` parameter
+    /// ```
+    /// # use iso6709parse::{ISO6709Coord, Location};
+    /// let coord = ISO6709Coord::new(48.2010, 16.3695, Some(183.)).unwrap();
+    /// let location = Location { coord, uncertainty: Some(66.5) };
+    /// assert_eq!(location.to_geo_uri(), "geo:48.201,16.3695,183;u=66.5");
+    /// ```
+    pub fn to_geo_uri(&self) -> String {
+        let mut uri = format!("geo:{},{}", self.coord.lat + 0., self.coord.lon + 0.);
+        if let Some(altitude) = self.coord.altitude {
+            uri.push_str(&format!(",{}", altitude + 0.));
+        }
+        if let Some(uncertainty) = self.uncertainty {
+            uri.push_str(&format!(";u={}", uncertainty + 0.));
+        }
+        uri
+    }
+}
+
+impl From<ISO6709Coord> for Location {
+    fn from(value: ISO6709Coord) -> Self {
+        Location {
+            coord: value,
+            uncertainty: None,
+        }
+    }
+}
+
+/// Parses a coordinate in either ISO6709 format followed by an uncertainty in meters such as `±10m`, `± 10 m` or
+/// `+/-10`, or an RFC 5870 geo URI such as `geo:35.5,-170.1;u=10`. The uncertainty may be left out of either.
+/// A geo URI with a `crs` other than `wgs84` fails with [`ErrorKind::UnknownCrs`].
+/// ```
+/// # use iso6709parse::parse_location;
+/// let location = parse_location("geo:35.5,-170.1,12;crs=wgs84;u=25").unwrap();
+/// assert_eq!(location.coord.altitude, Some(12.));
+/// assert_eq!(location.uncertainty, Some(25.));
+///
+/// let location = parse_location("15°30′00″N 95°15′00″W +/- 5.5 m").unwrap();
+/// assert_eq!(location.uncertainty, Some(5.5));
+/// ```
+pub fn parse_location(str: &str) -> Result<Location, ISO6709Error> {
+    read_location(str).map_err(|err| err.with_input(str))
+}
+
+fn read_location(str: &str) -> Result<Location, ISO6709Error> {
+    if str
+        .trim_start()
+        .get(..4)
+        .is_some_and(|scheme| scheme.eq_ignore_ascii_case("geo:"))
+    {
+        return read_geo_uri(str);
+    }
+    let (coord, uncertainty) = match str.rfind('±').or_else(|| str.rfind("+/-")) {
+        Some(index) => {
+            let (_, uncertainty) = all_consuming(trim(uncertainty))(&str[index..]).finish()?;
+            (&str[..index], Some(check_uncertainty(uncertainty)?))
+        }
+        None => (str, None),
+    };
+    Ok(Location {
+        coord: parse_coord(coord)?,
+        uncertainty,
+    })
+}
+
+fn read_geo_uri(str: &str) -> Result<Location, ISO6709Error> {
+    let (_, ((lat, lon, altitude), parameters)) = all_consuming(trim(geo_uri))(str).finish()?;
+    let mut uncertainty = None;
+    for (name, value) in parameters {
+        if name.eq_ignore_ascii_case("crs") && !value.eq_ignore_ascii_case("wgs84") {
+            return Err(ISO6709Error::new(
+                ErrorKind::UnknownCrs,
+                format!("a geo URI with the CRS {} is not WGS84", value),
+            ));
+        }
+        if name.eq_ignore_ascii_case("u") {
+            let value = value.parse().map_err(|_| {
+                ISO6709Error::new(ErrorKind::Syntax, "the uncertainty is not a number")
+            })?;
+            uncertainty = Some(check_uncertainty(value)?);
+        }
+    }
+    let coord = ISO6709Coord::new(lat, lon, altitude)?;
+    Ok(Location {
+        coord: ISO6709Coord::unsigned_zero(coord.lat, coord.lon, coord.altitude),
+        uncertainty,
+    })
+}
+
+fn check_uncertainty(uncertainty: f64) -> Result<f64, ISO6709Error> {
+    if uncertainty.is_finite() && uncertainty >= 0. {
+        Ok(uncertainty)
+    } else {
+        Err(ISO6709Error::new(
+            ErrorKind::OutOfRange,
+            format!("an uncertainty of {} is not a distance", uncertainty),
+        ))
+    }
+}
+
+fn number(inp: &str) -> IResult<&str, f64> {
+    map_res(
+        recognize(pair(digits1, opt(pair(tag("."), digits0)))),
+        str::parse,
+    )(inp)
+}
+
+fn signed_number(inp: &str) -> IResult<&str, f64> {
+    map_res(recognize(pair(opt(tag("-")), number)), str::parse)(inp)
+}
+
+fn uncertainty(inp: &str) -> IResult<&str, f64> {
+    terminated(
+        preceded(pair(alt((tag("±"), tag("+/-"))), space0), number),
+        opt(pair(space0, tag("m"))),
+    )(inp)
+}
+
+type Values = (f64, f64, Option<f64>);
+type Parameter<'a> = (&'a str, &'a str);
+
+fn geo_uri(inp: &str) -> IResult<&str, (Values, Vec<Parameter<'_>>)> {
+    pair(
+        preceded(
+            tag_no_case("geo:"),
+            tuple((
+                signed_number,
+                preceded(tag(","), signed_number),
+                opt(preceded(tag(","), signed_number)),
+            )),
+        ),
+        many0(preceded(
+            tag(";"),
+            separated_pair(parameter_text, tag("="), parameter_text),
+        )),
+    )(inp)
+}
+
+fn parameter_text(inp: &str) -> IResult<&str, &str> {
+    take_while1(|ch: char| ch.is_ascii_alphanumeric() || "-._~!$&'()*+:[]%".contains(ch))(inp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Format;
+
+    #[test]
+    fn should_parse_location() {
+        let coord = ISO6709Coord::from_parts(35.5, -170.1, None);
+        for str in [
+            "+35.50-170.10/±10m",
+            " N35.50W170.10/ ± 10 m ",
+            "+35.50-170.10/+/-10",
+            "GEO:35.5,-170.1;U=10",
+            "geo:35.5,-170.1;foo=bar;u=10.0",
+        ] {
+            assert_eq!(
+                parse_location(str),
+                Ok(Location {
+                    coord,
+                    uncertainty: Some(10.)
+                }),
+                "{}",
+                str
+            );
+        }
+        assert_eq!(parse_location("geo:35.5,-170.1"), Ok(coord.into()));
+        assert_eq!(parse_location("+35.50-170.10/"), Ok(coord.into()));
+        assert!(parse_location("geo:-0,0")
+            .unwrap()
+            .coord
+            .lat
+            .is_sign_positive());
+    }
+
+    #[test]
+    fn should_err_location() {
+        for str in [
+            "+35.50-170.10/±",
+            "+35.50-170.10/±-10m",
+            "+35.50-170.10/±10km",
+            "geo:35.5",
+            "geo:35.5,-170.1;u=ten",
+            "geo:35.5,-170.1 trailing",
+        ] {
+            assert_eq!(
+                parse_location(str).unwrap_err().kind(),
+                ErrorKind::Syntax,
+                "{}",
+                str
+            );
+        }
+        assert_eq!(
+            parse_location("geo:95.5,-170.1").unwrap_err().kind(),
+            ErrorKind::OutOfRange
+        );
+        assert_eq!(
+            parse_location("geo:35.5,-170.1;u=inf").unwrap_err().kind(),
+            ErrorKind::OutOfRange
+        );
+        assert_eq!(
+            parse_location("geo:35.5,-170.1;crs=nad27")
+                .unwrap_err()
+                .kind(),
+            ErrorKind::UnknownCrs
+        );
+    }
+
+    #[test]
+    fn should_format_location() {
+        let location = Location {
+            coord: ISO6709Coord::from_parts(15.5, -95.25, Some(-0.)),
+            uncertainty: Some(2.5),
+        };
+        let spec = FormatSpec {
+            format: Format::Readable,
+            decimals: 0,
+            decimal_comma: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            location.format(&spec).unwrap(),
+            "15°30′00″N 095°15′00″W 0m ±2,5m"
+        );
+        assert_eq!(location.to_geo_uri(), "geo:15.5,-95.25,0;u=2.5");
+        for str in [
+            location.format(&FormatSpec::default()).unwrap(),
+            location.to_geo_uri(),
+        ] {
+            assert_eq!(parse_location(&str), Ok(location), "{}", str);
+        }
+
+        let invalid = Location {
+            uncertainty: Some(-1.),
+            ..location
+        };
+        assert_eq!(
+            invalid.format(&spec).unwrap_err().kind(),
+            ErrorKind::OutOfRange
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use crate::ISO6709Coord;
+
+/// Number of cells along each axis, as a float
+const CELLS: f64 = 4294967296.;
+
+impl ISO6709Coord {
+    /// A Morton (Z-order) key: the latitude and longitude are each divided into 2³² cells, and the bits of the
+    /// longitude cell and latitude cell are interleaved, the longitude in the even bits and the latitude in the odd.
+    /// Sorting by the key keeps nearby coordinates mostly near each other, and every coordinate in a square that the
+    /// grid splits evenly has keys in a single range, so large datasets can be sorted and range-queried without a
+    /// spatial index. Cells are about 4.7 mm tall and 9.3 mm wide at the equator.
+    ///
+    /// Longitudes outside of ±180 are wrapped, latitudes outside of ±90 are clamped, and NaN is read as the south or
+    /// west edge. The altitude is not part of the key.
+    /// ```
+    /// # use iso6709parse::{parse, ISO6709Coord};
+    /// let coord: ISO6709Coord = parse("+00.00+000.00/").unwrap();
+    /// assert_eq!(coord.morton_key(), 0xC000_0000_0000_0000);
+    ///
+    /// let mut coords: Vec<ISO6709Coord> = ["+35.50-170.10/", "-35.50+170.10/", "+35.51-170.11/"]
+    ///     .into_iter()
+    ///     .map(|str| parse(str).unwrap())
+    ///     .collect();
+    /// coords.sort_by_key(ISO6709Coord::morton_key);
+    /// assert_eq!(coords[0].lat, -35.5);
+    /// ```
+    pub fn morton_key(&self) -> u64 {
+        let lat = cell((self.lat + 90.) / 180.);
+        let lon = cell((self.lon + 180.).rem_euclid(360.) / 360.);
+        spread(lon) | spread(lat) << 1
+    }
+}
+
+/// The cell that a fraction from 0 to 1 of the way across an axis falls in
+fn cell(fraction: f64) -> u32 {
+    // The cast saturates, so the north and east edges are in the last cell, and reads NaN as 0
+    (fraction * CELLS) as u32
+}
+
+/// Spreads the bits of `value` into the even bits of the result
+fn spread(value: u32) -> u64 {
+    let mut value = u64::from(value);
+    value = (value | value << 16) & 0x0000_FFFF_0000_FFFF;
+    value = (value | value << 8) & 0x00FF_00FF_00FF_00FF;
+    value = (value | value << 4) & 0x0F0F_0F0F_0F0F_0F0F;
+    value = (value | value << 2) & 0x3333_3333_3333_3333;
+    (value | value << 1) & 0x5555_5555_5555_5555
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(lat: f64, lon: f64) -> u64 {
+        ISO6709Coord::from_parts(lat, lon, None).morton_key()
+    }
+
+    #[test]
+    fn should_build_morton_key() {
+        assert_eq!(key(-90., -180.), 0);
+        assert_eq!(key(90., 180. - 1e-9), u64::MAX);
+        assert_eq!(key(0., -180.), 0b10 << 62);
+        assert_eq!(key(-90., 0.), 0b01 << 62);
+        assert_eq!(key(-90., 180.), 0);
+        assert_eq!(key(-90., 540.), 0);
+        assert_eq!(key(100., 0.), key(90., 0.));
+        assert_eq!(key(f64::NAN, f64::NAN), 0);
+        assert_eq!(
+            ISO6709Coord::from_parts(10., 20., Some(100.)).morton_key(),
+            key(10., 20.)
+        );
+    }
+
+    #[test]
+    fn should_keep_quadrants_in_ranges() {
+        // Every coordinate in the north-east quadrant has the top two bits set
+        for (lat, lon) in [(0., 0.), (45., 90.), (89.9, 179.9), (0.001, 120.)] {
+            assert_eq!(key(lat, lon) >> 62, 0b11, "{} {}", lat, lon);
+        }
+        assert!(key(35.5, -170.1) < key(35.51, -170.09));
+        assert_eq!(spread(0xFFFF_FFFF), 0x5555_5555_5555_5555);
+        assert_eq!(spread(0b1011), 0b1000101);
+    }
+}
@@ -0,0 +1,173 @@
+use crate::ast::{parse_ast, AngleNode, Notation, Sign, Span};
+use crate::{ErrorKind, ISO6709Coord, ISO6709Error};
+use std::ops::{Add, Div, Neg};
+
+/// Number types that [`parse_as`] can read a coordinate into, implemented for `f32` and `f64`, and for
+/// `rust_decimal::Decimal` with the `decimal` feature. The degrees, minutes, seconds and altitude are each read from
+/// the digits as written and combined in the type itself, so implementing it for another type is enough to parse
+/// into that type.
+/// ```
+/// use iso6709parse::{parse_as, CoordNumber};
+/// use std::ops::{Add, Div, Neg};
+///
+/// /// Thousandths of a degree
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// struct Milli(i64);
+///
+/// impl Add for Milli {
+///     type Output = Milli;
+///     fn add(self, other: Milli) -> Milli { Milli(self.0 + other.0) }
+/// }
+/// impl Div for Milli {
+///     type Output = Milli;
+///     fn div(self, other: Milli) -> Milli { Milli(self.0 * 1000 / other.0) }
+/// }
+/// impl Neg for Milli {
+///     type Output = Milli;
+///     fn neg(self) -> Milli { Milli(-self.0) }
+/// }
+/// impl CoordNumber for Milli {
+///     fn from_digits(digits: &str) -> Option<Milli> {
+///         digits.parse::<f64>().ok().map(|value| Milli((value * 1000.).round() as i64))
+///     }
+///     fn from_u16(value: u16) -> Milli { Milli(i64::from(value) * 1000) }
+/// }
+///
+/// let coord = parse_as::<Milli>("+4520.30-02130.00/").unwrap();
+/// assert_eq!((coord.lat, coord.lon), (Milli(45338), Milli(-21500)));
+/// ```
+pub trait CoordNumber: Copy + Add<Output = Self> + Div<Output = Self> + Neg<Output = Self> {
+    /// Reads a degree, minute, second or altitude from its digits as written, such as `020.30` or `-8712.5`.
+    /// `None` when the type cannot hold the number.
+    fn from_digits(digits: &str) -> Option<Self>;
+
+    /// The whole number `value`, for the 60 minutes in a degree and 3600 seconds
+    fn from_u16(value: u16) -> Self;
+}
+
+impl CoordNumber for f64 {
+    fn from_digits(digits: &str) -> Option<Self> {
+        digits.parse().ok().filter(|value: &f64| value.is_finite())
+    }
+
+    fn from_u16(value: u16) -> Self {
+        f64::from(value)
+    }
+}
+
+impl CoordNumber for f32 {
+    fn from_digits(digits: &str) -> Option<Self> {
+        digits.parse().ok().filter(|value: &f32| value.is_finite())
+    }
+
+    fn from_u16(value: u16) -> Self {
+        f32::from(value)
+    }
+}
+
+/// Parses either of the two formats into any [`CoordNumber`], reading the values and doing the degree, minute and
+/// second arithmetic in `T` rather than narrowing or widening an `f64` result. `parse_as::<f32>` gives the nearest
+/// `f32` to each number as written, where [`crate::parse`] into `ISO6709Coord<f32>` rounds twice.
+/// Like [`crate::ast::parse_ast`], the whole string (other than surrounding whitespace) must be part of the
+/// coordinate. As with [`crate::parse`], an altitude in the string representation without a CRS is left out.
+/// ```
+/// # use iso6709parse::parse_as;
+/// let coord = parse_as::<f32>("N35.50W170.10+8712.5CRSWGS_84/").unwrap();
+/// assert_eq!((coord.lat, coord.lon, coord.altitude), (35.5f32, -170.1f32, Some(8712.5f32)));
+///
+/// let coord = parse_as::<f64>("50°40′46.461″N 95°48′26.533″W").unwrap();
+/// assert_eq!(coord.lat, 50. + 40. / 60. + 46.461 / 3600.);
+/// ```
+pub fn parse_as<T: CoordNumber>(str: &str) -> Result<ISO6709Coord<T>, ISO6709Error> {
+    let ast = parse_ast(str)?;
+    let lat = angle(str, &ast.latitude)?;
+    let lon = angle(str, &ast.longitude)?;
+    let altitude = match &ast.altitude {
+        Some(altitude) if ast.notation == Notation::Readable || altitude.crs.is_some() => {
+            Some(number(str, altitude.value.span)?)
+        }
+        _ => None,
+    };
+    Ok(ISO6709Coord { lat, lon, altitude })
+}
+
+fn number<T: CoordNumber>(source: &str, span: Span) -> Result<T, ISO6709Error> {
+    let text = &source[span.start..span.end];
+    T::from_digits(text).ok_or_else(|| {
+        ISO6709Error::new(
+            ErrorKind::OutOfRange,
+            format!("`{}` cannot be held by the number type", text),
+        )
+    })
+}
+
+fn angle<T: CoordNumber>(source: &str, node: &AngleNode) -> Result<T, ISO6709Error> {
+    let mut value: T = number(source, node.degrees.span)?;
+    if let Some(minutes) = &node.minutes {
+        value = value + number::<T>(source, minutes.span)? / T::from_u16(60);
+    }
+    if let Some(seconds) = &node.seconds {
+        value = value + number::<T>(source, seconds.span)? / T::from_u16(3600);
+    }
+    Ok(match node.sign.value {
+        Sign::Positive => value,
+        Sign::Negative => -value,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_f32_from_the_digits() {
+        let coord = parse_as::<f32>("+4520.30-02130.00").unwrap();
+        assert_eq!(coord.lat, 45. + 20.3 / 60.);
+        assert_eq!(coord.lon, -21.5);
+
+        let coord = parse_as::<f32>("15°30′00.000″N 95°15′00.000″W -123.45m").unwrap();
+        assert_eq!((coord.lat, coord.lon), (15.5, -95.25));
+        assert_eq!(coord.altitude, Some(-123.45));
+
+        // Just over halfway between two f32, which f64 rounds to exactly halfway and then to the even f32 below
+        let digits = "00.50000002980232239";
+        assert_eq!(digits.parse::<f64>().unwrap() as f32, 0.5);
+        let coord = parse_as::<f32>(&format!("+{}+000/", digits)).unwrap();
+        assert_eq!(coord.lat, 0.50000006);
+    }
+
+    #[test]
+    fn should_agree_with_parse_for_f64() {
+        for str in [
+            "+35.50-170.10/",
+            "S452035.1528E1452018/",
+            "+4520.30-02130.00+8712CRSWGS_84/",
+            "50°40′46.461″N 95°48′26.533″W 123.45m",
+        ] {
+            let expected: ISO6709Coord = crate::parse(str).unwrap();
+            let coord = parse_as::<f64>(str).unwrap();
+            // `parse` adds the fraction of the last component separately, which can round differently in the last bit
+            assert!((coord.lat - expected.lat).abs() < 1e-12, "{}", str);
+            assert!((coord.lon - expected.lon).abs() < 1e-12, "{}", str);
+            assert_eq!(coord.altitude, expected.altitude, "{}", str);
+        }
+    }
+
+    #[test]
+    fn should_leave_out_altitude_without_crs() {
+        let coord = parse_as::<f32>("+35.50-170.10+12/").unwrap();
+        assert_eq!(coord, ISO6709Coord::from_parts(35.5, -170.1, None));
+    }
+
+    #[test]
+    fn should_err_as() {
+        assert!(parse_as::<f32>("+90.1-021.0").is_err());
+        assert!(parse_as::<f32>("N35.50W170.10 trailing").is_err());
+        let huge = format!("+35.50-170.10+1{}CRSWGS_84/", "0".repeat(39));
+        assert_eq!(
+            parse_as::<f32>(&huge).unwrap_err().kind(),
+            ErrorKind::OutOfRange
+        );
+        assert!(parse_as::<f64>(&huge).is_ok());
+    }
+}
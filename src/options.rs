@@ -0,0 +1,179 @@
+use crate::{Crs, CrsRegistry, ISO6709Coord, PrecisionTarget, Symbols};
+use std::fmt;
+use std::sync::Arc;
+
+/// How closely the input has to follow ISO6709
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum Leniency {
+    /// Accepts the same inputs as [`crate::parse`], up to [`ParserOptions::max_input_len`], except that an altitude
+    /// without a CRS fails rather than being left out, see [`ParserOptions::require_crs`]
+    #[default]
+    Standard,
+    /// Also accepts common deviations from sloppy producers, such as a lowercase `crs` tag, an altitude without a
+    /// CRS, or a sign along with a hemisphere letter, in which case the hemisphere letter is used.
+    /// Coordinates with the directions spelled out, as in OCR'd and transcribed documents, are read as well, such as
+    /// `North 50 degrees 40 minutes 46 seconds, West 95 degrees 48 minutes 27 seconds` or `50.68 North, 95.81 West`.
+    /// Quotes, parentheses and brackets around the whole input are stripped, as in `"(15°30′00″N 95°15′00″W)"`, and so
+    /// is punctuation ending a sentence after the coordinate, so that the period of `15°30′00″N 95°15′00″W 12.` is not
+    /// read as the decimal point of the altitude.
+    Lenient,
+}
+
+/// What [`crate::parse_with_options`] does with an altitude, see [`ParserOptions::altitude`]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum AltitudeMode {
+    #[default]
+    Keep,
+    /// Drops the altitude, along with its vertical datum, notation and precision, for consumers that only take 2D
+    /// coordinates. The input must still be valid with the altitude, so an altitude without a CRS in the string
+    /// representation fails as it otherwise would.
+    Ignore,
+    /// Fails with [`crate::ErrorKind::UnexpectedAltitude`] if there is an altitude
+    Reject,
+}
+
+/// The hemispheres of latitudes and longitudes written without a sign or hemisphere letter, see
+/// [`ParserOptions::default_hemispheres`]. The default is north and east.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Hemispheres {
+    /// South rather than north
+    pub south: bool,
+    /// West rather than east
+    pub west: bool,
+}
+
+/// A check of the parsed coordinate for rules of the application, such as altitude limits or restricted zones,
+/// set in [`ParserOptions::validator`]. A coordinate that fails it fails to parse with
+/// [`crate::ErrorKind::Rejected`] and the message returned by the check.
+/// ```
+/// # use iso6709parse::{parse_with_options, ErrorKind, ISO6709Coord, ParserOptions, Validator};
+/// let options = ParserOptions {
+///     validator: Some(Validator::new(|coord: &ISO6709Coord| match coord.altitude {
+///         Some(altitude) if altitude > 8849. => Err(format!("altitude {altitude} is above Everest")),
+///         _ => Ok(()),
+///     })),
+///     ..Default::default()
+/// };
+/// assert!(parse_with_options::<ISO6709Coord>("+27.99+086.93+8848CRSWGS_84/", &options).is_ok());
+/// let err = parse_with_options::<ISO6709Coord>("+27.99+086.93+9000CRSWGS_84/", &options).unwrap_err();
+/// assert_eq!(err.kind(), ErrorKind::Rejected);
+/// ```
+#[derive(Clone)]
+pub struct Validator(Arc<Check>);
+
+type Check = dyn Fn(&ISO6709Coord) -> Result<(), String> + Send + Sync;
+
+impl Validator {
+    pub fn new(
+        check: impl Fn(&ISO6709Coord) -> Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        Validator(Arc::new(check))
+    }
+
+    pub(crate) fn validate(&self, coord: &ISO6709Coord) -> Result<(), String> {
+        (self.0)(coord)
+    }
+}
+
+impl fmt::Debug for Validator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Validator")
+    }
+}
+
+/// Validators are equal only when they are clones of each other
+impl PartialEq for Validator {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Settings for [`crate::parse_with_options`]. The default options parse the same inputs as [`crate::parse`], except
+/// for those longer than [`ParserOptions::DEFAULT_MAX_INPUT_LEN`] bytes and those with an altitude without a CRS.
+/// ```
+/// # use iso6709parse::{CrsRegistry, ParserOptions};
+/// let options = ParserOptions {
+///     crs_registry: Some(CrsRegistry::default()),
+///     ..Default::default()
+/// };
+/// ```
+/// [`crate::Profile`] has presets for common sources of coordinates.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ParserOptions {
+    /// When set, a CRS in the string representation that is not in the registry fails with [`crate::ErrorKind::UnknownCrs`]
+    /// instead of being accepted as is.
+    pub crs_registry: Option<CrsRegistry>,
+    pub leniency: Leniency,
+    /// Where the coordinates are expected to be. When set, a coordinate outside the region that would be inside it
+    /// with latitude and longitude swapped gets a [`crate::Warning::LikelySwapped`]. The region can cross the
+    /// antimeridian, as returned by [`crate::parse_bbox`].
+    #[cfg(feature = "geo-types")]
+    pub expected_region: Option<geo_types::Rect>,
+    /// Inputs longer than this many bytes fail with [`crate::ErrorKind::InputTooLong`] before any parsing is done,
+    /// which guards services that parse untrusted strings. `None` accepts any length.
+    pub max_input_len: Option<usize>,
+    /// Numbers written with an exponent, such as `+3.55e1-1.701e2/`, fail with
+    /// [`crate::ErrorKind::ScientificNotation`] unless this is set. When set, they are written out in full before
+    /// parsing, and in the string representation are read as decimal degrees. Only a lowercase `e` is an exponent,
+    /// since `E` is the east hemisphere letter.
+    pub allow_exponent: bool,
+    /// Whether an altitude in the string representation without a CRS, such as `+35.50-170.10+12/`, fails with
+    /// [`crate::ErrorKind::MissingCrs`], as ISO6709 requires. `None` requires the CRS unless the
+    /// [`ParserOptions::leniency`] is [`Leniency::Lenient`].
+    pub require_crs: Option<bool>,
+    /// Reads a comma between two digits as a decimal point, in the latitude and longitude as well as the altitude,
+    /// as in `+35,50-170,10+8712,5CRSWGS_84/` or `123,45m`
+    pub decimal_comma: bool,
+    /// The CRS of coordinates that do not give one, which is recorded in [`crate::Position::crs`] with
+    /// [`crate::Position::crs_assumed`] set. `None` leaves the CRS of those positions unknown.
+    pub default_crs: Option<Crs>,
+    /// Whether the altitude is kept, dropped or rejected, to get strictly 2D coordinates
+    pub altitude: AltitudeMode,
+    /// The hemispheres of a latitude or longitude written without a sign or hemisphere letter, such as both halves of
+    /// `15°30′00″ 95°15′00″` or the latitude of `35.50-170.10/`, for regional datasets that leave out the hemisphere
+    /// because it goes without saying. The assumption is recorded in [`crate::Position::hemisphere_assumed`].
+    /// `None` fails to parse those coordinates, as ISO6709 requires a sign or hemisphere letter.
+    /// A longitude in the string representation needs one anyway, to tell where the latitude ends.
+    pub default_hemispheres: Option<Hemispheres>,
+    /// The degree, minute and second markers, decimal separators and hemisphere letters of the readable format
+    pub symbols: Symbols,
+    /// Run on every parsed coordinate, see [`Validator`]
+    pub validator: Option<Validator>,
+    /// Where the coordinates are going. When set, a coordinate that would lose precision it was written with there
+    /// gets a [`crate::Warning::PrecisionLoss`].
+    pub precision_target: Option<PrecisionTarget>,
+}
+
+impl ParserOptions {
+    /// Default for [`ParserOptions::max_input_len`], far beyond the length of any real ISO6709 string
+    pub const DEFAULT_MAX_INPUT_LEN: usize = 256;
+
+    /// Whether an altitude needs a CRS, see [`ParserOptions::require_crs`]
+    pub fn requires_crs(&self) -> bool {
+        self.require_crs
+            .unwrap_or(self.leniency != Leniency::Lenient)
+    }
+}
+
+impl Default for ParserOptions {
+    fn default() -> Self {
+        ParserOptions {
+            crs_registry: None,
+            leniency: Leniency::default(),
+            #[cfg(feature = "geo-types")]
+            expected_region: None,
+            max_input_len: Some(Self::DEFAULT_MAX_INPUT_LEN),
+            allow_exponent: false,
+            require_crs: None,
+            decimal_comma: false,
+            default_crs: Some(Crs::Wgs84),
+            default_hemispheres: None,
+            altitude: AltitudeMode::Keep,
+            symbols: Symbols::default(),
+            validator: None,
+            precision_target: None,
+        }
+    }
+}
@@ -1,7 +1,8 @@
+use crate::parsers::common::{digits1, take_while_capped, MAX_DIGITS};
 use nom::branch::alt;
-use nom::bytes::complete::tag;
-use nom::bytes::complete::{is_not, take_while};
-use nom::character::complete::{alpha1, digit1};
+use nom::bytes::complete::is_not;
+use nom::bytes::complete::{tag, tag_no_case};
+use nom::character::complete::{alpha1, space0};
 use nom::character::is_digit;
 use nom::combinator::map_res;
 use nom::combinator::value;
@@ -10,6 +11,8 @@ use nom::IResult;
 
 pub mod human_readable {
     use super::*;
+    use crate::parsers::common::{parse_with_separator, symbol};
+    use crate::Symbols;
     //     50°40′46.461″N 95°48′26.533″W 123.45m
     //     50°03′46.461″S 125°48′26.533″E 978.90m
     fn parse_sign(inp: &str) -> IResult<&str, f64> {
@@ -24,7 +27,10 @@ pub mod human_readable {
         ch.is_ascii() && (is_digit(ch as u8) || ch == '.')
     }
     fn altitude_decimal(inp: &str) -> IResult<&str, f64> {
-        map_res(take_while(is_part_of_float), |x: &str| x.parse::<f64>())(inp)
+        map_res(
+            take_while_capped(0, MAX_DIGITS, is_part_of_float),
+            |x: &str| x.parse::<f64>(),
+        )(inp)
     }
 
     pub fn altitude_parser(inp: &str) -> IResult<&str, f64> {
@@ -33,10 +39,45 @@ pub mod human_readable {
         Ok((rem, alt * mag))
     }
 
-    #[allow(dead_code)]
-    /// Follows only after using altitude_parser
+    /// Same as `altitude_parser`, with the decimal separators in `symbols`
+    pub(crate) fn altitude_parser_with<'s, 'a>(
+        symbols: &'s Symbols,
+    ) -> impl Fn(&'a str) -> IResult<&'a str, f64> + 's {
+        move |inp| {
+            if symbols.decimal == ["."] {
+                return altitude_parser(inp);
+            }
+            let (mut rem, mag) = parse_sign(inp)?;
+            let start = rem;
+            loop {
+                if let Ok((next, _)) = symbol(&symbols.decimal)(rem) {
+                    rem = next;
+                } else if rem.starts_with(|ch: char| ch.is_ascii_digit()) {
+                    rem = &rem[1..];
+                } else {
+                    break;
+                }
+                if start.len() - rem.len() > MAX_DIGITS {
+                    return Err(nom::Err::Failure(nom::error::Error::new(
+                        start,
+                        nom::error::ErrorKind::TooLarge,
+                    )));
+                }
+            }
+            let number = &start[..start.len() - rem.len()];
+            match parse_with_separator(number, &symbols.decimal) {
+                Ok(alt) => Ok((rem, alt * mag)),
+                Err(_) => Err(nom::Err::Error(nom::error::Error::new(
+                    start,
+                    nom::error::ErrorKind::MapRes,
+                ))),
+            }
+        }
+    }
+
+    /// Follows only after using altitude_parser. The unit may be separated from the value by whitespace, as in `123.45 m`
     pub fn altitude_unit(inp: &str) -> IResult<&str, &str> {
-        alpha1(inp)
+        preceded(space0, alpha1)(inp)
     }
 
     #[cfg(test)]
@@ -51,6 +92,24 @@ pub mod human_readable {
             assert_eq!(altitude_parser(inp), Ok(("m", -978.9)));
 
             assert_eq!(altitude_unit("m"), Ok(("", "m")));
+            assert_eq!(altitude_unit(" m"), Ok(("", "m")));
+        }
+
+        #[test]
+        fn should_parse_alt_with_symbols() {
+            let symbols = Symbols {
+                decimal: vec![",".to_string()],
+                ..Default::default()
+            };
+            assert_eq!(
+                altitude_parser_with(&symbols)("-978,90m"),
+                Ok(("m", -978.9))
+            );
+            assert_eq!(
+                altitude_parser_with(&symbols)("978.90m"),
+                Ok((".90m", 978.))
+            );
+            assert!(altitude_parser_with(&symbols)("m").is_err());
         }
 
         #[test]
@@ -62,6 +121,9 @@ pub mod human_readable {
 }
 pub mod string_expression {
     use super::*;
+    use crate::parsers::common::Component;
+    use crate::Leniency;
+    use nom::combinator::consumed;
 
     fn parse_positive(inp: &str) -> IResult<&str, f64> {
         value(1., tag("+"))(inp)
@@ -85,10 +147,13 @@ pub mod string_expression {
         ch.is_ascii() && (is_digit(ch as u8) || ch == '.')
     }
     fn altitude_decimal(inp: &str) -> IResult<&str, f64> {
-        map_res(take_while(is_part_of_float), |x: &str| x.parse::<f64>())(inp)
+        map_res(
+            take_while_capped(0, MAX_DIGITS, is_part_of_float),
+            |x: &str| x.parse::<f64>(),
+        )(inp)
     }
     fn altitude_int(inp: &str) -> IResult<&str, f64> {
-        map_res(digit1, |x: &str| x.parse::<f64>())(inp)
+        map_res(digits1, |x: &str| x.parse::<f64>())(inp)
     }
 
     fn parse_altitude_digits(inp: &str) -> IResult<&str, f64> {
@@ -105,6 +170,47 @@ pub mod string_expression {
         Ok((reference_system, alt))
     }
 
+    /// Parses the string that contains altitude AND the crs.
+    /// +2122CRSWGS_85
+    /// Returns both the altitude in f64 and the CRS (Coordinate Reference System)
+    pub(crate) fn altitude_crs_parser(altitude_with_crs: &str) -> IResult<&str, (f64, &str)> {
+        pair(altitude_parser, is_not("/"))(altitude_with_crs)
+    }
+
+    /// Parses an altitude without a CRS, directly followed by the solidus, whitespace or the end of the string.
+    /// +2122/
+    fn bare_altitude_parser(inp: &str) -> IResult<&str, f64> {
+        let (rem, alt) = parse_altitude_digits(inp)?;
+        if rem.is_empty() || rem.starts_with(|ch: char| ch == '/' || ch.is_whitespace()) {
+            Ok((rem, alt))
+        } else {
+            Err(nom::Err::Error(nom::error::Error::new(
+                rem,
+                nom::error::ErrorKind::Tag,
+            )))
+        }
+    }
+
+    /// Same as `altitude_crs_parser`, with the text of the altitude, following the `leniency`.
+    /// When lenient, the `CRS` tag is case insensitive: +2122crsWGS_85
+    /// The CRS is `None` when the altitude is directly followed by the solidus or the end of the string: +2122/
+    pub(crate) fn altitude_parts<'a>(
+        leniency: Leniency,
+    ) -> impl Fn(&'a str) -> IResult<&'a str, (Component<'a>, Option<&'a str>)> {
+        move |altitude_with_crs| {
+            if let Ok((rem, altitude)) = consumed(bare_altitude_parser)(altitude_with_crs) {
+                return Ok((rem, (altitude, None)));
+            }
+            let (rem, altitude) = consumed(parse_altitude_digits)(altitude_with_crs)?;
+            let (rem, _) = match leniency {
+                Leniency::Lenient => tag_no_case("CRS")(rem)?,
+                _ => tag("CRS")(rem)?,
+            };
+            let (rem, crs) = is_not("/")(rem)?;
+            Ok((rem, (altitude, Some(crs))))
+        }
+    }
+
     #[allow(dead_code)]
     /// Parses the string that contains altitude AND the crs.
     /// +2122CRSWGS_85
@@ -139,6 +245,45 @@ pub mod string_expression {
             assert_eq!(crs_parser(inp), Ok(("/", "WGS_85")));
         }
 
+        #[test]
+        fn should_parse_altitude_crs() {
+            let inp = "-2122.5CRSWGS_84/";
+            assert_eq!(altitude_crs_parser(inp), Ok(("/", (-2122.5, "WGS_84"))));
+        }
+
+        #[test]
+        fn should_parse_crs_tag_case_when_lenient() {
+            let inp = "+2122CRSWGS_84/";
+            assert_eq!(
+                altitude_parts(Leniency::Standard)(inp),
+                Ok(("/", (("+2122", 2122.), Some("WGS_84"))))
+            );
+            let inp = "+2122crsWGS_84/";
+            assert!(altitude_parts(Leniency::Standard)(inp).is_err());
+
+            let inp = "+2122crsWGS_84/";
+            assert_eq!(
+                altitude_parts(Leniency::Lenient)(inp),
+                Ok(("/", (("+2122", 2122.), Some("WGS_84"))))
+            );
+            let inp = "+2122CrsWGS_84/";
+            assert_eq!(
+                altitude_parts(Leniency::Lenient)(inp),
+                Ok(("/", (("+2122", 2122.), Some("WGS_84"))))
+            );
+        }
+
+        #[test]
+        fn should_parse_altitude_without_crs() {
+            let parts = altitude_parts(Leniency::Standard);
+            assert_eq!(parts("+2122/"), Ok(("/", (("+2122", 2122.), None))));
+            assert_eq!(parts("-12.5"), Ok(("", (("-12.5", -12.5), None))));
+            assert!(parts("+2122WGS_84/").is_err());
+            assert_eq!(bare_altitude_parser("+2122/"), Ok(("/", 2122.)));
+            assert_eq!(bare_altitude_parser("-12.5 "), Ok((" ", -12.5)));
+            assert!(bare_altitude_parser("+2122CRSWGS_84/").is_err());
+        }
+
         #[test]
         fn should_err_crs() {
             let inp = "+2122CRS";
@@ -1,9 +1,88 @@
-use nom::branch::alt;
-use nom::bytes::complete::tag;
-use nom::character::complete::digit1;
-use nom::combinator::{map_res, opt, recognize};
+use nom::bytes::complete::take_while_m_n;
+use nom::combinator::{consumed, map_res, opt, recognize};
 use nom::sequence::{terminated, tuple};
 use nom::IResult;
+use std::num::ParseFloatError;
+
+/// Longest run of digits accepted for a single number. An `f64` holds at most 17 significant digits, so longer
+/// runs cannot add precision; they are rejected before being scanned to the end or handed to `str::parse`.
+pub(crate) const MAX_DIGITS: usize = 40;
+
+/// Same as `take_while_m_n(min, max, cond)`, except that more than `max` matching characters is a failure
+/// tagged with `nom::error::ErrorKind::TooLarge` instead of leaving the rest of the run unparsed.
+pub(crate) fn take_while_capped<'a>(
+    min: usize,
+    max: usize,
+    cond: fn(char) -> bool,
+) -> impl Fn(&'a str) -> IResult<&'a str, &'a str> {
+    move |inp| {
+        let (rem, run) = take_while_m_n(min, max + 1, cond)(inp)?;
+        if run.len() > max {
+            Err(nom::Err::Failure(nom::error::Error::new(
+                inp,
+                nom::error::ErrorKind::TooLarge,
+            )))
+        } else {
+            Ok((rem, run))
+        }
+    }
+}
+
+/// The text of a component as written, along with its value
+pub(crate) type Component<'a> = (&'a str, f64);
+
+/// A latitude or longitude as written, which the syntax tree of [`crate::ast`] is built from. The decimal fraction
+/// belongs to the last component present, as it does in the input, so `+4520.30` has `20.30` minutes and no seconds.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) struct AngleParts<'a> {
+    /// The hemisphere letter or sign, along with `1.` or `-1.`
+    pub(crate) sign: Component<'a>,
+    pub(crate) degrees: Component<'a>,
+    pub(crate) minutes: Option<Component<'a>>,
+    pub(crate) seconds: Option<Component<'a>>,
+    /// The signed value in decimal degrees
+    pub(crate) value: f64,
+}
+
+impl<'a> AngleParts<'a> {
+    /// Splits `body`, the number of a string representation angle, into degrees of `degree_width` digits and then
+    /// two digits each of minutes and seconds, as many as there are
+    pub(crate) fn split(
+        sign: Component<'a>,
+        body: &'a str,
+        degree_width: usize,
+        value: f64,
+    ) -> AngleParts<'a> {
+        let integer = body.find('.').unwrap_or(body.len());
+        let component = |start: usize, end: usize| {
+            let text = if end >= integer {
+                &body[start..]
+            } else {
+                &body[start..end]
+            };
+            // Only ever digits, with the fraction on the last one
+            (text, text.parse().unwrap_or(0.))
+        };
+        AngleParts {
+            sign,
+            degrees: component(0, degree_width),
+            minutes: (integer > degree_width).then(|| component(degree_width, degree_width + 2)),
+            seconds: (integer > degree_width + 2)
+                .then(|| component(degree_width + 2, degree_width + 4)),
+            value,
+        }
+    }
+}
+
+/// Zero to `MAX_DIGITS` ascii digits
+pub(crate) fn digits0(inp: &str) -> IResult<&str, &str> {
+    take_while_capped(0, MAX_DIGITS, |c| c.is_ascii_digit())(inp)
+}
+
+/// One to `MAX_DIGITS` ascii digits
+pub(crate) fn digits1(inp: &str) -> IResult<&str, &str> {
+    take_while_capped(1, MAX_DIGITS, |c| c.is_ascii_digit())(inp)
+}
 
 #[cfg(test)]
 pub(crate) fn assert_float_approx<E: std::fmt::Debug>(
@@ -19,28 +98,169 @@ pub(crate) fn assert_float_approx<E: std::fmt::Debug>(
     )
 }
 
+/// Any one of `symbols`, as configured in [`crate::Symbols`]
+pub(crate) fn symbol<'s, 'a>(
+    symbols: &'s [String],
+) -> impl Fn(&'a str) -> IResult<&'a str, &'a str> + 's {
+    move |inp| {
+        symbols
+            .iter()
+            .find(|symbol| !symbol.is_empty() && inp.starts_with(symbol.as_str()))
+            .map(|symbol| (&inp[symbol.len()..], &inp[..symbol.len()]))
+            .ok_or_else(|| nom::Err::Error(nom::error::Error::new(inp, nom::error::ErrorKind::Tag)))
+    }
+}
+
+/// Reads a number written with any of the `decimal` separators
+pub(crate) fn parse_with_separator(
+    number: &str,
+    decimal: &[String],
+) -> Result<f64, ParseFloatError> {
+    match decimal
+        .iter()
+        .find(|separator| *separator != "." && number.contains(separator.as_str()))
+    {
+        Some(separator) => number.replacen(separator.as_str(), ".", 1).parse(),
+        None => number.parse(),
+    }
+}
+
 pub(crate) mod human_readable {
     use super::*;
+    use crate::Symbols;
+    use nom::branch::alt;
+    use nom::combinator::value;
+
     pub(crate) fn parse_value(inp: &str) -> IResult<&str, f64> {
-        map_res(digit1, |x: &str| x.parse::<f64>())(inp)
+        map_res(digits1, |x: &str| x.parse::<f64>())(inp)
+    }
+
+    pub(crate) fn parse_degree_with<'s, 'a>(
+        symbols: &'s Symbols,
+    ) -> impl Fn(&'a str) -> IResult<&'a str, Component<'a>> + 's {
+        move |inp| terminated(consumed(parse_value), symbol(&symbols.degree))(inp)
+    }
+
+    pub(crate) fn parse_minutes_with<'s, 'a>(
+        symbols: &'s Symbols,
+    ) -> impl Fn(&'a str) -> IResult<&'a str, Component<'a>> + 's {
+        move |inp| terminated(consumed(parse_value), symbol(&symbols.minute))(inp)
+    }
+
+    pub(crate) fn parse_seconds_with_decimal_with<'s, 'a>(
+        symbols: &'s Symbols,
+    ) -> impl Fn(&'a str) -> IResult<&'a str, f64> + 's {
+        move |inp| {
+            map_res(
+                recognize(tuple((
+                    digits1,
+                    opt(tuple((symbol(&symbols.decimal), digits1))),
+                ))),
+                |x: &str| parse_with_separator(x, &symbols.decimal),
+            )(inp)
+        }
+    }
+
+    pub(crate) fn parse_seconds_with<'s, 'a>(
+        symbols: &'s Symbols,
+    ) -> impl Fn(&'a str) -> IResult<&'a str, Component<'a>> + 's {
+        move |inp| {
+            terminated(
+                consumed(parse_seconds_with_decimal_with(symbols)),
+                symbol(&symbols.second),
+            )(inp)
+        }
     }
 
-    pub(crate) fn parse_degree(inp: &str) -> IResult<&str, f64> {
-        terminated(parse_value, tag("°"))(inp)
+    /// The degrees, minutes and seconds of a readable angle, before its hemisphere
+    pub(crate) fn parse_dms_with<'s, 'a>(
+        symbols: &'s Symbols,
+    ) -> impl Fn(&'a str) -> IResult<&'a str, (Component<'a>, Component<'a>, Component<'a>)> + 's
+    {
+        move |inp| {
+            tuple((
+                parse_degree_with(symbols),
+                parse_minutes_with(symbols),
+                parse_seconds_with(symbols),
+            ))(inp)
+        }
     }
 
-    pub(crate) fn parse_minutes(inp: &str) -> IResult<&str, f64> {
-        terminated(parse_value, alt((tag("'"), tag("′"))))(inp)
+    /// One of the `hemispheres`, the positive one first, along with `1.` or `-1.`
+    pub(crate) fn parse_hemisphere_with<'s, 'a>(
+        hemispheres: (&'s [String], &'s [String]),
+    ) -> impl Fn(&'a str) -> IResult<&'a str, Component<'a>> + 's {
+        move |inp| {
+            consumed(alt((
+                value(1., symbol(hemispheres.0)),
+                value(-1., symbol(hemispheres.1)),
+            )))(inp)
+        }
     }
 
-    pub(crate) fn parse_seconds_with_decimal(inp: &str) -> IResult<&str, f64> {
-        map_res(
-            recognize(tuple((digit1, opt(tuple((tag("."), digit1)))))),
-            |x: &str| x.parse::<f64>(),
-        )(inp)
+    /// A readable latitude or longitude: degrees, minutes and seconds, then one of the `hemispheres`, the positive
+    /// one first. Fails if it is more than `limit` degrees.
+    pub(crate) fn parse_angle_with<'s, 'a>(
+        symbols: &'s Symbols,
+        hemispheres: (&'s [String], &'s [String]),
+        limit: f64,
+    ) -> impl Fn(&'a str) -> IResult<&'a str, AngleParts<'a>> + 's {
+        move |inp| {
+            let (rem, (degrees, minutes, seconds)) = parse_dms_with(symbols)(inp)?;
+            let (rem, sign) = parse_hemisphere_with(hemispheres)(rem)?;
+            let (deg, min, sec) = (degrees.1, minutes.1, seconds.1);
+            let value = deg + min / 60. + sec / 3600.;
+            if value > limit {
+                Err(nom::Err::Failure(nom::error::Error::new(
+                    inp,
+                    nom::error::ErrorKind::Fail,
+                )))
+            } else {
+                Ok((
+                    rem,
+                    AngleParts {
+                        sign,
+                        degrees,
+                        minutes: Some(minutes),
+                        seconds: Some(seconds),
+                        value: sign.1 * value,
+                    },
+                ))
+            }
+        }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_symbols() {
+        let symbols = ["′".to_string(), "m".to_string()];
+        assert_eq!(symbol(&symbols)("m1"), Ok(("1", "m")));
+        assert!(symbol(&symbols)("″").is_err());
+        assert!(symbol(&[String::new()])("1").is_err());
+
+        let decimal = [".".to_string(), ",".to_string()];
+        assert_eq!(parse_with_separator("12,5", &decimal), Ok(12.5));
+        assert_eq!(parse_with_separator("12.5", &decimal), Ok(12.5));
+    }
+
+    #[test]
+    fn should_cap_digit_runs() {
+        let inp = "1".repeat(MAX_DIGITS);
+        assert_eq!(digits1(&inp), Ok(("", inp.as_str())));
+        assert_eq!(digits0("a"), Ok(("a", "")));
+        assert!(digits1("a").is_err());
 
-    pub(crate) fn parse_seconds(inp: &str) -> IResult<&str, f64> {
-        terminated(parse_seconds_with_decimal, alt((tag("\""), tag("″"))))(inp)
+        let inp = "1".repeat(10_000);
+        assert_eq!(
+            digits0(&inp),
+            Err(nom::Err::Failure(nom::error::Error::new(
+                inp.as_str(),
+                nom::error::ErrorKind::TooLarge
+            )))
+        );
     }
 }
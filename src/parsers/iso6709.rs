@@ -3,10 +3,12 @@ use nom::IResult;
 pub mod human_readable {
     use super::*;
     use crate::parsers::altitude::human_readable::*;
+    use crate::parsers::common::{AngleParts, Component};
     use crate::parsers::latitude::human_readable::*;
     use crate::parsers::longitude::human_readable::*;
+    use crate::Symbols;
     use nom::character::complete::space1;
-    use nom::combinator::opt;
+    use nom::combinator::{consumed, map, opt};
     use nom::sequence::{preceded, separated_pair, tuple};
 
     /// Parser to obtain lat long
@@ -59,6 +61,36 @@ pub mod human_readable {
         tuple((latlong_parser, opt(preceded(space1, altitude_parser))))(inp)
     }
 
+    /// Same as `latlong_altitude_option_parser`, with the markers in `symbols`
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn latlong_altitude_option_parser_with<'s, 'a>(
+        symbols: &'s Symbols,
+    ) -> impl Fn(&'a str) -> IResult<&'a str, ((f64, f64), Option<f64>)> + 's {
+        move |inp| {
+            map(
+                latlong_altitude_parts_with(symbols),
+                |(lat, lon, altitude)| ((lat.value, lon.value), altitude.map(|(_, alt)| alt)),
+            )(inp)
+        }
+    }
+
+    /// Same as `latlong_altitude_option_parser_with`, with the text of each component
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn latlong_altitude_parts_with<'s, 'a>(
+        symbols: &'s Symbols,
+    ) -> impl Fn(&'a str) -> IResult<&'a str, (AngleParts<'a>, AngleParts<'a>, Option<Component<'a>>)> + 's
+    {
+        move |inp| {
+            let (rem, (lat, _, lon, altitude)) = tuple((
+                latitude_parts_with(symbols),
+                space1,
+                longitude_parts_with(symbols),
+                opt(preceded(space1, consumed(altitude_parser_with(symbols)))),
+            ))(inp)?;
+            Ok((rem, (lat, lon, altitude)))
+        }
+    }
+
     #[cfg(test)]
     mod human_readable_tests {
         use super::*;
@@ -88,10 +120,16 @@ pub mod human_readable {
 
 pub mod string_expression {
     use super::*;
-    pub(crate) use crate::parsers::altitude::string_expression::altitude_parser;
+    pub(crate) use crate::parsers::altitude::string_expression::{
+        altitude_crs_parser, altitude_parser, altitude_parts,
+    };
+    use crate::parsers::common::{AngleParts, Component};
     pub use crate::parsers::latitude::string_expression::latitude_parser;
+    pub(crate) use crate::parsers::latitude::string_expression::latitude_parts;
     pub use crate::parsers::longitude::string_expression::longitude_parser;
-    use nom::combinator::opt;
+    pub(crate) use crate::parsers::longitude::string_expression::longitude_parts;
+    use crate::{Leniency, ParserOptions};
+    use nom::combinator::{map, opt};
     use nom::sequence::tuple;
 
     /// Parser to obtain lat long
@@ -143,6 +181,69 @@ pub mod string_expression {
     pub fn latlong_altitude_option_parser(inp: &str) -> IResult<&str, ((f64, f64), Option<f64>)> {
         tuple((latlong_parser, opt(altitude_parser)))(inp)
     }
+
+    /// Same as `latlong_altitude_option_parser`, but the `CRS` statement is kept along with the altitude.
+    /// The CRS runs until the solidus or the end of the string.
+    ///
+    ///
+    /// ```
+    /// # use iso6709parse::parsers::iso6709::string_expression::latlong_altitude_crs_option_parser;
+    /// let coord = "+1200.00-02130.00/";
+    /// assert_eq!(latlong_altitude_crs_option_parser(coord), Ok(("/", ((12.0, -21.5), None))));
+    ///
+    /// let coord = "+1200.00-02130.00+2321CRSWGS_84/";
+    /// assert_eq!(latlong_altitude_crs_option_parser(coord), Ok(("/", ((12.0, -21.5), Some((2321.0, "WGS_84"))))));
+    /// ```
+    ///  
+    #[allow(clippy::type_complexity)]
+    pub fn latlong_altitude_crs_option_parser(
+        inp: &str,
+    ) -> IResult<&str, ((f64, f64), Option<(f64, &str)>)> {
+        tuple((latlong_parser, opt(altitude_crs_parser)))(inp)
+    }
+
+    /// Same as `latlong_altitude_crs_option_parser`, following the `options`. The CRS is optional.
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn latlong_altitude_crs_option_parser_with<'a>(
+        options: &'a ParserOptions,
+    ) -> impl Fn(&'a str) -> IResult<&'a str, ((f64, f64), Option<(f64, Option<&'a str>)>)> + 'a
+    {
+        move |inp| {
+            map(
+                latlong_altitude_crs_parts(options.leniency),
+                |(lat, lon, altitude)| {
+                    (
+                        (lat.value, lon.value),
+                        altitude.map(|((_, alt), crs)| (alt, crs)),
+                    )
+                },
+            )(inp)
+        }
+    }
+
+    /// Same as `latlong_altitude_crs_option_parser_with`, with the text of each component
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn latlong_altitude_crs_parts<'a>(
+        leniency: Leniency,
+    ) -> impl Fn(
+        &'a str,
+    ) -> IResult<
+        &'a str,
+        (
+            AngleParts<'a>,
+            AngleParts<'a>,
+            Option<(Component<'a>, Option<&'a str>)>,
+        ),
+    > {
+        move |inp| {
+            tuple((
+                latitude_parts,
+                longitude_parts,
+                opt(altitude_parts(leniency)),
+            ))(inp)
+        }
+    }
+
     #[cfg(test)]
     mod string_expression_tests {
         use super::*;
@@ -2,7 +2,7 @@
 use nom::branch::alt;
 use nom::bytes::complete::tag;
 use nom::bytes::complete::take_while_m_n;
-use nom::character::complete::{digit0, u8};
+use nom::character::complete::u8;
 use nom::character::is_digit;
 use nom::combinator::{map, opt, value};
 use nom::combinator::{map_parser, map_res, recognize};
@@ -13,35 +13,27 @@ use nom::IResult;
 pub mod human_readable {
     use super::*;
     use crate::parsers::common::human_readable::*;
+    use crate::parsers::common::AngleParts;
+    use crate::Symbols;
     //     50°40′46.461″N 95°48′26.533″W 123.45m
     //     50°03′46.461″S 125°48′26.533″E 978.90m
 
-    fn parse_north(inp: &str) -> IResult<&str, f64> {
-        value(1., tag("N"))(inp)
+    /// Same as `latitude_parser`, with the text of each component
+    pub(crate) fn latitude_parts_with<'s, 'a>(
+        symbols: &'s Symbols,
+    ) -> impl Fn(&'a str) -> IResult<&'a str, AngleParts<'a>> + 's {
+        parse_angle_with(symbols, (&symbols.north, &symbols.south), 90.)
     }
 
-    fn parse_south(inp: &str) -> IResult<&str, f64> {
-        value(-1., tag("S"))(inp)
-    }
-
-    fn parse_north_or_south(inp: &str) -> IResult<&str, f64> {
-        alt((parse_north, parse_south))(inp)
+    /// Same as `latitude_parser`, with the markers in `symbols`
+    pub(crate) fn latitude_parser_with<'s, 'a>(
+        symbols: &'s Symbols,
+    ) -> impl Fn(&'a str) -> IResult<&'a str, f64> + 's {
+        move |inp| map(latitude_parts_with(symbols), |parts| parts.value)(inp)
     }
 
     pub fn latitude_parser(inp: &str) -> IResult<&str, f64> {
-        let (rem, deg) = parse_degree(inp)?;
-        let (rem, min) = parse_minutes(rem)?;
-        let (rem, sec) = parse_seconds(rem)?;
-        let (rem, mag) = parse_north_or_south(rem)?;
-        let value = deg + min / 60. + sec / 3600.;
-        if value > 90.0 {
-            Err(nom::Err::Failure(nom::error::Error::new(
-                inp,
-                nom::error::ErrorKind::Fail,
-            )))
-        } else {
-            Ok((rem, mag * value))
-        }
+        latitude_parser_with(Symbols::standard())(inp)
     }
 
     #[cfg(test)]
@@ -72,6 +64,11 @@ pub mod human_readable {
             assert_float_approx(latitude_parser(inp), 0.);
             let inp = "00°00'00.00\"S 95°48′26.533″W 123.45m";
             assert_float_approx(latitude_parser(inp), 0.);
+            // Minutes and seconds of 60 or more are added as they are
+            let inp = "50°60′00″N 95°48′26.533″W 123.45m";
+            assert_float_approx(latitude_parser(inp), 51.);
+            let inp = "50°40′90″N 95°48′26.533″W 123.45m";
+            assert_float_approx(latitude_parser(inp), 50.69166666666667);
         }
 
         #[test]
@@ -84,12 +81,16 @@ pub mod human_readable {
             assert!(latitude_parser(inp).is_err());
             let inp = "90°40′46.461″N 95°48′26.533″W 123.45m";
             assert!(latitude_parser(inp).is_err());
+            let inp = "89°60′00.1″N 95°48′26.533″W 123.45m";
+            assert!(latitude_parser(inp).is_err());
         }
     }
 }
 
 pub mod string_expression {
     use super::*;
+    use crate::parsers::common::{digits0, AngleParts};
+    use nom::combinator::consumed;
 
     fn parse_north(inp: &str) -> IResult<&str, f64> {
         value(1., alt((tag("N"), tag("+"))))(inp)
@@ -107,11 +108,11 @@ pub mod string_expression {
         char.is_ascii() && is_digit(char as u8)
     }
 
-    fn parse_two<'a, F: 'a, O, E: ParseError<&'a str>>(
+    fn parse_two<'a, F, O, E: ParseError<&'a str>>(
         inner: F,
     ) -> impl FnMut(&'a str) -> IResult<&'a str, O, E>
     where
-        F: Fn(&'a str) -> IResult<&'a str, O, E>,
+        F: 'a + Fn(&'a str) -> IResult<&'a str, O, E>,
     {
         map_parser(take_while_m_n(2, 2, is_char_digit), inner)
     }
@@ -151,7 +152,7 @@ pub mod string_expression {
     }
 
     fn parse_decimal(inp: &str) -> IResult<&str, f64> {
-        map_res(recognize(tuple((tag("."), digit0))), |x: &str| {
+        map_res(recognize(tuple((tag("."), digits0))), |x: &str| {
             x.parse::<f64>()
         })(inp)
     }
@@ -177,19 +178,30 @@ pub mod string_expression {
     /// Nom style parser for latitude. The beginning of the string slice must be the start of latitude.
     /// Returns Err if failed to parse, or latitude is greater than +/-90.0
     pub fn latitude_parser(inp: &str) -> IResult<&str, f64> {
-        let (lat, mag) = parse_north_or_south(inp)?;
-        let (rem, value) = alt((
+        map(latitude_parts, |parts| parts.value)(inp)
+    }
+
+    /// The number of a latitude, after its sign or hemisphere letter, without the range check
+    pub(crate) fn latitude_body(inp: &str) -> IResult<&str, f64> {
+        // Order matters for the next line!
+        alt((
             parse_degree_minute_second,
             parse_degree_minute,
             parse_degree,
-        ))(lat)?;
+        ))(inp)
+    }
+
+    /// Same as `latitude_parser`, with the text of each component
+    pub(crate) fn latitude_parts(inp: &str) -> IResult<&str, AngleParts<'_>> {
+        let (lat, sign) = consumed(parse_north_or_south)(inp)?;
+        let (rem, (body, value)) = consumed(latitude_body)(lat)?;
         if value > 90.0 {
             Err(nom::Err::Failure(nom::error::Error::new(
                 lat,
                 nom::error::ErrorKind::Fail,
             )))
         } else {
-            Ok((rem, mag * value))
+            Ok((rem, AngleParts::split(sign, body, 2, sign.1 * value)))
         }
     }
 
@@ -1,7 +1,7 @@
 use nom::branch::alt;
 use nom::bytes::complete::tag;
 use nom::bytes::complete::take_while_m_n;
-use nom::character::complete::{digit0, u8};
+use nom::character::complete::u8;
 use nom::character::is_digit;
 use nom::combinator::{map, opt, value};
 use nom::combinator::{map_parser, map_res, recognize};
@@ -12,35 +12,27 @@ use nom::IResult;
 pub mod human_readable {
     use super::*;
     use crate::parsers::common::human_readable::*;
+    use crate::parsers::common::AngleParts;
+    use crate::Symbols;
     //     50°40′46.461″N 95°48′26.533″W 123.45m
     //     50°03′46.461″S 125°48′26.533″E 978.90m
 
-    fn parse_east(inp: &str) -> IResult<&str, f64> {
-        value(1., tag("E"))(inp)
+    /// Same as `longitude_parser`, with the text of each component
+    pub(crate) fn longitude_parts_with<'s, 'a>(
+        symbols: &'s Symbols,
+    ) -> impl Fn(&'a str) -> IResult<&'a str, AngleParts<'a>> + 's {
+        parse_angle_with(symbols, (&symbols.east, &symbols.west), 180.)
     }
 
-    fn parse_west(inp: &str) -> IResult<&str, f64> {
-        value(-1., tag("W"))(inp)
-    }
-
-    fn parse_east_or_west(inp: &str) -> IResult<&str, f64> {
-        alt((parse_east, parse_west))(inp)
+    /// Same as `longitude_parser`, with the markers in `symbols`
+    pub(crate) fn longitude_parser_with<'s, 'a>(
+        symbols: &'s Symbols,
+    ) -> impl Fn(&'a str) -> IResult<&'a str, f64> + 's {
+        move |inp| map(longitude_parts_with(symbols), |parts| parts.value)(inp)
     }
 
     pub fn longitude_parser(inp: &str) -> IResult<&str, f64> {
-        let (rem, deg) = parse_degree(inp)?;
-        let (rem, min) = parse_minutes(rem)?;
-        let (rem, sec) = parse_seconds(rem)?;
-        let (rem, mag) = parse_east_or_west(rem)?;
-        let value = deg + min / 60. + sec / 3600.;
-        if value > 180.0 {
-            Err(nom::Err::Failure(nom::error::Error::new(
-                inp,
-                nom::error::ErrorKind::Fail,
-            )))
-        } else {
-            Ok((rem, mag * value))
-        }
+        longitude_parser_with(Symbols::standard())(inp)
     }
 
     #[cfg(test)]
@@ -54,6 +46,8 @@ pub mod human_readable {
             assert_float_approx(longitude_parser(inp), -95.80737);
             let inp = "95°48′26.533″E 123.45m";
             assert_float_approx(longitude_parser(inp), 95.80737);
+            let inp = "0°75′00″E";
+            assert_float_approx(longitude_parser(inp), 1.25);
             let inp = "95°48'26.533″W 123.45m";
             assert_float_approx(longitude_parser(inp), -95.80737);
             let inp = r#"95°48′26.533"W 123.45m"#;
@@ -80,11 +74,15 @@ pub mod human_readable {
             assert!(longitude_parser(inp).is_err());
             let inp = "95.48′26.533″ 123.45m";
             assert!(longitude_parser(inp).is_err());
+            let inp = "179°60′00.1″E";
+            assert!(longitude_parser(inp).is_err());
         }
     }
 }
 pub mod string_expression {
     use super::*;
+    use crate::parsers::common::{digits0, AngleParts};
+    use nom::combinator::consumed;
 
     fn parse_east(inp: &str) -> IResult<&str, f64> {
         value(1., alt((tag("E"), tag("+"))))(inp)
@@ -102,20 +100,20 @@ pub mod string_expression {
         char.is_ascii() && is_digit(char as u8)
     }
 
-    fn parse_two<'a, F: 'a, O, E: ParseError<&'a str>>(
+    fn parse_two<'a, F, O, E: ParseError<&'a str>>(
         inner: F,
     ) -> impl FnMut(&'a str) -> IResult<&'a str, O, E>
     where
-        F: Fn(&'a str) -> IResult<&'a str, O, E>,
+        F: 'a + Fn(&'a str) -> IResult<&'a str, O, E>,
     {
         map_parser(take_while_m_n(2, 2, is_char_digit), inner)
     }
 
-    fn parse_three<'a, F: 'a, O, E: ParseError<&'a str>>(
+    fn parse_three<'a, F, O, E: ParseError<&'a str>>(
         inner: F,
     ) -> impl FnMut(&'a str) -> IResult<&'a str, O, E>
     where
-        F: Fn(&'a str) -> IResult<&'a str, O, E>,
+        F: 'a + Fn(&'a str) -> IResult<&'a str, O, E>,
     {
         map_parser(take_while_m_n(3, 3, is_char_digit), inner)
     }
@@ -155,7 +153,7 @@ pub mod string_expression {
     }
 
     fn parse_decimal(inp: &str) -> IResult<&str, f64> {
-        map_res(recognize(tuple((tag("."), digit0))), |x: &str| {
+        map_res(recognize(tuple((tag("."), digits0))), |x: &str| {
             x.parse::<f64>()
         })(inp)
     }
@@ -179,20 +177,30 @@ pub mod string_expression {
     }
 
     pub fn longitude_parser(inp: &str) -> IResult<&str, f64> {
-        let (lat, mag) = parse_east_or_west(inp)?;
+        map(longitude_parts, |parts| parts.value)(inp)
+    }
+
+    /// The number of a longitude, after its sign or hemisphere letter, without the range check
+    pub(crate) fn longitude_body(inp: &str) -> IResult<&str, f64> {
         // Order matters for the next line!
-        let (rem, value) = alt((
+        alt((
             parse_degree_minute_second,
             parse_degree_minute,
             parse_degree,
-        ))(lat)?;
+        ))(inp)
+    }
+
+    /// Same as `longitude_parser`, with the text of each component
+    pub(crate) fn longitude_parts(inp: &str) -> IResult<&str, AngleParts<'_>> {
+        let (lat, sign) = consumed(parse_east_or_west)(inp)?;
+        let (rem, (body, value)) = consumed(longitude_body)(lat)?;
         if value > 180.0 {
             Err(nom::Err::Failure(nom::error::Error::new(
                 lat,
                 nom::error::ErrorKind::Fail,
             )))
         } else {
-            Ok((rem, mag * value))
+            Ok((rem, AngleParts::split(sign, body, 3, sign.1 * value)))
         }
     }
 
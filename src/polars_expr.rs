@@ -0,0 +1,85 @@
+use crate::{parse, ISO6709Coord};
+use polars::prelude::*;
+
+/// Parsing of ISO6709 strings in polars lazy queries
+/// ```
+/// # use iso6709parse::Iso6709Expr;
+/// use polars::prelude::*;
+///
+/// let frame = df!("position" => ["+35.50-170.10+12CRSWGS_84/", "15°30′00″N 95°15′00″W"]).unwrap();
+/// let parsed = frame
+///     .lazy()
+///     .select([col("position").parse_iso6709()])
+///     .unnest(by_name(["position"], true))
+///     .collect()
+///     .unwrap();
+/// assert_eq!(parsed.column("lat").unwrap().f64().unwrap().get(1), Some(15.5));
+/// assert_eq!(parsed.column("alt").unwrap().f64().unwrap().get(1), None);
+/// ```
+pub trait Iso6709Expr {
+    /// Parses a string column into a struct column of `lat`, `lon` and `alt` in `Float64`. The fields are null for
+    /// strings that are null or fail to parse, and `alt` is null for coordinates without an altitude.
+    fn parse_iso6709(self) -> Expr;
+}
+
+impl Iso6709Expr for Expr {
+    fn parse_iso6709(self) -> Expr {
+        self.map(parse_column, |_, field| {
+            Ok(Field::new(field.name().clone(), coord_type()))
+        })
+    }
+}
+
+fn coord_type() -> DataType {
+    DataType::Struct(vec![
+        Field::new("lat".into(), DataType::Float64),
+        Field::new("lon".into(), DataType::Float64),
+        Field::new("alt".into(), DataType::Float64),
+    ])
+}
+
+fn parse_column(column: Column) -> PolarsResult<Column> {
+    let coords: Vec<Option<ISO6709Coord>> = column
+        .str()?
+        .into_iter()
+        .map(|input| input.and_then(|input| parse(input).ok()))
+        .collect();
+    let field = |name: &str, value: fn(&ISO6709Coord) -> Option<f64>| {
+        Float64Chunked::from_iter_options(
+            name.into(),
+            coords.iter().map(|coord| coord.as_ref().and_then(value)),
+        )
+        .into_series()
+    };
+    let fields = [
+        field("lat", |coord| Some(coord.lat)),
+        field("lon", |coord| Some(coord.lon)),
+        field("alt", |coord| coord.altitude),
+    ];
+    let parsed = StructChunked::from_series(column.name().clone(), coords.len(), fields.iter())?;
+    Ok(parsed.into_series().into_column())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_column() {
+        let column = Column::new(
+            "position".into(),
+            [Some("+35.50-170.10+12CRSWGS_84/"), None, Some("north")],
+        );
+        let parsed = parse_column(column).unwrap();
+        assert_eq!(parsed.dtype(), &coord_type());
+        let parsed = parsed.struct_().unwrap();
+        let lat = parsed.field_by_name("lat").unwrap();
+        assert_eq!(lat.f64().unwrap().get(0), Some(35.5));
+        assert_eq!(lat.null_count(), 2);
+        let alt = parsed.field_by_name("alt").unwrap();
+        assert_eq!(alt.f64().unwrap().get(0), Some(12.));
+
+        let column = Column::new("position".into(), [1i32]);
+        assert!(parse_column(column).is_err());
+    }
+}
@@ -0,0 +1,190 @@
+use crate::format::MAX_DECIMALS;
+use crate::{
+    AltitudeNotation, AnglePrecision, AngleUnit, Crs, ISO6709Coord, Precision, PrecisionTarget,
+    VerticalDatum,
+};
+use std::ops::Range;
+
+/// The result of [`crate::parse_position`]: the coordinate, along with everything else that was read from the string.
+#[derive(Debug, PartialEq, Clone)]
+#[non_exhaustive]
+pub struct Position {
+    pub coord: ISO6709Coord,
+    /// The CRS that followed the altitude in the string representation, the datum that followed a readable coordinate,
+    /// or else [`crate::ParserOptions::default_crs`]
+    pub crs: Option<Crs>,
+    /// `true` if `crs` is the default from the options rather than written in the input
+    pub crs_assumed: bool,
+    /// `true` if the latitude or longitude had no sign or hemisphere letter, and is in the hemisphere from
+    /// [`crate::ParserOptions::default_hemispheres`]
+    pub hemisphere_assumed: bool,
+    /// Whether the altitude is an ellipsoidal height or an orthometric elevation, when the CRS says so.
+    /// Always `None` without an altitude.
+    pub vertical_datum: Option<VerticalDatum>,
+    /// The unit and reference of an altitude written in aviation notation after a readable coordinate, such as
+    /// `3500 ft MSL` or `FL350`. The altitude of `coord` is converted to meters.
+    pub altitude_notation: Option<AltitudeNotation>,
+    /// Byte range of the input that the coordinate was read from, including any altitude unit, CRS or datum, but not
+    /// surrounding whitespace or ignored trailing text. `None` if the input was rewritten before parsing, as in
+    /// [`crate::Leniency::Lenient`] mode, or was not a coordinate string, as with [`crate::parse_gml_position`].
+    pub span: Option<Range<usize>>,
+    /// How many decimal places each value was written with.
+    /// `None` if the precision could not be read, such as with a lowercase `crs` tag in lenient mode.
+    pub precision: Option<Precision>,
+    /// Things that parsed, but look suspicious
+    pub warnings: Vec<Warning>,
+}
+
+impl Position {
+    pub(crate) fn new(coord: ISO6709Coord, crs: Option<Crs>) -> Position {
+        Position {
+            coord,
+            vertical_datum: crs.as_ref().and_then(Crs::vertical_datum),
+            crs,
+            crs_assumed: false,
+            hemisphere_assumed: false,
+            altitude_notation: None,
+            span: None,
+            precision: None,
+            warnings: vec![],
+        }
+    }
+
+    /// Whether sending the coordinate to `target` loses precision it was written with: narrowing to `f32` moves a
+    /// value by more than half of its last written digit, or formatting writes fewer digits than were read.
+    /// Values whose precision is unknown are taken as exact.
+    /// ```
+    /// # use iso6709parse::{parse_position, Format, FormatSpec, ParserOptions, PrecisionTarget};
+    /// let position = parse_position("+35.1234567-170.10/", &ParserOptions::default()).unwrap();
+    /// assert!(position.loses_precision(&PrecisionTarget::F32));
+    ///
+    /// let spec = FormatSpec { format: Format::Degrees, decimals: 5, ..Default::default() };
+    /// assert!(position.loses_precision(&PrecisionTarget::Format(spec)));
+    /// let spec = FormatSpec { format: Format::DegreesMinutesSeconds, decimals: 4, ..Default::default() };
+    /// assert!(!position.loses_precision(&PrecisionTarget::Format(spec)));
+    /// ```
+    pub fn loses_precision(&self, target: &PrecisionTarget) -> bool {
+        let (lat, lon, altitude) = match &self.precision {
+            Some(precision) => (
+                precision.lat.resolution(),
+                precision.lon.resolution(),
+                precision
+                    .altitude
+                    .map_or(0., |decimals| 10f64.powi(-(decimals as i32))),
+            ),
+            None => (0., 0., 0.),
+        };
+        match target {
+            PrecisionTarget::F32 => {
+                let narrowing = |value: f64, resolution: f64| {
+                    (value as f32 as f64 - value).abs() > resolution / 2.
+                };
+                narrowing(self.coord.lat, lat)
+                    || narrowing(self.coord.lon, lon)
+                    || self
+                        .coord
+                        .altitude
+                        .is_some_and(|value| narrowing(value, altitude))
+            }
+            PrecisionTarget::Format(spec) => {
+                let written = AnglePrecision {
+                    unit: AngleUnit::of(spec.format),
+                    decimals: spec.decimals.min(MAX_DECIMALS),
+                }
+                .resolution();
+                // Allows for the rounding error of the resolutions themselves
+                written > lat.min(lon) * (1. + 1e-9)
+            }
+        }
+    }
+
+    /// The text that the coordinate was read from, as a slice of `input`, which must be the string that was parsed.
+    /// See [`Position::span`].
+    /// ```
+    /// # use iso6709parse::{parse_position, ParserOptions};
+    /// let line = "  N35.50W170.10+12CRSWGS_84/ seen at 12:04";
+    /// let position = parse_position(line, &ParserOptions::default()).unwrap();
+    /// assert_eq!(position.span, Some(2..28));
+    /// assert_eq!(position.matched(line), Some("N35.50W170.10+12CRSWGS_84/"));
+    /// ```
+    pub fn matched<'a>(&self, input: &'a str) -> Option<&'a str> {
+        input.get(self.span.clone()?)
+    }
+
+    /// Uses `crs` if none was written in the input
+    pub(crate) fn assume_crs(&mut self, crs: Option<&Crs>) {
+        if self.crs.is_some() || crs.is_none() {
+            return;
+        }
+        self.crs = crs.cloned();
+        self.crs_assumed = true;
+        if self.coord.altitude.is_some() {
+            self.vertical_datum = crs.and_then(Crs::vertical_datum);
+        }
+    }
+}
+
+/// Something suspicious about a coordinate that still parsed
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum Warning {
+    /// The coordinate is outside [`crate::ParserOptions::expected_region`], but would be inside it with the latitude
+    /// and longitude swapped, which is the most common data entry mistake
+    LikelySwapped,
+    /// A latitude or longitude had both a sign and a hemisphere letter, such as `-95°15′00″W`, and was read using
+    /// the hemisphere letter. Only given in [`crate::Leniency::Lenient`] mode, otherwise this is an error.
+    SignConflict,
+    /// The coordinate loses precision it was written with when sent to [`crate::ParserOptions::precision_target`],
+    /// see [`Position::loses_precision`]
+    PrecisionLoss,
+}
+
+/// `true` if the location is within `region`. Regions that cross the antimeridian have an east edge past 180°,
+/// as created by [`crate::parse_bbox`].
+#[cfg(feature = "geo-types")]
+pub(crate) fn in_region(region: &geo_types::Rect, lat: f64, lon: f64) -> bool {
+    let (min, max) = (region.min(), region.max());
+    let within_lon = |lon: f64| min.x <= lon && lon <= max.x;
+    min.y <= lat && lat <= max.y && (within_lon(lon) || within_lon(lon + 360.))
+}
+
+/// `true` if `coord` is outside `region`, but swapping the latitude and longitude would put it inside
+#[cfg(feature = "geo-types")]
+pub(crate) fn likely_swapped(coord: &ISO6709Coord, region: &geo_types::Rect) -> bool {
+    coord.lon.abs() <= 90.
+        && !in_region(region, coord.lat, coord.lon)
+        && in_region(region, coord.lon, coord.lat)
+}
+
+#[cfg(all(test, feature = "geo-types"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_check_region() {
+        let europe = geo_types::Rect::new(
+            geo_types::coord! { x: -10., y: 35. },
+            geo_types::coord! { x: 30., y: 70. },
+        );
+        assert!(in_region(&europe, 48.8, 2.3));
+        assert!(!in_region(&europe, 2.3, 48.8));
+
+        let paris = ISO6709Coord {
+            lat: 48.8,
+            lon: 2.3,
+            altitude: None,
+        };
+        let swapped = ISO6709Coord {
+            lat: 2.3,
+            lon: 48.8,
+            altitude: None,
+        };
+        assert!(!likely_swapped(&paris, &europe));
+        assert!(likely_swapped(&swapped, &europe));
+
+        let pacific = crate::parse_bbox("-30+170/+30-170/").unwrap();
+        assert!(in_region(&pacific, 0., 175.));
+        assert!(in_region(&pacific, 0., -175.));
+        assert!(!in_region(&pacific, 0., 165.));
+    }
+}
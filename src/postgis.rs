@@ -0,0 +1,104 @@
+use crate::ewkb::{from_ewkb, from_hex_ewkb, to_ewkb};
+use crate::{ISO6709Coord, ISO6709Error};
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use sqlx::{Decode, Encode, Type};
+
+/// Binds to and reads from a PostGIS `geometry` column, such as `geometry(PointZ, 4326)`, as EWKB. A coordinate
+/// without an altitude is written as a 2D point, which a `geometry(Point, 4326)` column expects.
+///
+/// Reading a point with an SRID other than 4326 fails with [`crate::ErrorKind::UnknownCrs`].
+impl Type<Postgres> for ISO6709Coord {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("geometry")
+    }
+}
+
+impl Encode<'_, Postgres> for ISO6709Coord {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        buf.extend_from_slice(&to_ewkb(self));
+        Ok(IsNull::No)
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for ISO6709Coord {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        Ok(decode(value.format(), value.as_bytes()?)?)
+    }
+}
+
+/// Reads a value in either of the formats Postgres sends, binary EWKB or its hexadecimal text
+fn decode(format: PgValueFormat, bytes: &[u8]) -> Result<ISO6709Coord, ISO6709Error> {
+    match format {
+        PgValueFormat::Binary => from_ewkb(bytes),
+        PgValueFormat::Text => from_hex_ewkb(&String::from_utf8_lossy(bytes)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ErrorKind;
+
+    /// The bytes sqlx sends for `coord`, through its `Encode` impl
+    fn encode(coord: &ISO6709Coord) -> Vec<u8> {
+        let mut buf = PgArgumentBuffer::default();
+        let is_null = Encode::<Postgres>::encode_by_ref(coord, &mut buf).unwrap();
+        assert!(matches!(is_null, IsNull::No));
+        buf.to_vec()
+    }
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02X}", byte)).collect()
+    }
+
+    // sqlx has no public way to build a `PgValueRef` outside of a connection, so decoding goes through the function
+    // that the `Decode` impl hands the format and bytes to
+    #[test]
+    fn should_round_trip_through_sqlx() {
+        for coord in [
+            ISO6709Coord::new(35.5, -170.1, Some(8712.)).unwrap(),
+            ISO6709Coord::new(-0.5, 179.25, Some(-12.5)).unwrap(),
+            ISO6709Coord::new(35.5, -170.1, None).unwrap(),
+        ] {
+            let bytes = encode(&coord);
+            assert_eq!(bytes.len(), if coord.altitude.is_some() { 33 } else { 25 });
+            assert_eq!(decode(PgValueFormat::Binary, &bytes), Ok(coord));
+            assert_eq!(
+                decode(PgValueFormat::Text, hex(&bytes).as_bytes()),
+                Ok(coord)
+            );
+        }
+        assert_eq!(
+            <ISO6709Coord as Type<Postgres>>::type_info(),
+            PgTypeInfo::with_name("geometry")
+        );
+    }
+
+    #[test]
+    fn should_reject_other_srids() {
+        for (coord, srid) in [
+            (
+                ISO6709Coord::new(35.5, -170.1, Some(8712.)).unwrap(),
+                4269u32,
+            ),
+            (ISO6709Coord::new(35.5, -170.1, None).unwrap(), 3857),
+        ] {
+            let mut bytes = encode(&coord);
+            bytes[5..9].copy_from_slice(&srid.to_le_bytes());
+            for (format, value) in [
+                (PgValueFormat::Binary, bytes.clone()),
+                (PgValueFormat::Text, hex(&bytes).into_bytes()),
+            ] {
+                let err = decode(format, &value).unwrap_err();
+                assert_eq!(err.kind(), ErrorKind::UnknownCrs);
+                assert!(err.to_string().contains(&srid.to_string()), "{}", err);
+            }
+        }
+        assert_eq!(
+            decode(PgValueFormat::Text, b"not hex").unwrap_err().kind(),
+            ErrorKind::Syntax
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use crate::ast::{AngleNode, CoordinateAst, Span};
+use crate::{Format, FormatSpec};
+
+/// The unit of the last component written for a latitude or longitude, which the decimal places apply to
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AngleUnit {
+    Degrees,
+    Minutes,
+    Seconds,
+}
+
+impl AngleUnit {
+    /// The unit of the last component that `format` writes
+    pub(crate) fn of(format: Format) -> AngleUnit {
+        match format {
+            Format::Degrees | Format::WikipediaTemplate => AngleUnit::Degrees,
+            Format::DegreesMinutes => AngleUnit::Minutes,
+            Format::DegreesMinutesSeconds | Format::Readable | Format::Wikipedia => {
+                AngleUnit::Seconds
+            }
+        }
+    }
+}
+
+/// How precisely a latitude or longitude was written. `+35.50` is 2 decimals of degrees, `+3530.5` is 1 decimal of minutes.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct AnglePrecision {
+    pub unit: AngleUnit,
+    /// Number of digits after the decimal point of the last component
+    pub decimals: usize,
+}
+
+impl AnglePrecision {
+    /// The smallest step the written value can express, in degrees
+    /// ```
+    /// # use iso6709parse::{AnglePrecision, AngleUnit};
+    /// let precision = AnglePrecision { unit: AngleUnit::Minutes, decimals: 1 };
+    /// assert_eq!(precision.resolution(), 0.1 / 60.);
+    /// ```
+    pub fn resolution(&self) -> f64 {
+        let per_degree = match self.unit {
+            AngleUnit::Degrees => 1.,
+            AngleUnit::Minutes => 60.,
+            AngleUnit::Seconds => 3600.,
+        };
+        10f64.powi(-(self.decimals as i32)) / per_degree
+    }
+}
+
+/// How precisely each value of a coordinate was written, so that output does not claim more precision than the source had
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Precision {
+    pub lat: AnglePrecision,
+    pub lon: AnglePrecision,
+    /// Number of digits after the decimal point of the altitude
+    pub altitude: Option<usize>,
+}
+
+impl Precision {
+    pub(crate) fn from_ast(source: &str, ast: &CoordinateAst) -> Precision {
+        Precision {
+            lat: angle_precision(source, &ast.latitude),
+            lon: angle_precision(source, &ast.longitude),
+            altitude: ast
+                .altitude
+                .as_ref()
+                .map(|altitude| decimals(source, altitude.value.span)),
+        }
+    }
+}
+
+/// Where parsed coordinates are going, to check that they keep the precision they were written with. See
+/// [`crate::Position::loses_precision`] and [`crate::ParserOptions::precision_target`].
+#[derive(Debug, PartialEq, Clone)]
+#[non_exhaustive]
+pub enum PrecisionTarget {
+    /// Values narrowed to `f32`, as when parsing into `geo_types::Point<f32>`
+    F32,
+    /// Written out again by [`crate::ISO6709Coord::format`] with these settings
+    Format(FormatSpec),
+}
+
+/// Number of digits after the decimal separator, which may be any of [`crate::Symbols::decimal`]
+pub(crate) fn decimals(source: &str, span: Span) -> usize {
+    let text = source[span.start..span.end].trim_start_matches(['+', '-']);
+    text.find(|ch: char| !ch.is_ascii_digit())
+        .map_or(0, |separator| {
+            text[separator..]
+                .chars()
+                .filter(char::is_ascii_digit)
+                .count()
+        })
+}
+
+pub(crate) fn angle_precision(source: &str, node: &AngleNode) -> AnglePrecision {
+    let (unit, span) = match (&node.minutes, &node.seconds) {
+        (_, Some(seconds)) => (AngleUnit::Seconds, seconds.span),
+        (Some(minutes), None) => (AngleUnit::Minutes, minutes.span),
+        (None, None) => (AngleUnit::Degrees, node.degrees.span),
+    };
+    AnglePrecision {
+        unit,
+        decimals: decimals(source, span),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::parse_ast;
+
+    fn precision(str: &str) -> Precision {
+        Precision::from_ast(str, &parse_ast(str).unwrap())
+    }
+
+    #[test]
+    fn should_count_decimals() {
+        let p = precision("+35.50-170.1234567/");
+        assert_eq!(
+            p.lat,
+            AnglePrecision {
+                unit: AngleUnit::Degrees,
+                decimals: 2
+            }
+        );
+        assert_eq!(
+            p.lon,
+            AnglePrecision {
+                unit: AngleUnit::Degrees,
+                decimals: 7
+            }
+        );
+        assert_eq!(p.altitude, None);
+
+        let p = precision("+3530-17030.5+12.25CRSWGS_84/");
+        assert_eq!(
+            p.lat,
+            AnglePrecision {
+                unit: AngleUnit::Minutes,
+                decimals: 0
+            }
+        );
+        assert_eq!(
+            p.lon,
+            AnglePrecision {
+                unit: AngleUnit::Minutes,
+                decimals: 1
+            }
+        );
+        assert_eq!(p.altitude, Some(2));
+
+        let p = precision("15°30′00.000″N 95°15′00″W 123m");
+        assert_eq!(
+            p.lat,
+            AnglePrecision {
+                unit: AngleUnit::Seconds,
+                decimals: 3
+            }
+        );
+        assert_eq!(
+            p.lon,
+            AnglePrecision {
+                unit: AngleUnit::Seconds,
+                decimals: 0
+            }
+        );
+        assert_eq!(p.altitude, Some(0));
+    }
+
+    #[test]
+    fn should_calculate_resolution() {
+        let p = precision("+35.50-1703015.5/");
+        assert_eq!(p.lat.resolution(), 0.01);
+        assert_eq!(p.lon.resolution(), 0.1 / 3600.);
+    }
+}
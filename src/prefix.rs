@@ -0,0 +1,530 @@
+//! As-you-type validation of partially entered coordinates.
+//!
+//! [`check_prefix`] reports whether the input so far can still become a valid ISO6709 coordinate, and which
+//! [`Token`]s may legally come next, so form inputs can give feedback before the user has finished typing.
+use std::sync::OnceLock;
+
+/// Something that may legally be typed next.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Token {
+    Digit,
+    DecimalPoint,
+    Degree,
+    Minute,
+    Second,
+    LatitudeHemisphere,
+    LongitudeHemisphere,
+    /// `+` or `-` in the string representation
+    Sign,
+    /// Leading `-` of a readable altitude
+    Minus,
+    Space,
+    /// Letter of a readable altitude unit, such as the `m` in `123.45m`
+    Unit,
+    /// The `CRS` keyword that follows a string representation altitude
+    Crs,
+    /// Any character of the CRS name other than `/`
+    CrsName,
+    Solidus,
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            Token::Digit => "digit",
+            Token::DecimalPoint => "`.`",
+            Token::Degree => "`°`",
+            Token::Minute => "`′` or `'`",
+            Token::Second => "`″` or `\"`",
+            Token::LatitudeHemisphere => "`N` or `S`",
+            Token::LongitudeHemisphere => "`E` or `W`",
+            Token::Sign => "`+` or `-`",
+            Token::Minus => "`-`",
+            Token::Space => "space",
+            Token::Unit => "unit",
+            Token::Crs => "`CRS`",
+            Token::CrsName => "CRS name",
+            Token::Solidus => "`/`",
+        };
+        f.write_str(str)
+    }
+}
+
+/// Result of [`check_prefix`]
+#[derive(Debug, PartialEq, Clone)]
+pub struct PrefixCheck {
+    /// `true` if characters can be appended to the input to make a valid coordinate
+    pub valid_prefix: bool,
+    /// `true` if the input is already a valid coordinate, including the latitude and longitude range checks, the
+    /// same as [`crate::is_valid`]
+    pub complete: bool,
+    /// Byte offset of the first character that could not be part of a coordinate, or the length of the input
+    /// if `valid_prefix` is `true`
+    pub valid_up_to: usize,
+    /// Tokens that may legally follow the valid part of the input, in the order they are first reached
+    pub expected: Vec<Token>,
+}
+
+/// Checks a partially entered coordinate in either format.
+/// Only the syntax is checked until the input is `complete`, so a latitude of `91°` is not reported until the
+/// coordinate is finished. Like [`crate::parse`], text after a complete coordinate is ignored, so any input that
+/// [`crate::is_valid`] accepts is a valid prefix, with nothing `expected` once the ignored text starts.
+/// ```
+/// # use iso6709parse::prefix::{check_prefix, Token};
+/// let check = check_prefix("15°30");
+/// assert!(check.valid_prefix);
+/// assert!(!check.complete);
+/// assert_eq!(check.expected, vec![Token::Digit, Token::Minute]);
+///
+/// let check = check_prefix("N35.50W170.10");
+/// assert!(check.complete);
+/// assert!(check.expected.contains(&Token::Solidus));
+///
+/// let check = check_prefix("N3x");
+/// assert!(!check.valid_prefix);
+/// assert_eq!(check.valid_up_to, 2);
+///
+/// let check = check_prefix("N35.50W170.10 is the spot");
+/// assert!(check.valid_prefix && check.complete);
+/// assert!(check.expected.is_empty());
+/// ```
+pub fn check_prefix(inp: &str) -> PrefixCheck {
+    static NFA: OnceLock<Nfa> = OnceLock::new();
+    let nfa = NFA.get_or_init(Nfa::new);
+    let trimmed = inp.trim_start();
+    let mut current = nfa.closure([nfa.start]);
+    let mut valid_up_to = inp.len() - trimmed.len();
+
+    for (offset, ch) in trimmed.char_indices() {
+        let next = nfa.step(&current, ch);
+        if next.is_empty() {
+            if crate::is_valid(inp) {
+                return PrefixCheck {
+                    valid_prefix: true,
+                    complete: true,
+                    valid_up_to: inp.len(),
+                    expected: vec![],
+                };
+            }
+            return PrefixCheck {
+                valid_prefix: false,
+                complete: false,
+                valid_up_to,
+                expected: nfa.expected(&current),
+            };
+        }
+        current = next;
+        valid_up_to = inp.len() - trimmed.len() + offset + ch.len_utf8();
+    }
+
+    PrefixCheck {
+        valid_prefix: true,
+        complete: crate::is_valid(inp),
+        valid_up_to,
+        expected: nfa.expected(&current),
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Class {
+    Digit,
+    Chars(&'static [char]),
+    Letter,
+    Space,
+    NotSolidus,
+}
+
+impl Class {
+    fn matches(self, ch: char) -> bool {
+        match self {
+            Class::Digit => ch.is_ascii_digit(),
+            Class::Chars(chars) => chars.contains(&ch),
+            Class::Letter => ch.is_ascii_alphabetic(),
+            Class::Space => ch == ' ' || ch == '\t',
+            Class::NotSolidus => ch != '/',
+        }
+    }
+}
+
+/// Grammar of both formats, written as a small regular expression
+enum Pattern {
+    Char(Class, Token),
+    Seq(Vec<Pattern>),
+    Alt(Vec<Pattern>),
+    Optional(Box<Pattern>),
+    /// Zero or more
+    Many(Box<Pattern>),
+}
+
+use Pattern::*;
+
+fn ch(chars: &'static [char], token: Token) -> Pattern {
+    Char(Class::Chars(chars), token)
+}
+
+fn digit() -> Pattern {
+    Char(Class::Digit, Token::Digit)
+}
+
+fn digits(count: usize) -> Pattern {
+    Seq((0..count).map(|_| digit()).collect())
+}
+
+fn one_or_more(make: impl Fn() -> Pattern) -> Pattern {
+    Seq(vec![make(), Many(Box::new(make()))])
+}
+
+fn optional(pattern: Pattern) -> Pattern {
+    Optional(Box::new(pattern))
+}
+
+fn decimal() -> Pattern {
+    Seq(vec![
+        one_or_more(digit),
+        optional(Seq(vec![
+            ch(&['.'], Token::DecimalPoint),
+            one_or_more(digit),
+        ])),
+    ])
+}
+
+fn readable_angle(hemisphere: Pattern) -> Pattern {
+    Seq(vec![
+        one_or_more(digit),
+        ch(&['°'], Token::Degree),
+        one_or_more(digit),
+        ch(&['′', '\''], Token::Minute),
+        decimal(),
+        ch(&['″', '"'], Token::Second),
+        hemisphere,
+    ])
+}
+
+fn readable() -> Pattern {
+    let space = || Char(Class::Space, Token::Space);
+    Seq(vec![
+        readable_angle(ch(&['N', 'S'], Token::LatitudeHemisphere)),
+        one_or_more(space),
+        readable_angle(ch(&['E', 'W'], Token::LongitudeHemisphere)),
+        optional(Seq(vec![
+            one_or_more(space),
+            optional(ch(&['-'], Token::Minus)),
+            decimal(),
+            Many(Box::new(space())),
+            Many(Box::new(Char(Class::Letter, Token::Unit))),
+        ])),
+    ])
+}
+
+fn string_angle(hemisphere: Pattern, degree_width: usize) -> Pattern {
+    let sign = || ch(&['+', '-'], Token::Sign);
+    Seq(vec![
+        Alt(vec![hemisphere, sign()]),
+        digits(degree_width),
+        optional(Seq(vec![digits(2), optional(digits(2))])),
+        optional(Seq(vec![
+            ch(&['.'], Token::DecimalPoint),
+            Many(Box::new(digit())),
+        ])),
+    ])
+}
+
+fn string_representation() -> Pattern {
+    Seq(vec![
+        string_angle(ch(&['N', 'S'], Token::LatitudeHemisphere), 2),
+        string_angle(ch(&['E', 'W'], Token::LongitudeHemisphere), 3),
+        optional(Seq(vec![
+            ch(&['+', '-'], Token::Sign),
+            one_or_more(digit),
+            optional(Seq(vec![
+                ch(&['.'], Token::DecimalPoint),
+                Many(Box::new(digit())),
+            ])),
+            ch(&['C'], Token::Crs),
+            ch(&['R'], Token::Crs),
+            ch(&['S'], Token::Crs),
+            one_or_more(|| Char(Class::NotSolidus, Token::CrsName)),
+        ])),
+        optional(ch(&['/'], Token::Solidus)),
+    ])
+}
+
+enum State {
+    Char(Class, Token, usize),
+    Split(usize, usize),
+    Match,
+}
+
+/// Thompson construction of [`Pattern`], so that every possible parse is followed at once
+struct Nfa {
+    states: Vec<State>,
+    start: usize,
+    matched: usize,
+}
+
+impl Nfa {
+    fn new() -> Nfa {
+        let mut nfa = Nfa {
+            states: vec![State::Match],
+            start: 0,
+            matched: 0,
+        };
+        let trailing_space = nfa.states.len();
+        nfa.states.push(State::Split(0, 0));
+        let space = nfa.push(State::Char(Class::Space, Token::Space, trailing_space));
+        nfa.states[trailing_space] = State::Split(space, nfa.matched);
+        let grammar = Alt(vec![readable(), string_representation()]);
+        nfa.start = nfa.compile(&grammar, trailing_space);
+        nfa
+    }
+
+    fn push(&mut self, state: State) -> usize {
+        self.states.push(state);
+        self.states.len() - 1
+    }
+
+    /// Returns the start state of `pattern`, which continues on to `next` once matched
+    fn compile(&mut self, pattern: &Pattern, next: usize) -> usize {
+        match pattern {
+            Pattern::Char(class, token) => self.push(State::Char(*class, *token, next)),
+            Seq(patterns) => patterns
+                .iter()
+                .rev()
+                .fold(next, |next, pattern| self.compile(pattern, next)),
+            Alt(patterns) => {
+                let starts: Vec<usize> = patterns.iter().map(|p| self.compile(p, next)).collect();
+                starts
+                    .into_iter()
+                    .reduce(|a, b| self.push(State::Split(a, b)))
+                    .unwrap_or(next)
+            }
+            Optional(pattern) => {
+                let start = self.compile(pattern, next);
+                self.push(State::Split(start, next))
+            }
+            Many(pattern) => {
+                let split = self.push(State::Split(0, 0));
+                let start = self.compile(pattern, split);
+                self.states[split] = State::Split(start, next);
+                split
+            }
+        }
+    }
+
+    fn closure(&self, states: impl IntoIterator<Item = usize>) -> Vec<usize> {
+        let mut stack: Vec<usize> = states.into_iter().collect();
+        stack.reverse();
+        let mut closure = vec![];
+        while let Some(state) = stack.pop() {
+            if closure.contains(&state) {
+                continue;
+            }
+            closure.push(state);
+            if let State::Split(a, b) = self.states[state] {
+                stack.push(b);
+                stack.push(a);
+            }
+        }
+        closure
+    }
+
+    fn step(&self, current: &[usize], ch: char) -> Vec<usize> {
+        let next = current
+            .iter()
+            .filter_map(|&state| match self.states[state] {
+                State::Char(class, _, next) if class.matches(ch) => Some(next),
+                _ => None,
+            });
+        self.closure(next.collect::<Vec<_>>())
+    }
+
+    fn expected(&self, current: &[usize]) -> Vec<Token> {
+        let mut tokens = vec![];
+        for &state in current {
+            if let State::Char(_, token, _) = self.states[state] {
+                if !tokens.contains(&token) {
+                    tokens.push(token);
+                }
+            }
+        }
+        tokens
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_suggest_first_token() {
+        let check = check_prefix("");
+        assert!(check.valid_prefix);
+        assert!(!check.complete);
+        assert_eq!(
+            check.expected,
+            vec![Token::Digit, Token::LatitudeHemisphere, Token::Sign]
+        );
+    }
+
+    #[test]
+    fn should_follow_readable() {
+        assert_eq!(
+            check_prefix("15").expected,
+            vec![Token::Digit, Token::Degree]
+        );
+        assert_eq!(
+            check_prefix("15°30′00").expected,
+            vec![Token::Digit, Token::DecimalPoint, Token::Second]
+        );
+        assert_eq!(
+            check_prefix("15°30′00.000″").expected,
+            vec![Token::LatitudeHemisphere]
+        );
+
+        let check = check_prefix("15°30′00.000″N 95°15′00.000″W");
+        assert!(check.complete);
+        assert_eq!(check.expected, vec![Token::Space]);
+
+        let check = check_prefix("15°30′00.000″N 95°15′00.000″W 123.45m ");
+        assert!(check.complete);
+        let check = check_prefix("15°30′00.000″N 95°15′00.000″W 123.45 m");
+        assert!(check.complete);
+    }
+
+    #[test]
+    fn should_follow_string_representation() {
+        assert_eq!(check_prefix("N").expected, vec![Token::Digit]);
+        assert_eq!(
+            check_prefix("N35").expected,
+            vec![
+                Token::Digit,
+                Token::DecimalPoint,
+                Token::LongitudeHemisphere,
+                Token::Sign
+            ]
+        );
+        assert_eq!(
+            check_prefix("N35.50W170.10+8712").expected,
+            vec![Token::Digit, Token::DecimalPoint, Token::Crs]
+        );
+
+        let check = check_prefix("N35.50W170.10+8712CRSWGS_84/");
+        assert!(check.complete);
+        assert!(check.expected.contains(&Token::Space));
+    }
+
+    #[test]
+    fn should_report_invalid_prefix() {
+        let check = check_prefix("N351W");
+        assert!(!check.valid_prefix);
+        assert_eq!(check.valid_up_to, 4);
+        assert_eq!(check.expected, vec![Token::Digit]);
+
+        let check = check_prefix("  15°30x");
+        assert!(!check.valid_prefix);
+        assert_eq!(check.valid_up_to, 8);
+    }
+
+    #[test]
+    fn should_accept_prefixes_of_valid_input() {
+        let inputs = [
+            "15°30′00.000″N 95°15′00.000″W",
+            " 15°30′00″N\t95°15′00″W 123.45 m ",
+            "15°30′00″N 95°15′00″W -.5m",
+            "N35.50W170.10/",
+            "+3520.30-17006.12+8712CRSWGS_84/",
+            "+352030.5-1700612.25-12.CRSEPSG:4979/",
+            "N35.50W170.10 is the spot",
+            "+35.50-170.10+12x",
+            "S00W000",
+        ];
+        for input in inputs {
+            assert_agrees(input);
+        }
+
+        let check = check_prefix("+35.50-170.10+12/");
+        assert!(check.valid_prefix && check.complete);
+    }
+
+    /// Every prefix of a valid input is a valid prefix, and is complete exactly when `is_valid` accepts it
+    fn assert_agrees(input: &str) {
+        assert!(crate::is_valid(input), "{}", input);
+        for (end, _) in input.char_indices().chain([(input.len(), ' ')]) {
+            let prefix = &input[..end];
+            let check = check_prefix(prefix);
+            assert!(check.valid_prefix, "{:?} of {:?}", prefix, input);
+            assert_eq!(check.valid_up_to, end, "{:?} of {:?}", prefix, input);
+            assert_eq!(
+                check.complete,
+                crate::is_valid(prefix),
+                "{:?} of {:?}",
+                prefix,
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn should_agree_with_is_valid_on_formatted_coordinates() {
+        use crate::{Format, FormatSpec, ISO6709Coord};
+
+        let angles: [f64; 4] = [0., 5.05, -45.999999, 90.];
+        let altitudes = [None, Some(-12.5), Some(8712.25)];
+        let formats = [
+            Format::Degrees,
+            Format::DegreesMinutes,
+            Format::DegreesMinutesSeconds,
+            Format::Readable,
+        ];
+        for (lat, lon) in angles.iter().flat_map(|&lat| {
+            angles
+                .iter()
+                .map(move |&lon| (lat, (lon * 2. - lat / 10.).clamp(-180., 180.)))
+        }) {
+            for altitude in altitudes {
+                let coord = ISO6709Coord::new(lat, lon, altitude).unwrap();
+                for (format, decimals) in formats
+                    .iter()
+                    .flat_map(|&format| [0, 3].map(|decimals| (format, decimals)))
+                {
+                    let spec = FormatSpec {
+                        format,
+                        decimals,
+                        ..Default::default()
+                    };
+                    let str = coord.format(&spec).unwrap();
+                    assert_agrees(&str);
+                    assert_agrees(&format!("  {}  ", str));
+                    if format == Format::Readable {
+                        assert_agrees(&str.replace('′', "'").replace('″', "\""));
+                    } else {
+                        // Hemisphere letters in place of the signs
+                        let lon_start = str[1..].find(['+', '-']).unwrap() + 1;
+                        let letter = |sign, letters: [char; 2]| {
+                            if sign == '-' {
+                                letters[1]
+                            } else {
+                                letters[0]
+                            }
+                        };
+                        let lettered = format!(
+                            "{}{}{}{}",
+                            letter(str.chars().next().unwrap(), ['N', 'S']),
+                            &str[1..lon_start],
+                            letter(str[lon_start..].chars().next().unwrap(), ['E', 'W']),
+                            &str[lon_start + 1..]
+                        );
+                        assert_agrees(&lettered);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn should_only_complete_in_range() {
+        let check = check_prefix("N95.50W170.10");
+        assert!(check.valid_prefix);
+        assert!(!check.complete);
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{Crs, Format, FormatSpec, Leniency, ParserOptions};
+
+/// Presets of [`ParserOptions`] and [`FormatSpec`] for common sources and destinations of coordinates
+/// ```
+/// # use iso6709parse::{parse_position, ISO6709Coord, ParserOptions, Profile};
+/// let options = ParserOptions::from(Profile::Wikipedia);
+/// let position = parse_position("50º40’46”N 95º48’27”W", &options).unwrap();
+/// let out = position.coord.format(&Profile::Wikipedia.format_spec()).unwrap();
+/// assert_eq!(out, "50°40′46″N 95°48′27″W");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum Profile {
+    /// The letter of ISO6709 Annex H: strict parsing, a CRS is required with an altitude and never assumed
+    Iso6709AnnexH,
+    /// Text copied from wiki pages: lenient parsing, written in the compact Wikipedia style
+    Wikipedia,
+    /// GNSS receiver output: lenient parsing, heights taken to be ellipsoidal, written in decimal degrees with
+    /// enough decimals for centimeters
+    GpsDevice,
+    /// Aeronautical publications: lenient parsing, written as whole seconds as in `+353000-1700600/`
+    Aviation,
+}
+
+impl Profile {
+    pub fn parser_options(self) -> ParserOptions {
+        let lenient = ParserOptions {
+            leniency: Leniency::Lenient,
+            ..Default::default()
+        };
+        match self {
+            Profile::Iso6709AnnexH => ParserOptions {
+                require_crs: Some(true),
+                default_crs: None,
+                ..Default::default()
+            },
+            Profile::Wikipedia | Profile::Aviation => lenient,
+            Profile::GpsDevice => ParserOptions {
+                default_crs: Some(Crs::Wgs84_3D),
+                ..lenient
+            },
+        }
+    }
+
+    pub fn format_spec(self) -> FormatSpec {
+        match self {
+            Profile::Iso6709AnnexH => FormatSpec::default(),
+            Profile::Wikipedia => FormatSpec {
+                format: Format::Wikipedia,
+                decimals: 0,
+                ..Default::default()
+            },
+            Profile::GpsDevice => FormatSpec {
+                format: Format::Degrees,
+                decimals: 7,
+                crs: Crs::Wgs84_3D,
+                ..Default::default()
+            },
+            Profile::Aviation => FormatSpec {
+                format: Format::DegreesMinutesSeconds,
+                decimals: 0,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl From<Profile> for ParserOptions {
+    fn from(value: Profile) -> Self {
+        value.parser_options()
+    }
+}
+
+impl From<Profile> for FormatSpec {
+    fn from(value: Profile) -> Self {
+        value.format_spec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_position, ErrorKind};
+
+    #[test]
+    fn should_apply_profiles() {
+        let coord = "+35.50-170.10+12/";
+        let err = parse_position(coord, &Profile::Iso6709AnnexH.into()).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::MissingCrs);
+        let position = parse_position("+35.50-170.10/", &Profile::Iso6709AnnexH.into()).unwrap();
+        assert_eq!(position.crs, None);
+
+        let position = parse_position(coord, &Profile::GpsDevice.into()).unwrap();
+        assert_eq!(position.crs, Some(Crs::Wgs84_3D));
+        assert_eq!(
+            position.coord.format(&Profile::GpsDevice.into()).unwrap(),
+            "+35.5000000-170.1000000+12.0000000CRSWGS_84_3D/"
+        );
+        assert_eq!(
+            position.coord.format(&Profile::Aviation.into()).unwrap(),
+            "+353000-1700600+12CRSWGS_84/"
+        );
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{parse_position, ISO6709Error, ParserOptions, Position};
+use std::fmt::{Display, Formatter};
+
+/// A typo fixed by [`parse_with_repairs`]. `at` is the byte offset of the typo in the original input.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum Repair {
+    /// A letter next to a digit that was read as the digit it looks like, such as `O` for `0` or `l` for `1`
+    LetterForDigit {
+        at: usize,
+        letter: char,
+        digit: char,
+    },
+    /// A comma between digits that was read as a decimal point
+    CommaForPoint { at: usize },
+    /// A symbol typed twice in a row, such as `°°` or `//`, that was read once
+    DoubledSymbol { at: usize, symbol: char },
+}
+
+impl Display for Repair {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Repair::LetterForDigit { at, letter, digit } => {
+                write!(f, "read `{}` at byte {} as `{}`", letter, at, digit)
+            }
+            Repair::CommaForPoint { at } => write!(f, "read `,` at byte {} as `.`", at),
+            Repair::DoubledSymbol { at, symbol } => {
+                write!(f, "removed doubled `{}` at byte {}", symbol, at)
+            }
+        }
+    }
+}
+
+fn look_alike_digit(ch: char) -> Option<char> {
+    match ch {
+        'O' | 'o' => Some('0'),
+        'l' | 'I' => Some('1'),
+        _ => None,
+    }
+}
+
+/// Fixes the typos described by [`Repair`], returning the input unchanged if there are none
+pub(crate) fn repair(str: &str) -> (String, Vec<Repair>) {
+    let chars: Vec<(usize, char)> = str.char_indices().collect();
+    let char_at = |i: Option<usize>| i.and_then(|i| chars.get(i)).map(|&(_, ch)| ch);
+    let is_digit = |ch: Option<char>| ch.is_some_and(|ch| ch.is_ascii_digit());
+
+    let mut repairs = vec![];
+    let mut out = String::with_capacity(str.len());
+    for (i, &(at, ch)) in chars.iter().enumerate() {
+        let (previous, next) = (char_at(i.checked_sub(1)), char_at(Some(i + 1)));
+        match ch {
+            // Only next to a digit, so letters in a CRS name are left alone
+            ch if look_alike_digit(ch).is_some()
+                && (is_digit(previous) || is_digit(next) || next == Some('.')) =>
+            {
+                let digit = look_alike_digit(ch).unwrap_or(ch);
+                repairs.push(Repair::LetterForDigit {
+                    at,
+                    letter: ch,
+                    digit,
+                });
+                out.push(digit);
+            }
+            ',' if is_digit(previous)
+                && (is_digit(next) || next.and_then(look_alike_digit).is_some()) =>
+            {
+                repairs.push(Repair::CommaForPoint { at });
+                out.push('.');
+            }
+            '+' | '-' | '/' | '.' | '°' | '′' | '″' if out.ends_with(ch) => {
+                repairs.push(Repair::DoubledSymbol { at, symbol: ch });
+            }
+            _ => out.push(ch),
+        }
+    }
+    (out, repairs)
+}
+
+/// Parses like [`crate::parse_position`], after fixing common typos: letters for the digits they look like, a comma
+/// for a decimal point, and doubled symbols. Along with the position, returns what had to be fixed, which is empty
+/// if the input needed no repairs.
+/// ```
+/// # use iso6709parse::{parse_with_repairs, ParserOptions};
+/// let (position, repairs) = parse_with_repairs("+35,5O-l70.10//", &ParserOptions::default()).unwrap();
+/// assert_eq!((position.coord.lat, position.coord.lon), (35.5, -170.1));
+/// let repairs: Vec<String> = repairs.iter().map(|repair| repair.to_string()).collect();
+/// assert_eq!(
+///     repairs,
+///     vec![
+///         "read `,` at byte 3 as `.`",
+///         "read `O` at byte 5 as `0`",
+///         "read `l` at byte 7 as `1`",
+///         "removed doubled `/` at byte 14",
+///     ]
+/// );
+/// ```
+pub fn parse_with_repairs(
+    str: &str,
+    options: &ParserOptions,
+) -> Result<(Position, Vec<Repair>), ISO6709Error> {
+    let (repaired, repairs) = repair(str);
+    parse_position(&repaired, options).map(|position| (position, repairs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_repair_typos() {
+        let (repaired, repairs) = repair("+35.50-170.10+12CRSWGS_84/");
+        assert_eq!(repaired, "+35.50-170.10+12CRSWGS_84/");
+        assert!(repairs.is_empty());
+
+        let (repaired, repairs) = repair("15°°3O′00,5″N 95°15′0O″W 1l.5m");
+        assert_eq!(repaired, "15°30′00.5″N 95°15′00″W 11.5m");
+        assert_eq!(
+            repairs,
+            vec![
+                Repair::DoubledSymbol {
+                    at: 4, symbol: '°'
+                },
+                Repair::LetterForDigit {
+                    at: 7,
+                    letter: 'O',
+                    digit: '0'
+                },
+                Repair::CommaForPoint { at: 13 },
+                Repair::LetterForDigit {
+                    at: 30,
+                    letter: 'O',
+                    digit: '0'
+                },
+                Repair::LetterForDigit {
+                    at: 37,
+                    letter: 'l',
+                    digit: '1'
+                },
+            ]
+        );
+
+        // Letters in the CRS are left alone
+        let (repaired, _) = repair("+35.50-170.10+12CRSlocal/");
+        assert_eq!(repaired, "+35.50-170.10+12CRSlocal/");
+    }
+
+    #[test]
+    fn should_parse_with_repairs() {
+        let options = ParserOptions::default();
+        let (position, repairs) = parse_with_repairs("N35.50W170.10/", &options).unwrap();
+        assert_eq!(position.coord.lat, 35.5);
+        assert!(repairs.is_empty());
+
+        let (position, repairs) = parse_with_repairs("N35.5OW17O.1O/", &options).unwrap();
+        assert_eq!((position.coord.lat, position.coord.lon), (35.5, -170.1));
+        assert_eq!(repairs.len(), 3);
+
+        assert!(parse_with_repairs("N35.5OW/", &options).is_err());
+    }
+}
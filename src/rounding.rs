@@ -0,0 +1,115 @@
+use crate::ISO6709Coord;
+
+/// Length of one degree of latitude in meters, close enough for coarsening
+const METERS_PER_DEGREE: f64 = 111_320.;
+
+/// Most decimal places [`ISO6709Coord::rounded`] rounds to
+const MAX_ROUNDING_DECIMALS: u32 = 15;
+
+impl ISO6709Coord {
+    /// Rounds the latitude and longitude to `decimals` decimal places of degrees, at most 15, past which an `f64` of
+    /// a few hundred degrees has no digits left to round. The altitude is left as is.
+    /// ```
+    /// # use iso6709parse::{parse, ISO6709Coord};
+    /// let coord: ISO6709Coord = parse("+35.123456-170.987654/").unwrap();
+    /// let rounded = coord.rounded(2);
+    /// assert_eq!((rounded.lat, rounded.lon), (35.12, -170.99));
+    /// ```
+    pub fn rounded(&self, decimals: u32) -> ISO6709Coord {
+        let scale = 10f64.powi(decimals.min(MAX_ROUNDING_DECIMALS) as i32);
+        // Values too large to scale have no fraction to round, and `-0.0` is written as `0.0`
+        let round = |value: f64| {
+            let scaled = value * scale;
+            if scaled.is_finite() {
+                scaled.round() / scale + 0.
+            } else {
+                value
+            }
+        };
+        ISO6709Coord {
+            lat: round(self.lat),
+            lon: round(self.lon),
+            altitude: self.altitude,
+        }
+    }
+
+    /// Coarsens the coordinate for public display by moving it to the center of a grid cell roughly
+    /// `precision_meters` on each side, so every location within the same cell gives the same result.
+    /// The altitude is moved to the middle of its `precision_meters` band the same way.
+    /// A `precision_meters` that is not a positive number returns the coordinate unchanged.
+    /// ```
+    /// # use iso6709parse::{parse, ISO6709Coord};
+    /// let home: ISO6709Coord = parse("+48.85837+002.29448/").unwrap();
+    /// let neighbor: ISO6709Coord = parse("+48.85850+002.29500/").unwrap();
+    /// assert_eq!(home.truncated_to(1000.), neighbor.truncated_to(1000.));
+    /// assert!(home.distance_m(&home.truncated_to(1000.)) < 1000.);
+    /// ```
+    pub fn truncated_to(&self, precision_meters: f64) -> ISO6709Coord {
+        if !(precision_meters > 0. && precision_meters.is_finite()) {
+            return *self;
+        }
+        let center = |value: f64, step: f64| ((value / step).floor() + 0.5) * step;
+
+        let lat_step = (precision_meters / METERS_PER_DEGREE).min(180.);
+        let lat = center(self.lat + 90., lat_step).min(180.) - 90.;
+        // Use the same longitude grid for the whole band of latitude
+        let lon_step =
+            (precision_meters / (METERS_PER_DEGREE * lat.to_radians().cos().max(1e-9))).min(360.);
+        let lon = center(self.lon + 180., lon_step).min(360.) - 180.;
+
+        ISO6709Coord {
+            lat,
+            lon,
+            altitude: self
+                .altitude
+                .map(|altitude| center(altitude, precision_meters)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coord(lat: f64, lon: f64, altitude: Option<f64>) -> ISO6709Coord {
+        ISO6709Coord { lat, lon, altitude }
+    }
+
+    #[test]
+    fn should_round() {
+        let rounded = coord(-35.555, 170.0049, Some(12.3456)).rounded(2);
+        assert_eq!(rounded, coord(-35.56, 170., Some(12.3456)));
+        let rounded = coord(-35.5, 170.5, None).rounded(0);
+        assert_eq!(rounded, coord(-36., 171., None));
+
+        let rounded = coord(-35.123456789, 170.5, None).rounded(400);
+        assert_eq!(rounded, coord(-35.123456789, 170.5, None));
+        let rounded = coord(1e300, -f64::MAX, None).rounded(20);
+        assert_eq!(rounded, coord(1e300, -f64::MAX, None));
+        let rounded = coord(-0.004, -0., None).rounded(2);
+        assert!(rounded.lat.is_sign_positive() && rounded.lon.is_sign_positive());
+        assert_eq!(
+            coord(-0.4, 5., None).rounded(0).lat.to_bits(),
+            0f64.to_bits()
+        );
+    }
+
+    #[test]
+    fn should_truncate() {
+        let coarse = coord(48.85837, 2.29448, Some(35.)).truncated_to(1000.);
+        assert_eq!(coarse.altitude, Some(500.));
+        assert_eq!(coarse.truncated_to(1000.), coarse);
+
+        // Stays in range at the poles and antimeridian
+        let coarse = coord(90., 180., None).truncated_to(50_000.);
+        assert!(coarse.lat <= 90. && coarse.lon <= 180.);
+        let coarse = coord(-90., -180., None).truncated_to(50_000.);
+        assert!(coarse.lat >= -90. && coarse.lon >= -180.);
+        let coarse = coord(10., 20., None).truncated_to(1e9);
+        assert_eq!(coarse, coord(0., 0., None));
+
+        let unchanged = coord(48.85837, 2.29448, None);
+        assert_eq!(unchanged.truncated_to(0.), unchanged);
+        assert_eq!(unchanged.truncated_to(f64::NAN), unchanged);
+    }
+}
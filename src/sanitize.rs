@@ -0,0 +1,259 @@
+use std::borrow::Cow;
+
+/// What a character is replaced with, `Some(None)` to drop it, or `None` to keep it
+fn substitute(ch: char) -> Option<Option<char>> {
+    let replacement = match ch {
+        // Degree sign look-alikes: masculine ordinal, ring above, ring operator
+        'º' | '˚' | '∘' => '°',
+        // Smart and modifier quotes used for minutes and seconds
+        '‘' | '’' | '´' | 'ʹ' => '′',
+        '“' | '”' | 'ʺ' | '〃' | '˝' => '″',
+        // Minus sign and dashes
+        '−' | '‐' | '‒' | '–' | '—' => '-',
+        '＋' => '+',
+        ' ' => return None,
+        ch if ch.is_whitespace() => ' ',
+        // Zero width characters are neither whitespace nor control characters
+        '\u{200b}' | '\u{200c}' | '\u{200d}' | '\u{2060}' | '\u{feff}' => return Some(None),
+        ch if ch.is_control() => return Some(None),
+        _ => return None,
+    };
+    Some(Some(replacement))
+}
+
+/// Combining marks, which are drawn on the character before them as a single grapheme
+fn is_combining(ch: char) -> bool {
+    matches!(ch, '\u{300}'..='\u{36f}' | '\u{1ab0}'..='\u{1aff}' | '\u{1dc0}'..='\u{1dff}' | '\u{20d0}'..='\u{20ff}' | '\u{fe20}'..='\u{fe2f}')
+}
+
+/// The marker that a combining mark stands for when it is drawn on a number, as in `15̊30́`. These are also the marks
+/// that `˚`, `´` and `˝` decompose into.
+fn combining_marker(ch: char) -> Option<char> {
+    match ch {
+        '\u{30a}' => Some('°'),
+        '\u{301}' => Some('′'),
+        '\u{30b}' => Some('″'),
+        _ => None,
+    }
+}
+
+fn is_minute_marker(ch: char) -> bool {
+    matches!(ch, '′' | '\'')
+}
+
+/// Cleans up text copied from documents and web pages: control characters are removed, smart quotes, look-alike
+/// degree signs and dashes are replaced by the symbols ISO6709 uses, and runs of whitespace of any kind become
+/// a single space. The input is returned as is when there is nothing to clean up.
+///
+/// Symbols are matched by grapheme rather than by code point, so that text normalized into decomposed forms by
+/// another system still reads: a combining ring, acute or double acute drawn on a number, or on its own after a
+/// space, is a degree, minute or second marker, two minute markers in a row are a second marker, and combining marks
+/// drawn on a marker are dropped.
+///
+/// [`crate::parse_with_options`] runs this on its input in [`crate::Leniency::Lenient`] mode.
+/// ```
+/// # use iso6709parse::{parse, sanitize, ISO6709Coord};
+/// let copied = "15º30’00.000”N\u{a0}\u{a0}95º15’00.000”W\u{200b}";
+/// assert_eq!(sanitize(copied), "15°30′00.000″N 95°15′00.000″W");
+/// assert!(parse::<ISO6709Coord>(&sanitize(copied)).is_ok());
+///
+/// assert!(matches!(sanitize("+35.50-170.10/"), std::borrow::Cow::Borrowed(_)));
+/// ```
+pub fn sanitize(str: &str) -> Cow<'_, str> {
+    if !str.contains("  ")
+        && !str.contains("''")
+        && !str.contains("′′")
+        && !str.contains("'′")
+        && !str.contains("′'")
+        && str
+            .chars()
+            .all(|ch| substitute(ch).is_none() && !is_combining(ch))
+    {
+        return Cow::Borrowed(str);
+    }
+
+    let mut out = String::with_capacity(str.len());
+    for ch in str.chars() {
+        if is_combining(ch) {
+            match (out.chars().next_back(), combining_marker(ch)) {
+                (Some(' '), Some(marker)) => {
+                    out.pop();
+                    out.push(marker);
+                }
+                (None | Some('0'..='9'), Some(marker)) => out.push(marker),
+                // Drawn on a marker
+                (Some('°' | '′' | '″' | '\'' | '"'), _) => {}
+                _ => out.push(ch),
+            }
+            continue;
+        }
+        let ch = match substitute(ch) {
+            Some(Some(replacement)) => replacement,
+            Some(None) => continue,
+            None => ch,
+        };
+        if ch == ' ' && out.ends_with(' ') {
+            continue;
+        }
+        if is_minute_marker(ch) && out.ends_with(is_minute_marker) {
+            out.pop();
+            out.push('″');
+            continue;
+        }
+        out.push(ch);
+    }
+    Cow::Owned(out)
+}
+
+/// Strips quotes, parentheses and brackets that wrap the whole input, as CSV exports and copied text add, such as
+/// `"(15°30′00″N 95°15′00″W)"`. Only matching pairs are stripped, along with the whitespace inside them, so that a
+/// quote or parenthesis that is part of the coordinate or of the text after it is kept. Punctuation ending a sentence
+/// is stripped as well, inside and outside of the wrappers.
+pub(crate) fn unwrap(str: &str) -> &str {
+    let mut str = trim_punctuation(str.trim_start());
+    while let Some(inner) = ["\"\"", "''", "()", "[]", "{}", "″″", "′′", "«»"]
+        .iter()
+        .find_map(|pair| {
+            let mut chars = pair.chars();
+            let (open, close) = (chars.next()?, chars.next()?);
+            str.strip_prefix(open)?.strip_suffix(close)
+        })
+    {
+        str = trim_punctuation(inner.trim_start());
+    }
+    str
+}
+
+/// Strips the punctuation that ends a sentence or clause after a coordinate: `.`, `,`, `;`, and a `)` without a `(`.
+/// Otherwise, the period in `… 12.` would be read as the decimal point of the altitude, or as part of the CRS in
+/// `+35-170+12CRSWGS_84.`.
+fn trim_punctuation(str: &str) -> &str {
+    let mut str = str.trim_end();
+    loop {
+        let trimmed = str.trim_end_matches(['.', ',', ';']).trim_end();
+        let trimmed = match trimmed.strip_suffix(')') {
+            Some(inner) if trimmed.matches('(').count() < trimmed.matches(')').count() => {
+                inner.trim_end()
+            }
+            _ => trimmed,
+        };
+        if trimmed.len() == str.len() {
+            return str;
+        }
+        str = trimmed;
+    }
+}
+
+/// Replaces every comma between two digits with a decimal point, as in `123,45m` or `+8712,5CRSWGS_84/`
+pub(crate) fn decimal_commas(str: &str) -> Cow<'_, str> {
+    let bytes = str.as_bytes();
+    let is_decimal_comma = |at: usize| {
+        at > 0
+            && bytes[at - 1].is_ascii_digit()
+            && bytes.get(at + 1).is_some_and(u8::is_ascii_digit)
+    };
+    if !str.match_indices(',').any(|(at, _)| is_decimal_comma(at)) {
+        return Cow::Borrowed(str);
+    }
+
+    let mut out = String::from(str);
+    for (at, _) in str
+        .match_indices(',')
+        .filter(|&(at, _)| is_decimal_comma(at))
+    {
+        out.replace_range(at..at + 1, ".");
+    }
+    Cow::Owned(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_sanitize() {
+        assert_eq!(sanitize("+35.50−170.10/\r\n"), "+35.50-170.10/ ");
+        assert_eq!(sanitize("\u{feff}N35.50W170.10/"), "N35.50W170.10/");
+        assert_eq!(sanitize("15°30′00″N\t 95°15′00″W"), "15°30′00″N 95°15′00″W");
+        assert_eq!(sanitize("15˚30‘00“N 95˚15’00”W"), "15°30′00″N 95°15′00″W");
+        assert_eq!(sanitize("N35.50\u{7}W170.10/"), "N35.50W170.10/");
+    }
+
+    #[test]
+    fn should_unwrap() {
+        assert_eq!(
+            unwrap("\"(15°30′00″N 95°15′00″W)\""),
+            "15°30′00″N 95°15′00″W"
+        );
+        assert_eq!(unwrap(" [ +35.50-170.10/ ] "), "+35.50-170.10/");
+        assert_eq!(unwrap("'{«+35.50-170.10/»}'"), "+35.50-170.10/");
+        assert_eq!(unwrap("″+35.50-170.10/″"), "+35.50-170.10/");
+        for kept in ["(+35.50-170.10/", "(+35.50-170.10/]", "\""] {
+            assert_eq!(unwrap(kept), kept);
+        }
+        assert_eq!(unwrap("( )"), "");
+    }
+
+    #[test]
+    fn should_trim_punctuation() {
+        assert_eq!(
+            unwrap("15°30′00″N 95°15′00″W 12."),
+            "15°30′00″N 95°15′00″W 12"
+        );
+        assert_eq!(unwrap("+35-170+12CRSWGS_84.,; "), "+35-170+12CRSWGS_84");
+        assert_eq!(unwrap("+35.50-170.10/)."), "+35.50-170.10/");
+        assert_eq!(unwrap("(+35.50-170.10/)."), "+35.50-170.10/");
+        assert_eq!(unwrap("\"+35.50-170.10/.\","), "+35.50-170.10/");
+        assert_eq!(
+            unwrap("15°30′00″N 95°15′00″W (ED50))."),
+            "15°30′00″N 95°15′00″W (ED50)"
+        );
+        assert_eq!(unwrap("+35.-170./"), "+35.-170./");
+    }
+
+    #[test]
+    fn should_match_decomposed_symbols() {
+        let expected = "15°30′00″N 95°15′00″W";
+        for decomposed in [
+            "15\u{30a}30\u{301}00\u{30b}N 95\u{30a}15\u{301}00\u{30b}W",
+            "15 \u{30a}30 \u{301}00 \u{30b}N 95 \u{30a}15 \u{301}00 \u{30b}W",
+            "15˚30´00˝N 95˚15´00˝W",
+            "15°30′00′′N 95°15′00′′W",
+            "15°\u{338}30′\u{301}00″N 95°15′00″\u{30b}W",
+        ] {
+            assert_eq!(sanitize(decomposed), expected, "{:?}", decomposed);
+        }
+        assert_eq!(sanitize("15°30'00''N"), "15°30'00″N");
+        assert_eq!(sanitize("Ne\u{301}"), "Ne\u{301}");
+        assert!(matches!(sanitize("15°30'00\"N"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn should_replace_decimal_commas() {
+        assert_eq!(
+            decimal_commas("+35,50-170,10+8712,5CRSWGS_84/"),
+            "+35.50-170.10+8712.5CRSWGS_84/"
+        );
+        assert_eq!(
+            decimal_commas("15°30′00,5″N, 95°15′00″W 123,45m"),
+            "15°30′00.5″N, 95°15′00″W 123.45m"
+        );
+        assert!(matches!(
+            decimal_commas("15°30′00″N, 95°15′00″W"),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn should_borrow_clean_input() {
+        for clean in [
+            "+35.50-170.10/",
+            "15°30′00″N 95°15′00″W 123m",
+            "15°30'00\"N 95°15'00\"W",
+            "",
+        ] {
+            assert!(matches!(sanitize(clean), Cow::Borrowed(_)), "{}", clean);
+        }
+        assert!(matches!(sanitize("a  b"), Cow::Owned(_)));
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{parse, ISO6709Coord};
+use sea_orm::sea_query::{ArrayType, ColumnType, Nullable, Value, ValueType, ValueTypeErr};
+use sea_orm::{ColIdx, DbErr, QueryResult, TryGetError, TryGetable};
+
+/// Stores the coordinate in a text column as its string representation, see [`ISO6709Coord::storage_string`].
+///
+/// Panics if a value is NaN or infinite, or the latitude or longitude is out of range, which only a coordinate
+/// built from its public fields can be.
+/// ```
+/// # use iso6709parse::ISO6709Coord;
+/// use sea_orm::Value;
+///
+/// let coord = ISO6709Coord::new(35.5, -170.1, None).unwrap();
+/// let value = Value::from(coord);
+/// assert_eq!(value, Value::from("+35.500000000000-170.100000000000/"));
+/// ```
+impl From<ISO6709Coord> for Value {
+    fn from(value: ISO6709Coord) -> Self {
+        let text = value
+            .storage_string()
+            .expect("an ISO6709Coord must be finite and in range to be stored");
+        Value::String(Some(Box::new(text)))
+    }
+}
+
+impl Nullable for ISO6709Coord {
+    fn null() -> Value {
+        Value::String(None)
+    }
+}
+
+impl ValueType for ISO6709Coord {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            Value::String(Some(text)) => parse(&text).map_err(|_| ValueTypeErr),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        "ISO6709Coord".to_string()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::String
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::Text
+    }
+}
+
+impl TryGetable for ISO6709Coord {
+    fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError> {
+        let text = String::try_get_by(res, index)?;
+        parse(&text).map_err(|err| TryGetError::DbErr(DbErr::Type(err.to_string())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_convert_value() {
+        let coord = ISO6709Coord::new(35.5, -170.1, Some(12.)).unwrap();
+        let value = Value::from(coord);
+        assert_eq!(<ISO6709Coord as ValueType>::try_from(value).unwrap(), coord);
+        assert_eq!(
+            <ISO6709Coord as ValueType>::try_from(Value::from("N35.50W170.10/")).unwrap(),
+            ISO6709Coord::new(35.5, -170.1, None).unwrap()
+        );
+        assert!(<ISO6709Coord as ValueType>::try_from(Value::from("north")).is_err());
+        assert!(<ISO6709Coord as ValueType>::try_from(Value::Int(Some(1))).is_err());
+        assert_eq!(ISO6709Coord::null(), Value::String(None));
+    }
+}
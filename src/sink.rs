@@ -0,0 +1,153 @@
+use crate::parsers::iso6709;
+use crate::{exponent, trim, ISO6709Error, Symbols};
+use nom::Finish;
+
+/// Receives the values of a coordinate from [`parse_into`], so that callers can build their own types, or fill
+/// memory shared across an FFI boundary, without an [`crate::ISO6709Coord`] in between.
+/// Only the latitude and longitude must be handled; the altitude and CRS are ignored unless overridden.
+pub trait CoordSink {
+    /// The latitude in decimal degrees, positive to the north
+    fn lat(&mut self, degrees: f64);
+    /// The longitude in decimal degrees, positive to the east
+    fn lon(&mut self, degrees: f64);
+    /// The altitude in meters, if there is one
+    fn altitude(&mut self, _meters: f64) {}
+    /// The CRS written after the altitude of the string representation, such as `WGS_84`
+    fn crs(&mut self, _crs: &str) {}
+}
+
+/// Parses either of the two formats, the same as [`crate::parse`] apart from aviation altitude notation, and
+/// passes the values to `sink` in the order latitude, longitude, altitude and CRS.
+/// Nothing is passed to the sink unless the whole coordinate parses.
+/// ```
+/// # use iso6709parse::{parse_into, CoordSink};
+/// #[repr(C)]
+/// #[derive(Default)]
+/// struct CPoint { x: f64, y: f64, z: f64 }
+///
+/// impl CoordSink for CPoint {
+///     fn lat(&mut self, degrees: f64) { self.y = degrees; }
+///     fn lon(&mut self, degrees: f64) { self.x = degrees; }
+///     fn altitude(&mut self, meters: f64) { self.z = meters; }
+/// }
+///
+/// let mut point = CPoint::default();
+/// parse_into("N35.50W170.10+8712CRSWGS_84/", &mut point).unwrap();
+/// assert_eq!((point.x, point.y, point.z), (-170.1, 35.5, 8712.));
+/// ```
+pub fn parse_into<S: CoordSink + ?Sized>(str: &str, sink: &mut S) -> Result<(), ISO6709Error> {
+    read_into(str, sink).map_err(|err| err.with_input(str))
+}
+
+fn read_into<S: CoordSink + ?Sized>(str: &str, sink: &mut S) -> Result<(), ISO6709Error> {
+    exponent::reject_exponents(str)?;
+    let readable = trim(
+        iso6709::human_readable::latlong_altitude_option_parser_with(Symbols::standard()),
+    )(str);
+    let ((lat, lon), altitude) = match readable {
+        Ok((_, ((lat, lon), altitude))) => ((lat, lon), altitude.map(|altitude| (altitude, None))),
+        Err(_) => {
+            let (_, ((lat, lon), altitude)) =
+                trim(iso6709::string_expression::latlong_altitude_crs_option_parser)(str)
+                    .finish()?;
+            (
+                (lat, lon),
+                altitude.map(|(altitude, crs)| (altitude, Some(crs))),
+            )
+        }
+    };
+    // Adding positive zero turns `-0.0` into `0.0`, as the other parse functions do
+    sink.lat(lat + 0.);
+    sink.lon(lon + 0.);
+    if let Some((altitude, crs)) = altitude {
+        sink.altitude(altitude + 0.);
+        if let Some(crs) = crs {
+            sink.crs(crs.trim_end());
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_coord, ErrorKind};
+
+    #[derive(Default)]
+    struct Events(Vec<String>);
+
+    impl CoordSink for Events {
+        fn lat(&mut self, degrees: f64) {
+            self.0.push(format!("lat {}", degrees));
+        }
+
+        fn lon(&mut self, degrees: f64) {
+            self.0.push(format!("lon {}", degrees));
+        }
+
+        fn altitude(&mut self, meters: f64) {
+            self.0.push(format!("altitude {}", meters));
+        }
+
+        fn crs(&mut self, crs: &str) {
+            self.0.push(format!("crs {}", crs));
+        }
+    }
+
+    fn events(str: &str) -> Result<Vec<String>, ISO6709Error> {
+        let mut events = Events::default();
+        parse_into(str, &mut events)?;
+        Ok(events.0)
+    }
+
+    #[test]
+    fn should_drive_sink() {
+        assert_eq!(
+            events("-3530.00+17006.00-12.5CRSEPSG:4979/").unwrap(),
+            ["lat -35.5", "lon 170.1", "altitude -12.5", "crs EPSG:4979"]
+        );
+        assert_eq!(
+            events(" 15°30′00″S 95°15′00″W 123.45m ").unwrap(),
+            ["lat -15.5", "lon -95.25", "altitude 123.45"]
+        );
+        assert_eq!(events("S00.00W000.00/").unwrap(), ["lat 0", "lon 0"]);
+    }
+
+    #[test]
+    fn should_match_parse() {
+        struct Sink<'a>(&'a mut crate::ISO6709Coord);
+        impl CoordSink for Sink<'_> {
+            fn lat(&mut self, degrees: f64) {
+                self.0.lat = degrees;
+            }
+            fn lon(&mut self, degrees: f64) {
+                self.0.lon = degrees;
+            }
+            fn altitude(&mut self, meters: f64) {
+                self.0.altitude = Some(meters);
+            }
+        }
+        for str in [
+            "+35.50-170.10/",
+            "N3530.5W17006+8712CRSWGS_84/",
+            "50°40′46.461″N 95°48′26.533″W 123.45m",
+            "+95.50-170.10/",
+            "+1e2-170.10/",
+            "not a coordinate",
+        ] {
+            let mut coord = crate::ISO6709Coord::from_parts(f64::NAN, f64::NAN, None);
+            match (parse_into(str, &mut Sink(&mut coord)), parse_coord(str)) {
+                (Ok(()), Ok(expected)) => assert_eq!(coord, expected, "{}", str),
+                (Err(err), Err(expected)) => {
+                    assert_eq!(err.kind(), expected.kind(), "{}", str);
+                    assert!(coord.lat.is_nan(), "{}", str);
+                }
+                (result, expected) => panic!("{}: {:?} and {:?}", str, result, expected),
+            }
+        }
+        assert_eq!(
+            events("+1e2-170.10/").unwrap_err().kind(),
+            ErrorKind::ScientificNotation
+        );
+    }
+}
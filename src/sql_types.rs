@@ -0,0 +1,96 @@
+//! `diesel` support, so [`ISO6709Coord`] can be a field of `Queryable` and `Insertable` structs.
+//!
+//! A `Text` column holds the coordinate as written by [`ISO6709Coord::storage_string`]. A [`Geometry`] column holds a
+//! PostGIS point with SRID 4326.
+//! ```
+//! # use iso6709parse::{sql_types::Geometry, ISO6709Coord};
+//! diesel::table! {
+//!     use diesel::sql_types::{Integer, Text};
+//!     use iso6709parse::sql_types::Geometry;
+//!
+//!     sites (id) {
+//!         id -> Integer,
+//!         entered -> Text,
+//!         location -> Geometry,
+//!     }
+//! }
+//!
+//! #[derive(diesel::prelude::Queryable, diesel::prelude::Insertable)]
+//! #[diesel(table_name = sites)]
+//! struct Site {
+//!     id: i32,
+//!     entered: ISO6709Coord,
+//!     location: ISO6709Coord,
+//! }
+//! ```
+use crate::ewkb::{from_ewkb, to_ewkb};
+use crate::{parse, ISO6709Coord};
+use diesel::deserialize::{self, FromSql};
+use diesel::pg::{Pg, PgValue};
+use diesel::serialize::{self, IsNull, Output, ToSql};
+use diesel::sql_types::Text;
+use std::io::Write;
+
+/// The PostGIS `geometry` type, as a point with SRID 4326 and a Z coordinate when there is an altitude, as in a
+/// `geometry(PointZ, 4326)` column. Reading a point with another SRID fails with [`crate::ErrorKind::UnknownCrs`].
+#[derive(
+    Debug, Clone, Copy, Default, diesel::sql_types::SqlType, diesel::query_builder::QueryId,
+)]
+#[diesel(postgres_type(name = "geometry"))]
+pub struct Geometry;
+
+impl ToSql<Text, Pg> for ISO6709Coord {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(self.storage_string()?.as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<Text, Pg> for ISO6709Coord {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let text = <String as FromSql<Text, Pg>>::from_sql(bytes)?;
+        Ok(parse(&text)?)
+    }
+}
+
+impl ToSql<Geometry, Pg> for ISO6709Coord {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(&to_ewkb(self))?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<Geometry, Pg> for ISO6709Coord {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        Ok(from_ewkb(bytes.as_bytes())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_write_text() {
+        let coord = ISO6709Coord::new(35.5, -170.1, Some(-12.5)).unwrap();
+        let text = coord.storage_string().unwrap();
+        assert_eq!(
+            text,
+            "+35.500000000000-170.100000000000-12.500000000000CRSWGS_84/"
+        );
+        assert_eq!(parse(&text), Ok(coord));
+
+        let coord = ISO6709Coord::new(1. / 3., 2. / 3., None).unwrap();
+        let read: ISO6709Coord = parse(&coord.storage_string().unwrap()).unwrap();
+        assert!((read.lat - coord.lat).abs() < 1e-12);
+        assert!(ISO6709Coord::from_parts(f64::NAN, 0., None)
+            .storage_string()
+            .is_err());
+
+        let coord = ISO6709Coord::new(35.5, -170.1, Some(9.99e26)).unwrap();
+        assert_eq!(parse(&coord.storage_string().unwrap()), Ok(coord));
+        assert!(ISO6709Coord::from_parts(35.5, -170.1, Some(1e300))
+            .storage_string()
+            .is_err());
+    }
+}
@@ -0,0 +1,57 @@
+use std::sync::OnceLock;
+
+/// The markers of the readable format, for devices and programs that write their own. Each marker can be written
+/// in any of several ways; the defaults are those accepted by [`crate::parse_readable`].
+/// ```
+/// # use iso6709parse::{parse_position, ParserOptions, Symbols};
+/// let options = ParserOptions {
+///     symbols: Symbols {
+///         degree: vec!["°".to_string(), "d".to_string()],
+///         decimal: vec![".".to_string(), ",".to_string()],
+///         west: vec!["W".to_string(), "O".to_string()],
+///         ..Default::default()
+///     },
+///     ..Default::default()
+/// };
+/// let position = parse_position("15d30'00,5\"N 95d15'00\"O", &options).unwrap();
+/// assert_eq!(position.coord.lon, -95.25);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Symbols {
+    pub degree: Vec<String>,
+    pub minute: Vec<String>,
+    pub second: Vec<String>,
+    /// Separator of the fraction of the seconds and of the altitude
+    pub decimal: Vec<String>,
+    pub north: Vec<String>,
+    pub south: Vec<String>,
+    pub east: Vec<String>,
+    pub west: Vec<String>,
+}
+
+fn strings(symbols: &[&str]) -> Vec<String> {
+    symbols.iter().map(|symbol| symbol.to_string()).collect()
+}
+
+impl Default for Symbols {
+    fn default() -> Self {
+        Symbols {
+            degree: strings(&["°"]),
+            minute: strings(&["'", "′"]),
+            second: strings(&["\"", "″"]),
+            decimal: strings(&["."]),
+            north: strings(&["N"]),
+            south: strings(&["S"]),
+            east: strings(&["E"]),
+            west: strings(&["W"]),
+        }
+    }
+}
+
+impl Symbols {
+    /// The default symbols, shared by the parsers that do not take options
+    pub(crate) fn standard() -> &'static Symbols {
+        static STANDARD: OnceLock<Symbols> = OnceLock::new();
+        STANDARD.get_or_init(Symbols::default)
+    }
+}
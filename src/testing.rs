@@ -0,0 +1,137 @@
+//! Assertions for checking coordinates in the test suites of downstream crates, such as property tests of types that
+//! convert to and from [`ISO6709Coord`]. They panic with a message describing the mismatch, like `assert_eq!`.
+use crate::{parse, parse_wikipedia_template, Format, FormatSpec, ISO6709Coord, ISO6709Error};
+
+/// Largest difference in degrees that writing an angle in `format` with `decimals` can cause, with some slack for
+/// floating point arithmetic. Altitudes are rounded to `decimals` meters, which is `tolerance(Format::Degrees, ...)`.
+pub fn tolerance(format: Format, decimals: usize) -> f64 {
+    let unit = match format {
+        Format::Degrees | Format::WikipediaTemplate => 1.,
+        Format::DegreesMinutes => 60.,
+        _ => 3600.,
+    };
+    0.5 * 10f64.powi(-(decimals.min(12) as i32)) / unit + 1e-12
+}
+
+/// Asserts that `coord` written in `format` with 12 decimals parses back to the same coordinate, within
+/// [`tolerance`] of the angles and of the altitude in meters
+/// ```
+/// # use iso6709parse::{testing::assert_roundtrip, Format, ISO6709Coord};
+/// let coord = ISO6709Coord::new(15.5, -95.25, Some(123.45)).unwrap();
+/// assert_roundtrip(&coord, Format::Readable);
+/// assert_roundtrip(&coord, Format::DegreesMinutesSeconds);
+/// ```
+#[track_caller]
+pub fn assert_roundtrip(coord: &ISO6709Coord, format: Format) {
+    assert_roundtrip_with(
+        coord,
+        &FormatSpec {
+            format,
+            decimals: 12,
+            ..Default::default()
+        },
+    )
+}
+
+/// Same as [`assert_roundtrip`], following `spec`. Formats without an altitude must parse back without one.
+#[track_caller]
+pub fn assert_roundtrip_with(coord: &ISO6709Coord, spec: &FormatSpec) {
+    let written = coord
+        .format(spec)
+        .unwrap_or_else(|err| panic!("{:?} cannot be formatted: {}", coord, err));
+    let expected = match spec.format {
+        Format::Wikipedia | Format::WikipediaTemplate => ISO6709Coord {
+            altitude: None,
+            ..*coord
+        },
+        _ => *coord,
+    };
+    let parsed = match spec.format {
+        Format::WikipediaTemplate => parse_wikipedia_template(&written),
+        _ => parse(&written),
+    };
+    assert_within(
+        &written,
+        parsed,
+        &expected,
+        tolerance(spec.format, spec.decimals),
+        tolerance(Format::Degrees, spec.decimals),
+    );
+}
+
+/// Asserts that `input` parses to `expected`, with the latitude, longitude and altitude each within `eps`
+/// ```
+/// # use iso6709parse::{testing::assert_parses_within, ISO6709Coord};
+/// let expected = ISO6709Coord::new(15.5, -95.25, None).unwrap();
+/// assert_parses_within("15°30′00.001″N 95°15′00″W", &expected, 1e-6);
+/// ```
+#[track_caller]
+pub fn assert_parses_within(input: &str, expected: &ISO6709Coord, eps: f64) {
+    assert_within(input, parse(input), expected, eps, eps)
+}
+
+#[track_caller]
+fn assert_within(
+    input: &str,
+    parsed: Result<ISO6709Coord, ISO6709Error>,
+    expected: &ISO6709Coord,
+    eps: f64,
+    altitude_eps: f64,
+) {
+    let actual = parsed.unwrap_or_else(|err| panic!("{:?} does not parse: {}", input, err));
+    let within = |a: f64, b: f64| (a - b).abs() <= eps;
+    let altitude_within = match (actual.altitude, expected.altitude) {
+        (Some(a), Some(b)) => (a - b).abs() <= altitude_eps,
+        (a, b) => a == b,
+    };
+    assert!(
+        within(actual.lat, expected.lat) && within(actual.lon, expected.lon) && altitude_within,
+        "{:?} parses to {:?}, not within {} of {:?}",
+        input,
+        actual,
+        eps,
+        expected
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_roundtrip() {
+        let coord = ISO6709Coord::new(-89.999, 179.5, Some(-12.5)).unwrap();
+        for format in [
+            Format::Degrees,
+            Format::DegreesMinutes,
+            Format::DegreesMinutesSeconds,
+            Format::Readable,
+            Format::Wikipedia,
+            Format::WikipediaTemplate,
+        ] {
+            assert_roundtrip(&coord, format);
+            assert_roundtrip_with(
+                &coord,
+                &FormatSpec {
+                    format,
+                    decimals: 0,
+                    ..Default::default()
+                },
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "not within")]
+    fn should_panic_outside_eps() {
+        let expected = ISO6709Coord::new(15.5, -95.25, None).unwrap();
+        assert_parses_within("15°30′01″N 95°15′00″W", &expected, 1e-6);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not parse")]
+    fn should_panic_on_failure() {
+        let expected = ISO6709Coord::new(15.5, -95.25, None).unwrap();
+        assert_parses_within("15°30′01″N", &expected, 1.);
+    }
+}
@@ -0,0 +1,252 @@
+use crate::{ErrorKind, ISO6709Coord, ISO6709Error};
+use std::f64::consts::PI;
+
+/// Web Mercator is square at these latitudes, and tiles end there
+const MAX_LATITUDE: f64 = 85.05112878;
+/// Bing Maps has levels 1 to 23
+const MAX_QUADKEY_LEVEL: u8 = 23;
+/// The most zoom levels whose tile columns and rows fit in a `u32`
+const MAX_ZOOM: u8 = 31;
+
+impl ISO6709Coord {
+    /// The column and row of the XYZ ("slippy map") tile at `zoom`, from 0 to 31, that holds the coordinate, counting
+    /// from the north-west corner of Web Mercator as OpenStreetMap and most web maps do. Latitudes beyond ±85.0511°,
+    /// where Web Mercator ends, are in the tiles along the edge.
+    /// Fails if the latitude or longitude is NaN, infinite or out of range, or the zoom is beyond 31.
+    /// ```
+    /// # use iso6709parse::{parse, ISO6709Coord};
+    /// let coord: ISO6709Coord = parse("+51.5074-000.1278/").unwrap();
+    /// assert_eq!(coord.to_tile(10).unwrap(), (511, 340));
+    /// ```
+    pub fn to_tile(&self, zoom: u8) -> Result<(u32, u32), ISO6709Error> {
+        check_zoom(zoom)?;
+        tile(self, zoom)
+    }
+
+    /// The center of the XYZ tile at `zoom`, column `x` and row `y`.
+    /// Fails if the zoom is beyond 31, or the column or row is beyond the `2^zoom` tiles at that zoom.
+    /// ```
+    /// # use iso6709parse::ISO6709Coord;
+    /// let center = ISO6709Coord::from_tile_center(10, 511, 340).unwrap();
+    /// assert_eq!(center.to_tile(10).unwrap(), (511, 340));
+    /// assert!((center.lon + 0.17578125).abs() < 1e-9);
+    /// ```
+    pub fn from_tile_center(zoom: u8, x: u32, y: u32) -> Result<ISO6709Coord, ISO6709Error> {
+        check_zoom(zoom)?;
+        if x >> zoom != 0 || y >> zoom != 0 {
+            return Err(ISO6709Error::new(
+                ErrorKind::OutOfRange,
+                format!("tile {}/{} is beyond the tiles at zoom {}", x, y, zoom),
+            ));
+        }
+        Ok(tile_center(zoom, x, y))
+    }
+
+    /// The Bing Maps quadkey of the tile at `level`, from 1 to 23, that holds the coordinate, which has one digit per
+    /// level. Latitudes beyond ±85.0511°, where Web Mercator ends, are in the tiles along the edge.
+    /// Fails if the latitude or longitude is NaN, infinite or out of range, or the level is not from 1 to 23.
+    /// ```
+    /// # use iso6709parse::{parse, ISO6709Coord};
+    /// let coord: ISO6709Coord = parse("+47.61-122.33/").unwrap();
+    /// assert_eq!(coord.to_quadkey(5).unwrap(), "02123");
+    /// ```
+    pub fn to_quadkey(&self, level: u8) -> Result<String, ISO6709Error> {
+        if !(1..=MAX_QUADKEY_LEVEL).contains(&level) {
+            return Err(ISO6709Error::new(
+                ErrorKind::OutOfRange,
+                format!(
+                    "quadkey level {} is not from 1 to {}",
+                    level, MAX_QUADKEY_LEVEL
+                ),
+            ));
+        }
+        let (x, y) = tile(self, level)?;
+        Ok((0..level)
+            .rev()
+            .map(|bit| char::from(b'0' + ((x >> bit) & 1) as u8 + 2 * ((y >> bit) & 1) as u8))
+            .collect())
+    }
+
+    /// The center of the tile with the Bing Maps `quadkey`, which is the coordinate every coordinate in the tile is
+    /// closest to. Fails with [`ErrorKind::Syntax`] if the quadkey is empty, longer than 23 digits, or has a digit
+    /// other than 0 to 3.
+    /// ```
+    /// # use iso6709parse::ISO6709Coord;
+    /// let center = ISO6709Coord::from_quadkey("02123").unwrap();
+    /// assert_eq!(center.to_quadkey(5).unwrap(), "02123");
+    /// assert!((center.lon + 118.125).abs() < 1e-9);
+    /// ```
+    pub fn from_quadkey(quadkey: &str) -> Result<ISO6709Coord, ISO6709Error> {
+        read_quadkey(quadkey).map_err(|err| err.with_input(quadkey))
+    }
+}
+
+fn check_zoom(zoom: u8) -> Result<(), ISO6709Error> {
+    if zoom > MAX_ZOOM {
+        return Err(ISO6709Error::new(
+            ErrorKind::OutOfRange,
+            format!("zoom {} is beyond {}", zoom, MAX_ZOOM),
+        ));
+    }
+    Ok(())
+}
+
+fn read_quadkey(quadkey: &str) -> Result<ISO6709Coord, ISO6709Error> {
+    if quadkey.is_empty() || quadkey.len() > usize::from(MAX_QUADKEY_LEVEL) {
+        return Err(ISO6709Error::new(
+            ErrorKind::Syntax,
+            format!("a quadkey has 1 to {} digits", MAX_QUADKEY_LEVEL),
+        ));
+    }
+    let (mut x, mut y) = (0, 0);
+    for digit in quadkey.chars() {
+        let digit = digit
+            .to_digit(4)
+            .ok_or_else(|| ISO6709Error::new(ErrorKind::Syntax, "quadkey digits are 0 to 3"))?;
+        x = x << 1 | digit & 1;
+        y = y << 1 | digit >> 1;
+    }
+    Ok(tile_center(quadkey.len() as u8, x, y))
+}
+
+/// The column and row, counting from the west and the north, of the Web Mercator tile at `zoom` that holds `coord`
+fn tile(coord: &ISO6709Coord, zoom: u8) -> Result<(u32, u32), ISO6709Error> {
+    ISO6709Coord::new(coord.lat, coord.lon, None)?;
+    let tiles = f64::from(1u32 << zoom);
+    let sin = coord
+        .lat
+        .clamp(-MAX_LATITUDE, MAX_LATITUDE)
+        .to_radians()
+        .sin();
+    let x = (coord.lon + 180.) / 360.;
+    let y = 0.5 - ((1. + sin) / (1. - sin)).ln() / (4. * PI);
+    // Clamped so that the east and south edges are in the last tiles
+    let index = |fraction: f64| (fraction * tiles).clamp(0., tiles - 1.) as u32;
+    Ok((index(x), index(y)))
+}
+
+fn tile_center(zoom: u8, x: u32, y: u32) -> ISO6709Coord {
+    let tiles = f64::from(1u32 << zoom);
+    let (x, y) = ((f64::from(x) + 0.5) / tiles, (f64::from(y) + 0.5) / tiles);
+    let lat = (PI * (1. - 2. * y)).sinh().atan().to_degrees();
+    ISO6709Coord::from_parts(lat, x * 360. - 180., None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_convert_tiles() {
+        let coord = ISO6709Coord::from_parts(0., 0., None);
+        assert_eq!(coord.to_tile(0), Ok((0, 0)));
+        assert_eq!(coord.to_tile(1), Ok((1, 1)));
+        let corner = ISO6709Coord::from_parts(-90., 180., None);
+        assert_eq!(corner.to_tile(31), Ok((u32::MAX >> 1, u32::MAX >> 1)));
+        assert_eq!(
+            ISO6709Coord::from_parts(85.06, -180., None).to_tile(4),
+            Ok((0, 0))
+        );
+
+        for (zoom, x, y) in [
+            (0, 0, 0),
+            (3, 7, 0),
+            (17, 65535, 43690),
+            (31, 1, u32::MAX >> 1),
+        ] {
+            let center = ISO6709Coord::from_tile_center(zoom, x, y).unwrap();
+            assert_eq!(center.to_tile(zoom), Ok((x, y)));
+        }
+        assert_eq!(
+            ISO6709Coord::from_tile_center(0, 0, 0),
+            Ok(ISO6709Coord::from_parts(0., 0., None))
+        );
+        let quadkey = ISO6709Coord::from_parts(47.61, -122.33, None)
+            .to_quadkey(12)
+            .unwrap();
+        let (x, y) = ISO6709Coord::from_quadkey(&quadkey)
+            .unwrap()
+            .to_tile(12)
+            .unwrap();
+        assert_eq!(
+            ISO6709Coord::from_tile_center(12, x, y)
+                .unwrap()
+                .to_quadkey(12)
+                .unwrap(),
+            quadkey
+        );
+    }
+
+    #[test]
+    fn should_err_tiles() {
+        let coord = ISO6709Coord::from_parts(10., 20., None);
+        assert_eq!(coord.to_tile(32).unwrap_err().kind(), ErrorKind::OutOfRange);
+        assert_eq!(
+            ISO6709Coord::from_parts(10., 200., None)
+                .to_tile(3)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::OutOfRange
+        );
+        for (zoom, x, y) in [(32, 0, 0), (3, 8, 0), (3, 0, 8), (0, 1, 0)] {
+            assert_eq!(
+                ISO6709Coord::from_tile_center(zoom, x, y)
+                    .unwrap_err()
+                    .kind(),
+                ErrorKind::OutOfRange
+            );
+        }
+    }
+
+    fn quadkey(lat: f64, lon: f64, level: u8) -> String {
+        ISO6709Coord::from_parts(lat, lon, None)
+            .to_quadkey(level)
+            .unwrap()
+    }
+
+    #[test]
+    fn should_build_quadkey() {
+        assert_eq!(quadkey(0.001, -0.001, 1), "0");
+        assert_eq!(quadkey(0.001, 0., 1), "1");
+        assert_eq!(quadkey(0., -0.001, 1), "2");
+        assert_eq!(quadkey(0., 0., 1), "3");
+        assert_eq!(quadkey(90., -180., 3), "000");
+        assert_eq!(quadkey(-90., 180., 3), "333");
+        assert_eq!(quadkey(-85.06, 179.99, 2), "33");
+        assert_eq!(quadkey(47.61, -122.33, 23).len(), 23);
+        assert!(quadkey(47.61, -122.33, 23).starts_with("02123"));
+    }
+
+    #[test]
+    fn should_read_quadkey() {
+        for key in ["0", "3", "213", "02123003", "33333333333333333333333"] {
+            let center = ISO6709Coord::from_quadkey(key).unwrap();
+            assert_eq!(center.to_quadkey(key.len() as u8).unwrap(), key);
+        }
+        let center = ISO6709Coord::from_quadkey("3").unwrap();
+        assert!((center.lat + 66.51326).abs() < 1e-5 && center.lon == 90.);
+    }
+
+    #[test]
+    fn should_err_quadkey() {
+        let coord = ISO6709Coord::from_parts(10., 20., None);
+        for level in [0, 24] {
+            assert_eq!(
+                coord.to_quadkey(level).unwrap_err().kind(),
+                ErrorKind::OutOfRange
+            );
+        }
+        assert_eq!(
+            ISO6709Coord::from_parts(f64::NAN, 20., None)
+                .to_quadkey(3)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::NonFinite
+        );
+        for key in ["", "0124", "000000000000000000000000", "O1"] {
+            let err = ISO6709Coord::from_quadkey(key).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::Syntax, "{}", key);
+            assert_eq!(err.input(), Some(key));
+        }
+    }
+}
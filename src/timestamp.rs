@@ -0,0 +1,173 @@
+use crate::{parse_coord, ErrorKind, ISO6709Coord, ISO6709Error};
+use nom::branch::alt;
+use nom::bytes::complete::{tag, tag_no_case, take_while1, take_while_m_n};
+use nom::character::complete::{one_of, space0, space1};
+use nom::combinator::{map_res, opt, recognize, verify};
+use nom::sequence::{pair, preceded, tuple};
+use nom::{Finish, IResult};
+
+/// Parses a line of a tracker log that starts with an ISO 8601 timestamp, followed by whitespace or a comma and a
+/// coordinate in either ISO6709 format, such as `2023-01-01T12:00:00Z +27.5916+086.5640/`.
+/// The timestamp is checked and returned as written, so that it can be handed to any date and time library.
+/// Dates and times may be in the extended (`2023-01-01T12:00:00`) or basic (`20230101T120000`) format, with
+/// optional fractions of a second and a `Z` or `±hh:mm` offset.
+/// ```
+/// # use iso6709parse::parse_timestamped;
+/// let line = "2023-01-01T12:00:00Z +27.5916+086.5640+8850CRSWGS_84/";
+/// let (timestamp, coord) = parse_timestamped(line).unwrap();
+/// assert_eq!(timestamp, "2023-01-01T12:00:00Z");
+/// assert_eq!((coord.lat, coord.lon, coord.altitude), (27.5916, 86.564, Some(8850.)));
+/// ```
+pub fn parse_timestamped(line: &str) -> Result<(&str, ISO6709Coord), ISO6709Error> {
+    read_timestamped(line).map_err(|err| err.with_input(line))
+}
+
+fn read_timestamped(line: &str) -> Result<(&str, ISO6709Coord), ISO6709Error> {
+    let (rest, timestamp) = preceded(space0, timestamp)(line).finish().map_err(|_| {
+        ISO6709Error::new(
+            ErrorKind::Syntax,
+            "the line does not start with an ISO 8601 timestamp",
+        )
+    })?;
+    let (coord, _) = separator(rest).finish().map_err(|_| {
+        ISO6709Error::new(
+            ErrorKind::Syntax,
+            "the timestamp is not followed by whitespace or a comma",
+        )
+    })?;
+    Ok((timestamp, parse_coord(coord)?))
+}
+
+fn separator(inp: &str) -> IResult<&str, &str> {
+    alt((recognize(tuple((space0, tag(","), space0))), space1))(inp)
+}
+
+fn timestamp(inp: &str) -> IResult<&str, &str> {
+    recognize(alt((
+        tuple((extended_date, tag_no_case("T"), extended_time, opt(offset))),
+        tuple((basic_date, tag_no_case("T"), basic_time, opt(offset))),
+    )))(inp)
+}
+
+/// Exactly `digits` digits, read as a number from `min` to `max`
+fn number<'a>(digits: usize, min: u16, max: u16) -> impl FnMut(&'a str) -> IResult<&'a str, u16> {
+    verify(
+        map_res(
+            take_while_m_n(digits, digits, |ch: char| ch.is_ascii_digit()),
+            str::parse,
+        ),
+        move |value| (min..=max).contains(value),
+    )
+}
+
+fn extended_date(inp: &str) -> IResult<&str, &str> {
+    recognize(tuple((
+        number(4, 0, 9999),
+        tag("-"),
+        number(2, 1, 12),
+        tag("-"),
+        number(2, 1, 31),
+    )))(inp)
+}
+
+fn basic_date(inp: &str) -> IResult<&str, &str> {
+    recognize(tuple((
+        number(4, 0, 9999),
+        number(2, 1, 12),
+        number(2, 1, 31),
+    )))(inp)
+}
+
+/// Seconds of 60 are allowed, for leap seconds
+fn extended_time(inp: &str) -> IResult<&str, &str> {
+    recognize(tuple((
+        number(2, 0, 24),
+        tag(":"),
+        number(2, 0, 59),
+        opt(pair(tag(":"), number(2, 0, 60))),
+        opt(fraction),
+    )))(inp)
+}
+
+fn basic_time(inp: &str) -> IResult<&str, &str> {
+    recognize(tuple((
+        number(2, 0, 24),
+        number(2, 0, 59),
+        opt(number(2, 0, 60)),
+        opt(fraction),
+    )))(inp)
+}
+
+fn fraction(inp: &str) -> IResult<&str, &str> {
+    recognize(pair(
+        one_of(".,"),
+        take_while1(|ch: char| ch.is_ascii_digit()),
+    ))(inp)
+}
+
+fn offset(inp: &str) -> IResult<&str, &str> {
+    alt((
+        tag_no_case("Z"),
+        recognize(tuple((
+            one_of("+-"),
+            number(2, 0, 23),
+            opt(preceded(opt(tag(":")), number(2, 0, 59))),
+        ))),
+    ))(inp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_timestamped() {
+        let coord = ISO6709Coord::from_parts(35.5, -170.1, None);
+        for (line, expected) in [
+            (
+                "2023-01-01T12:00:00Z +35.50-170.10/",
+                "2023-01-01T12:00:00Z",
+            ),
+            (" 2023-01-01t12:00 N35.50W170.10/ ", "2023-01-01t12:00"),
+            (
+                "2023-01-01T23:59:60.123+05:30, +35.50-170.10/",
+                "2023-01-01T23:59:60.123+05:30",
+            ),
+            (
+                "20230101T120000,5-0800\t+35.50-170.10/",
+                "20230101T120000,5-0800",
+            ),
+            (
+                "2023-01-01T12:00:00Z,35°30′00″N 170°06′00″W",
+                "2023-01-01T12:00:00Z",
+            ),
+        ] {
+            assert_eq!(parse_timestamped(line), Ok((expected, coord)), "{}", line);
+        }
+    }
+
+    #[test]
+    fn should_err_timestamped() {
+        for line in [
+            "+35.50-170.10/",
+            "2023-01-01 +35.50-170.10/",
+            "2023-13-01T12:00:00Z +35.50-170.10/",
+            "2023-01-01T12:60:00Z +35.50-170.10/",
+            "2023-01-01T12:00:00Z+35.50-170.10/",
+            "2023-01-01T12:00:00Z",
+        ] {
+            assert_eq!(
+                parse_timestamped(line).unwrap_err().kind(),
+                ErrorKind::Syntax,
+                "{}",
+                line
+            );
+        }
+        let err = parse_timestamped("2023-01-01T12:00:00Z +95.50-170.10/").unwrap_err();
+        assert_eq!(
+            err.kind(),
+            parse_coord("+95.50-170.10/").unwrap_err().kind()
+        );
+        assert_eq!(err.input(), Some("2023-01-01T12:00:00Z +95.50-170.10/"));
+    }
+}
@@ -0,0 +1,33 @@
+use crate::{parse, ISO6709Coord};
+use std::borrow::Cow;
+use validator::ValidationError;
+
+/// Checks that a string is a coordinate in either ISO6709 format, for the `validator` derive:
+/// `#[validate(custom(function = "iso6709parse::validate_str"))]` on a `String` field. The error has the code
+/// `iso6709` and the reason the string failed to parse as its message.
+/// ```
+/// # use iso6709parse::validate_str;
+/// assert!(validate_str("15°30′00″N 95°15′00″W").is_ok());
+///
+/// let err = validate_str("15°30′00″N").unwrap_err();
+/// assert_eq!(err.code, "iso6709");
+/// ```
+pub fn validate_str(value: &str) -> Result<(), ValidationError> {
+    parse::<ISO6709Coord>(value)
+        .map(|_| ())
+        .map_err(|err| ValidationError::new("iso6709").with_message(Cow::Owned(err.to_string())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_validate_str() {
+        assert!(validate_str("+35.50-170.10+12CRSWGS_84/").is_ok());
+        let err = validate_str("+95.50-170.10/").unwrap_err();
+        assert_eq!(err.code, "iso6709");
+        assert!(err.message.is_some());
+        assert!(validate_str("").is_err());
+    }
+}
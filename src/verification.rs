@@ -0,0 +1,65 @@
+//! Kani proof harnesses for the range invariants of parsed coordinates, run with `cargo kani`. The parser harnesses
+//! cover every input up to a few bytes long; longer inputs are left to the fuzz targets.
+use crate::parsers::latitude;
+use crate::parsers::longitude;
+use crate::ISO6709Coord;
+
+/// Any string of up to `N` bytes
+fn any_str<const N: usize>(bytes: &[u8; N]) -> Option<&str> {
+    let len: usize = kani::any();
+    kani::assume(len <= N);
+    std::str::from_utf8(&bytes[..len]).ok()
+}
+
+#[kani::proof]
+fn coord_new_is_in_range() {
+    let (lat, lon, altitude): (f64, f64, Option<f64>) = (kani::any(), kani::any(), kani::any());
+    if let Ok(coord) = ISO6709Coord::new(lat, lon, altitude) {
+        assert!(coord.lat.abs() <= 90. && coord.lon.abs() <= 180.);
+        assert!(coord.altitude.is_none_or(f64::is_finite));
+    }
+}
+
+#[kani::proof]
+fn minutes_and_seconds_are_less_than_a_degree() {
+    let (minutes, seconds): (f64, f64) = (kani::any(), kani::any());
+    kani::assume((0. ..60.).contains(&minutes) && (0. ..60.).contains(&seconds));
+    assert!(minutes / 60. + seconds / 3600. < 1.);
+}
+
+#[kani::proof]
+#[kani::unwind(12)]
+fn string_latitude_is_in_range() {
+    let bytes: [u8; 10] = kani::any();
+    if let Some(Ok((_, lat))) = any_str(&bytes).map(latitude::string_expression::latitude_parser) {
+        assert!(lat.abs() <= 90.);
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(12)]
+fn string_longitude_is_in_range() {
+    let bytes: [u8; 10] = kani::any();
+    if let Some(Ok((_, lon))) = any_str(&bytes).map(longitude::string_expression::longitude_parser)
+    {
+        assert!(lon.abs() <= 180.);
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(14)]
+fn readable_latitude_is_in_range() {
+    let bytes: [u8; 12] = kani::any();
+    if let Some(Ok((_, lat))) = any_str(&bytes).map(latitude::human_readable::latitude_parser) {
+        assert!(lat.abs() <= 90.);
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(14)]
+fn readable_longitude_is_in_range() {
+    let bytes: [u8; 12] = kani::any();
+    if let Some(Ok((_, lon))) = any_str(&bytes).map(longitude::human_readable::longitude_parser) {
+        assert!(lon.abs() <= 180.);
+    }
+}
@@ -0,0 +1,173 @@
+use crate::{ErrorKind, FromIso6709, ISO6709Coord, ISO6709Error};
+
+/// Parses a MediaWiki `{{coord}}` template invocation into any struct that implements [`FromIso6709`].
+/// The latitude and longitude are either signed decimal degrees, or degrees, minutes and seconds, each optional after
+/// the degrees, followed by the hemisphere letter. Coordinate parameters such as `type:city` and named parameters such
+/// as `display=title` are ignored. This reads the output of [`crate::Format::WikipediaTemplate`].
+/// ```
+/// # use iso6709parse::{parse_wikipedia_template, ISO6709Coord};
+/// let coord: ISO6709Coord = parse_wikipedia_template("{{coord|50|40|46|N|95|48|27|W|type:city}}").unwrap();
+/// assert_eq!((coord.lat, coord.lon), (50. + 40. / 60. + 46. / 3600., -(95. + 48. / 60. + 27. / 3600.)));
+///
+/// let coord: ISO6709Coord = parse_wikipedia_template("{{Coord|50.6796|-95.8074|display=inline}}").unwrap();
+/// assert_eq!((coord.lon, coord.lat), (-95.8074, 50.6796));
+/// ```
+pub fn parse_wikipedia_template<T>(str: &str) -> Result<T, ISO6709Error>
+where
+    T: FromIso6709,
+{
+    read_template(str)
+        .map(T::from_iso6709)
+        .map_err(|err| err.with_input(str))
+}
+
+fn syntax(message: impl Into<String>) -> ISO6709Error {
+    ISO6709Error::new(ErrorKind::Syntax, message)
+}
+
+fn read_template(str: &str) -> Result<ISO6709Coord, ISO6709Error> {
+    let body = str
+        .trim()
+        .strip_prefix("{{")
+        .and_then(|body| body.strip_suffix("}}"))
+        .ok_or_else(|| syntax("a template must be enclosed in `{{` and `}}`"))?;
+    let mut parameters = body.split('|').map(str::trim);
+    if !parameters
+        .next()
+        .is_some_and(|name| name.eq_ignore_ascii_case("coord"))
+    {
+        return Err(syntax("not a `coord` template"));
+    }
+    let positional: Vec<&str> = parameters
+        .filter(|parameter| !parameter.contains('='))
+        .collect();
+
+    let (lat, lon) = match hemisphere_at(&positional, "NS") {
+        Some(at) => {
+            let lon_parts = &positional[at + 1..];
+            let lon_at = hemisphere_at(lon_parts, "EW")
+                .ok_or_else(|| syntax("missing longitude hemisphere `E` or `W`"))?;
+            (
+                sexagesimal(&positional[..at], positional[at])?,
+                sexagesimal(&lon_parts[..lon_at], lon_parts[lon_at])?,
+            )
+        }
+        None => match positional[..] {
+            [lat, lon, ..] => (decimal(lat)?, decimal(lon)?),
+            _ => return Err(syntax("missing latitude or longitude")),
+        },
+    };
+    let coord = ISO6709Coord::new(lat, lon, None)?;
+    Ok(ISO6709Coord::unsigned_zero(coord.lat, coord.lon, None))
+}
+
+/// Where the hemisphere letter is, after one to three of degrees, minutes and seconds
+fn hemisphere_at(parameters: &[&str], hemispheres: &str) -> Option<usize> {
+    (1..=3).find(|&at| {
+        parameters
+            .get(at)
+            .is_some_and(|&parameter| parameter.len() == 1 && hemispheres.contains(parameter))
+    })
+}
+
+/// Degrees, then optionally minutes and seconds, with a decimal fraction allowed only on the last
+fn sexagesimal(parts: &[&str], hemisphere: &str) -> Result<f64, ISO6709Error> {
+    let mut value = 0.;
+    for (index, (&part, per_degree)) in parts.iter().zip([1., 60., 3600.]).enumerate() {
+        let last = index + 1 == parts.len();
+        let unsigned = !part.is_empty()
+            && part.bytes().all(|ch| ch.is_ascii_digit() || ch == b'.')
+            && (last || !part.contains('.'));
+        let number = match part.parse::<f64>() {
+            Ok(number) if unsigned => number,
+            _ => return Err(syntax(format!("`{}` is not a valid angle component", part))),
+        };
+        if index > 0 && number >= 60. {
+            return Err(syntax(format!("`{}` is not less than 60", part)));
+        }
+        value += number / per_degree;
+    }
+    Ok(match hemisphere {
+        "S" | "W" => -value,
+        _ => value,
+    })
+}
+
+fn decimal(parameter: &str) -> Result<f64, ISO6709Error> {
+    let digits = parameter.strip_prefix(['+', '-']).unwrap_or(parameter);
+    match parameter.parse::<f64>() {
+        Ok(value) if digits.bytes().all(|ch| ch.is_ascii_digit() || ch == b'.') => Ok(value),
+        _ => Err(syntax(format!("`{}` is not decimal degrees", parameter))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Format, FormatSpec};
+
+    fn template(str: &str) -> Result<ISO6709Coord, ISO6709Error> {
+        parse_wikipedia_template(str)
+    }
+
+    #[test]
+    fn should_parse_template() {
+        assert_eq!(
+            template("{{coord|57|N|23|E}}"),
+            Ok(ISO6709Coord::from_parts(57., 23., None))
+        );
+        assert_eq!(
+            template(" {{ coord | 57 | 18.5 | S | 123 | 30 | W | region:US }} "),
+            Ok(ISO6709Coord::from_parts(-57.30833333333333, -123.5, None))
+        );
+        assert_eq!(
+            template("{{coord|0|0|1.8|S|0|0|0|E|name=Null Island}}"),
+            Ok(ISO6709Coord::from_parts(-0.0005, 0., None))
+        );
+        assert_eq!(
+            template("{{coord|-0.0|-170.1|type:landmark|display=title}}"),
+            Ok(ISO6709Coord::from_parts(0., -170.1, None))
+        );
+        assert!(template("{{coord|-0.0|1}}").unwrap().lat.is_sign_positive());
+    }
+
+    #[test]
+    fn should_err_template() {
+        for str in [
+            "coord|57|N|23|E",
+            "{{location|57|N|23|E}}",
+            "{{coord|57|N|23}}",
+            "{{coord|57}}",
+            "{{coord|57|60|N|23|E}}",
+            "{{coord|57.5|30|N|23|E}}",
+            "{{coord|-57|N|23|E}}",
+            "{{coord|N57|23}}",
+            "{{coord|1e1|23}}",
+        ] {
+            assert_eq!(
+                template(str).unwrap_err().kind(),
+                ErrorKind::Syntax,
+                "{}",
+                str
+            );
+        }
+        assert_eq!(
+            template("{{coord|95|N|23|E}}").unwrap_err().kind(),
+            ErrorKind::OutOfRange
+        );
+    }
+
+    #[test]
+    fn should_read_formatted_templates() {
+        let coord = ISO6709Coord::new(-35.123456, 170.5, Some(12.)).unwrap();
+        let spec = FormatSpec {
+            format: Format::WikipediaTemplate,
+            decimals: 6,
+            ..Default::default()
+        };
+        assert_eq!(
+            template(&coord.format(&spec).unwrap()),
+            Ok(ISO6709Coord::from_parts(-35.123456, 170.5, None))
+        );
+    }
+}
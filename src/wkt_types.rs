@@ -0,0 +1,85 @@
+use crate::{ErrorKind, ISO6709Coord, ISO6709Error};
+use wkt::types::{Coord, Point};
+use wkt::Wkt;
+
+/// Longitude is x, latitude is y and the altitude, if any, is z.
+/// ```
+/// # use iso6709parse::{parse, ISO6709Coord};
+/// use wkt::Wkt;
+///
+/// let coord: ISO6709Coord = parse("+35.50-170.10+12CRSWGS_84/").unwrap();
+/// assert_eq!(Wkt::from(coord).to_string(), "POINT Z(-170.1 35.5 12)");
+///
+/// let wkt: Wkt = "POINT(-170.1 35.5)".parse().unwrap();
+/// assert_eq!(ISO6709Coord::try_from(wkt).unwrap().lat, 35.5);
+/// ```
+impl From<ISO6709Coord> for Point {
+    fn from(value: ISO6709Coord) -> Self {
+        Point::from_coord(Coord {
+            x: value.lon,
+            y: value.lat,
+            z: value.altitude,
+            m: None,
+        })
+    }
+}
+
+impl From<ISO6709Coord> for Wkt {
+    fn from(value: ISO6709Coord) -> Self {
+        Wkt::Point(value.into())
+    }
+}
+
+impl TryFrom<Point> for ISO6709Coord {
+    type Error = ISO6709Error;
+
+    /// Fails if the point is empty or out of range, as checked by [`ISO6709Coord::new`]. A measure is dropped.
+    fn try_from(value: Point) -> Result<Self, Self::Error> {
+        match value.coord() {
+            Some(coord) => ISO6709Coord::new(coord.y, coord.x, coord.z),
+            None => Err(ISO6709Error::new(ErrorKind::Syntax, "the point is empty")),
+        }
+    }
+}
+
+impl TryFrom<Wkt> for ISO6709Coord {
+    type Error = ISO6709Error;
+
+    /// Fails if the geometry is not a point
+    fn try_from(value: Wkt) -> Result<Self, Self::Error> {
+        match value {
+            Wkt::Point(point) => point.try_into(),
+            _ => Err(ISO6709Error::new(
+                ErrorKind::Syntax,
+                "the geometry is not a point",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wkt::types::Dimension;
+
+    #[test]
+    fn should_convert_wkt() {
+        let coord = ISO6709Coord::new(15.5, -95.25, None).unwrap();
+        let point = Point::from(coord);
+        assert_eq!(point.dimension(), Dimension::XY);
+        assert_eq!(ISO6709Coord::try_from(point), Ok(coord));
+
+        let wkt: Wkt = "POINT ZM(-95.25 15.5 123.45 7)".parse().unwrap();
+        let coord = ISO6709Coord::try_from(wkt).unwrap();
+        assert_eq!(coord.altitude, Some(123.45));
+        assert_eq!(Wkt::from(coord).to_string(), "POINT Z(-95.25 15.5 123.45)");
+    }
+
+    #[test]
+    fn should_reject_other_wkt() {
+        let err = |wkt: &str| ISO6709Coord::try_from(wkt.parse::<Wkt>().unwrap()).unwrap_err();
+        assert_eq!(err("POINT EMPTY").kind(), ErrorKind::Syntax);
+        assert_eq!(err("LINESTRING(0 0,1 1)").kind(), ErrorKind::Syntax);
+        assert_eq!(err("POINT(15.5 -95.25)").kind(), ErrorKind::OutOfRange);
+    }
+}
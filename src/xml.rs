@@ -0,0 +1,268 @@
+use crate::{Crs, ErrorKind, FromIso6709, ISO6709Coord, ISO6709Error, Position};
+use quick_xml::escape::escape;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+/// An element of an XML document, named without its namespace prefix. Namespaces are not checked, so fragments
+/// cut out of larger documents read the same as whole documents.
+pub(crate) struct Element {
+    pub(crate) name: String,
+    pub(crate) attributes: Vec<(String, String)>,
+    pub(crate) text: String,
+}
+
+impl Element {
+    /// The value of the attribute named `name` without its namespace prefix
+    pub(crate) fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Every element of `xml`, in document order
+pub(crate) fn elements(xml: &str) -> Result<Vec<Element>, ISO6709Error> {
+    let mut reader = Reader::from_str(xml);
+    let invalid = |reader: &Reader<&[u8]>| {
+        ISO6709Error::new(
+            ErrorKind::Syntax,
+            format!("invalid XML at byte {}", reader.error_position()),
+        )
+    };
+    let mut elements = vec![];
+    let mut open = vec![];
+    loop {
+        match reader.read_event().map_err(|_| invalid(&reader))? {
+            Event::Start(start) => {
+                elements.push(element(&start).ok_or_else(|| invalid(&reader))?);
+                open.push(elements.len() - 1);
+            }
+            Event::Empty(start) => elements.push(element(&start).ok_or_else(|| invalid(&reader))?),
+            Event::Text(text) => {
+                let text = text.decode().map_err(|_| invalid(&reader))?;
+                if let Some(&at) = open.last() {
+                    elements[at].text.push_str(&text);
+                }
+            }
+            Event::End(_) => {
+                open.pop();
+            }
+            Event::Eof if open.is_empty() => return Ok(elements),
+            Event::Eof => {
+                return Err(ISO6709Error::new(
+                    ErrorKind::Syntax,
+                    "invalid XML, an element is not closed",
+                ))
+            }
+            _ => {}
+        }
+    }
+}
+
+fn element(start: &BytesStart) -> Option<Element> {
+    let name = String::from_utf8(start.local_name().as_ref().to_vec()).ok()?;
+    let attributes = start
+        .attributes()
+        .map(|attribute| {
+            let attribute = attribute.ok()?;
+            let key = String::from_utf8(attribute.key.local_name().as_ref().to_vec()).ok()?;
+            Some((key, attribute.unescape_value().ok()?.into_owned()))
+        })
+        .collect::<Option<_>>()?;
+    Some(Element {
+        name,
+        attributes,
+        text: String::new(),
+    })
+}
+
+/// Reads a number, failing with a message naming the element it is from
+pub(crate) fn number(text: &str, element: &str) -> Result<f64, ISO6709Error> {
+    text.trim().parse().map_err(|_| {
+        ISO6709Error::new(
+            ErrorKind::Syntax,
+            format!("`{}` in `{}` is not a number", text.trim(), element),
+        )
+    })
+}
+
+/// Parses the XML representation of a point location, with the latitude and longitude in decimal degrees, into any
+/// struct that implements [`FromIso6709`]. See [`parse_xml_position`] for the elements read.
+/// ```
+/// # use iso6709parse::{parse_xml, ISO6709Coord};
+/// let xml = "<PointLocation><Latitude>35.5</Latitude><Longitude>-170.1</Longitude></PointLocation>";
+/// let coord: ISO6709Coord = parse_xml(xml).unwrap();
+/// assert_eq!((coord.lon, coord.lat), (-170.1, 35.5));
+/// ```
+pub fn parse_xml<T>(str: &str) -> Result<T, ISO6709Error>
+where
+    T: FromIso6709,
+{
+    parse_xml_position(str).map(|position| T::from_iso6709(position.coord))
+}
+
+/// Parses the XML representation of a point location into a [`Position`]. The `Latitude` and `Longitude` elements
+/// are required, `Height` (or `Altitude`) and `CRS` are optional. Element names are matched without regard to
+/// case or namespace prefix, and the point element around them can have any name.
+/// ```
+/// # use iso6709parse::{parse_xml_position, Crs};
+/// let xml = r#"<iso6709:PointLocation xmlns:iso6709="urn:iso:std:iso:6709">
+///     <iso6709:Latitude>35.5</iso6709:Latitude>
+///     <iso6709:Longitude>-170.1</iso6709:Longitude>
+///     <iso6709:Height>8712</iso6709:Height>
+///     <iso6709:CRS>EPSG:4979</iso6709:CRS>
+/// </iso6709:PointLocation>"#;
+/// let position = parse_xml_position(xml).unwrap();
+/// assert_eq!(position.coord.altitude, Some(8712.));
+/// assert_eq!(position.crs, Some(Crs::Epsg(4979)));
+/// ```
+pub fn parse_xml_position(str: &str) -> Result<Position, ISO6709Error> {
+    read_xml(str).map_err(|err| err.with_input(str))
+}
+
+fn read_xml(str: &str) -> Result<Position, ISO6709Error> {
+    let elements = elements(str)?;
+    let find = |names: &[&str]| {
+        elements.iter().find(|element| {
+            names
+                .iter()
+                .any(|name| element.name.eq_ignore_ascii_case(name))
+        })
+    };
+    let value = |name: &str| match find(&[name]) {
+        Some(element) => number(&element.text, &element.name),
+        None => Err(ISO6709Error::new(
+            ErrorKind::Syntax,
+            format!("missing `{}` element", name),
+        )),
+    };
+    let altitude = find(&["Height", "Altitude"])
+        .map(|element| number(&element.text, &element.name))
+        .transpose()?;
+    let coord = ISO6709Coord::new(value("Latitude")?, value("Longitude")?, altitude)?;
+    let crs = find(&["CRS"])
+        .map(|element| element.text.trim())
+        .filter(|crs| !crs.is_empty())
+        .map(Crs::from);
+    Ok(Position::new(
+        ISO6709Coord::unsigned_zero(coord.lat, coord.lon, coord.altitude),
+        crs,
+    ))
+}
+
+impl ISO6709Coord {
+    /// Writes the XML representation of the point location, as read by [`parse_xml_position`], with the latitude
+    /// and longitude in decimal degrees and `crs` in the `CRS` element.
+    /// Fails if a value is NaN or infinite, or the latitude or longitude is out of range.
+    /// ```
+    /// # use iso6709parse::{Crs, ISO6709Coord};
+    /// let coord = ISO6709Coord::new(35.5, -170.1, Some(8712.)).unwrap();
+    /// assert_eq!(
+    ///     coord.to_xml(&Crs::Epsg(4979)).unwrap(),
+    ///     "<PointLocation><Latitude>35.5</Latitude><Longitude>-170.1</Longitude><Height>8712</Height>\
+    ///      <CRS>EPSG:4979</CRS></PointLocation>"
+    /// );
+    /// ```
+    pub fn to_xml(&self, crs: &Crs) -> Result<String, ISO6709Error> {
+        ISO6709Coord::new(self.lat, self.lon, self.altitude)?;
+        let mut out = format!(
+            "<PointLocation><Latitude>{}</Latitude><Longitude>{}</Longitude>",
+            self.lat + 0.,
+            self.lon + 0.
+        );
+        if let Some(altitude) = self.altitude {
+            out.push_str(&format!("<Height>{}</Height>", altitude + 0.));
+        }
+        out.push_str(&format!(
+            "<CRS>{}</CRS></PointLocation>",
+            escape(crs.to_string())
+        ));
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_xml() {
+        let position = parse_xml_position(
+            "<pointlocation><latitude> +35.5 </latitude><longitude>-170.1</longitude><altitude>-12.5</altitude>\
+             <crs>WGS_84</crs></pointlocation>",
+        )
+        .unwrap();
+        assert_eq!(
+            position.coord,
+            ISO6709Coord::from_parts(35.5, -170.1, Some(-12.5))
+        );
+        assert_eq!(position.crs, Some(Crs::Wgs84));
+
+        let position = parse_xml_position(
+            "<PointLocation><Latitude>-0.0</Latitude><Longitude>0</Longitude><CRS/></PointLocation>",
+        )
+        .unwrap();
+        assert_eq!(position.coord, ISO6709Coord::from_parts(0., 0., None));
+        assert!(position.coord.lat.is_sign_positive());
+        assert_eq!(position.crs, None);
+    }
+
+    #[test]
+    fn should_err_xml() {
+        let err =
+            parse_xml::<ISO6709Coord>("<PointLocation><Latitude>35.5</Latitude></PointLocation>")
+                .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Syntax);
+        assert_eq!(
+            err.to_string(),
+            "Failed to parse ISO6709 coordinate: missing `Longitude` element"
+        );
+
+        let err = parse_xml::<ISO6709Coord>(
+            "<PointLocation><Latitude>N35</Latitude><Longitude>1</Longitude></PointLocation>",
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Failed to parse ISO6709 coordinate: `N35` in `Latitude` is not a number"
+        );
+
+        let err = parse_xml::<ISO6709Coord>(
+            "<PointLocation><Latitude>95</Latitude><Longitude>1</Longitude></PointLocation>",
+        )
+        .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::OutOfRange);
+
+        for xml in [
+            "<PointLocation><Latitude>35.5</Longitude></PointLocation>",
+            "<PointLocation><Latitude>35.5</Latitude>",
+            "<PointLocation <Latitude>",
+        ] {
+            let err = parse_xml::<ISO6709Coord>(xml).unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::Syntax, "{}", xml);
+            assert_eq!(err.input(), Some(xml));
+        }
+    }
+
+    #[test]
+    fn should_write_xml() {
+        let coord = ISO6709Coord::from_parts(-0., 15.25, None);
+        let xml = coord.to_xml(&Crs::Other("A<B".to_string())).unwrap();
+        assert_eq!(
+            xml,
+            "<PointLocation><Latitude>0</Latitude><Longitude>15.25</Longitude><CRS>A&lt;B</CRS></PointLocation>"
+        );
+
+        let coord = ISO6709Coord::from_parts(-35.123456789012, 170.1, Some(-12.5));
+        let position = parse_xml_position(&coord.to_xml(&Crs::Wgs84).unwrap()).unwrap();
+        assert_eq!(position.coord, coord);
+        assert_eq!(position.crs, Some(Crs::Wgs84));
+
+        let coord = ISO6709Coord::from_parts(95., 170.1, None);
+        assert_eq!(
+            coord.to_xml(&Crs::Wgs84).unwrap_err().kind(),
+            ErrorKind::OutOfRange
+        );
+    }
+}
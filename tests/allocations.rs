@@ -0,0 +1,96 @@
+//! Successful parses must not allocate, so the crate can be used in latency-sensitive code. This is its own test
+//! binary, since it replaces the global allocator.
+use iso6709parse::{parse, parse_into, parse_position, CoordSink, ISO6709Coord, ParserOptions};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+struct Counting;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+fn allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+const INPUTS: [&str; 5] = [
+    "15°30′00.000″N 95°15′00.000″W",
+    " 15°30′00.000″N 95°15′00.000″W 123.45m ",
+    "N35.50W170.10/",
+    "+3520.30-17006.12+8712CRSWGS_84/",
+    "+352018.5-1700612.25+8712CRSEPSG:4979/",
+];
+
+#[test]
+fn should_parse_without_allocating() {
+    // The standard symbols are built on first use
+    parse::<ISO6709Coord>(INPUTS[0]).unwrap();
+
+    for input in INPUTS {
+        assert_eq!(
+            allocations(|| {
+                parse::<ISO6709Coord>(input).unwrap();
+            }),
+            0,
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+fn should_parse_positions_without_allocating() {
+    let options = ParserOptions::default();
+    for input in INPUTS {
+        assert_eq!(
+            allocations(|| {
+                parse_position(input, &options).unwrap();
+            }),
+            0,
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+fn should_drive_sink_without_allocating() {
+    struct Sum(f64);
+    impl CoordSink for Sum {
+        fn lat(&mut self, degrees: f64) {
+            self.0 += degrees;
+        }
+        fn lon(&mut self, degrees: f64) {
+            self.0 += degrees;
+        }
+    }
+    parse::<ISO6709Coord>(INPUTS[0]).unwrap();
+
+    for input in INPUTS {
+        assert_eq!(
+            allocations(|| {
+                parse_into(input, &mut Sum(0.)).unwrap();
+            }),
+            0,
+            "{}",
+            input
+        );
+    }
+}